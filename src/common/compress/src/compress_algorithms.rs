@@ -45,6 +45,9 @@ pub enum CompressAlgorithm {
 }
 
 impl CompressAlgorithm {
+    /// The length of the longest magic bytes detected by [`CompressAlgorithm::from_magic_bytes`].
+    pub const MAX_MAGIC_BYTES_LEN: usize = 6;
+
     /// Get the file extension of this compress algorithm.
     pub fn extension(&self) -> &str {
         match self {
@@ -87,4 +90,21 @@ impl CompressAlgorithm {
 
         CompressAlgorithm::from_extension(&ext)
     }
+
+    /// Detect CompressAlgorithm from the magic bytes at the beginning of the content.
+    ///
+    /// Only formats with an unambiguous magic number are detected,
+    /// `None` will be return for other formats and uncompressed content.
+    /// `bytes` should hold the first [`CompressAlgorithm::MAX_MAGIC_BYTES_LEN`] bytes
+    /// of the content, or all of it if the content is shorter.
+    pub fn from_magic_bytes(bytes: &[u8]) -> Option<CompressAlgorithm> {
+        match bytes {
+            [0x1f, 0x8b, ..] => Some(CompressAlgorithm::Gzip),
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => Some(CompressAlgorithm::Zstd),
+            // "BZh" followed by the block size from '1' to '9'.
+            [b'B', b'Z', b'h', b'1'..=b'9', ..] => Some(CompressAlgorithm::Bz2),
+            [0xfd, b'7', b'z', b'X', b'Z', 0x00, ..] => Some(CompressAlgorithm::Xz),
+            _ => None,
+        }
+    }
 }
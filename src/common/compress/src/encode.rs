@@ -217,4 +217,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_compress_algorithm_from_magic_bytes() -> databend_common_exception::Result<()> {
+        let content = br#"{"a": 1, "b": "x"}"#.repeat(16);
+        for algo in [
+            CompressAlgorithm::Gzip,
+            CompressAlgorithm::Bz2,
+            CompressAlgorithm::Zstd,
+            CompressAlgorithm::Xz,
+        ] {
+            let mut encoder = CompressCodec::from(algo);
+            let compressed = encoder.compress_all(&content)?;
+            assert_eq!(
+                Some(algo),
+                CompressAlgorithm::from_magic_bytes(&compressed),
+                "fail to detect {algo:?}"
+            );
+        }
+
+        assert_eq!(None, CompressAlgorithm::from_magic_bytes(&content));
+        assert_eq!(None, CompressAlgorithm::from_magic_bytes(&[]));
+        assert_eq!(None, CompressAlgorithm::from_magic_bytes(b"BZh"));
+        assert_eq!(None, CompressAlgorithm::from_magic_bytes(b"BZhello"));
+        assert_eq!(None, CompressAlgorithm::from_magic_bytes(b"BZh0"));
+        assert_eq!(
+            Some(CompressAlgorithm::Bz2),
+            CompressAlgorithm::from_magic_bytes(b"BZh9")
+        );
+
+        Ok(())
+    }
 }
@@ -32,3 +32,4 @@ pub use crate::metrics::mysql;
 pub use crate::metrics::openai;
 pub use crate::metrics::session;
 pub use crate::metrics::storage;
+pub use crate::metrics::udf;
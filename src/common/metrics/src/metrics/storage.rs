@@ -15,10 +15,14 @@
 use std::sync::LazyLock;
 
 use databend_common_base::runtime::metrics::register_counter;
+use databend_common_base::runtime::metrics::register_counter_family;
 use databend_common_base::runtime::metrics::register_histogram_in_milliseconds;
 use databend_common_base::runtime::metrics::Counter;
+use databend_common_base::runtime::metrics::FamilyCounter;
 use databend_common_base::runtime::metrics::Histogram;
 
+use crate::VecLabels;
+
 // Common metrics.
 static OMIT_FILTER_ROWGROUPS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("omit_filter_rowgroups"));
@@ -134,6 +138,8 @@ static BLOCK_WRITE_NUMS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_block_write_nums"));
 static BLOCK_WRITE_BYTES: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_block_write_bytes"));
+static BLOCK_WRITE_BYTES_PER_COLUMN: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family("fuse_block_write_bytes_per_column"));
 static BLOCK_WRITE_MILLISECONDS: LazyLock<Histogram> =
     LazyLock::new(|| register_histogram_in_milliseconds("fuse_block_write_millioseconds"));
 static BLOCK_INDEX_WRITE_NUMS: LazyLock<Counter> =
@@ -142,6 +148,8 @@ static BLOCK_INDEX_WRITE_BYTES: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_block_index_write_bytes"));
 static BLOCK_INDEX_WRITE_MILLISECONDS: LazyLock<Histogram> =
     LazyLock::new(|| register_histogram_in_milliseconds("fuse_block_index_write_milliseconds"));
+static BLOCK_INDEX_WRITE_SKIPPED_NUMS: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("fuse_block_index_write_skipped_nums"));
 static BLOCK_INDEX_READ_BYTES: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_block_index_read_bytes"));
 static BLOCK_INVERTED_INDEX_WRITE_NUMS: LazyLock<Counter> =
@@ -496,6 +504,14 @@ pub fn metrics_inc_block_write_bytes(c: u64) {
     BLOCK_WRITE_BYTES.inc_by(c);
 }
 
+pub fn metrics_inc_block_write_bytes_per_column(table_id: u64, column_id: u32, c: u64) {
+    let labels = &vec![
+        ("table_id", table_id.to_string()),
+        ("column_id", column_id.to_string()),
+    ];
+    BLOCK_WRITE_BYTES_PER_COLUMN.get_or_create(labels).inc_by(c);
+}
+
 pub fn metrics_inc_block_write_milliseconds(c: u64) {
     BLOCK_WRITE_MILLISECONDS.observe(c as f64);
 }
@@ -512,6 +528,10 @@ pub fn metrics_inc_block_index_write_milliseconds(c: u64) {
     BLOCK_INDEX_WRITE_MILLISECONDS.observe(c as f64);
 }
 
+pub fn metrics_inc_block_index_write_skipped_nums(c: u64) {
+    BLOCK_INDEX_WRITE_SKIPPED_NUMS.inc_by(c);
+}
+
 pub fn metrics_inc_block_inverted_index_write_nums(c: u64) {
     BLOCK_INVERTED_INDEX_WRITE_NUMS.inc_by(c);
 }
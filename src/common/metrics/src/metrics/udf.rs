@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::register_histogram_in_milliseconds;
+use databend_common_base::runtime::metrics::FamilyCounter;
+use databend_common_base::runtime::metrics::Histogram;
+
+use crate::VecLabels;
+
+const METRIC_UDF_RUNTIME_CREATED: &str = "udf_runtime_created";
+const METRIC_UDF_RUNTIME_REUSED: &str = "udf_runtime_reused";
+const METRIC_UDF_WASM_MODULE_LOAD_MS: &str = "udf_wasm_module_load_ms";
+
+static UDF_RUNTIME_CREATED: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family(METRIC_UDF_RUNTIME_CREATED));
+static UDF_RUNTIME_REUSED: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family(METRIC_UDF_RUNTIME_REUSED));
+static UDF_WASM_MODULE_LOAD_MS: LazyLock<Histogram> =
+    LazyLock::new(|| register_histogram_in_milliseconds(METRIC_UDF_WASM_MODULE_LOAD_MS));
+
+const LABEL_LANG: &str = "lang";
+
+pub fn metrics_inc_udf_runtime_created(lang: &str) {
+    let labels = &vec![(LABEL_LANG, lang.to_string())];
+    UDF_RUNTIME_CREATED.get_or_create(labels).inc();
+}
+
+pub fn metrics_inc_udf_runtime_reused(lang: &str) {
+    let labels = &vec![(LABEL_LANG, lang.to_string())];
+    UDF_RUNTIME_REUSED.get_or_create(labels).inc();
+}
+
+pub fn observe_udf_wasm_module_load_time(duration: Duration) {
+    UDF_WASM_MODULE_LOAD_MS.observe(duration.as_millis() as f64);
+}
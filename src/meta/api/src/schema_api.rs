@@ -92,6 +92,8 @@ use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReply;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReq;
 use databend_common_meta_app::schema::UpsertTableOptionReply;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_app::schema::VirtualColumnMeta;
@@ -130,6 +132,11 @@ pub trait SchemaApi: Send + Sync {
         req: RenameDatabaseReq,
     ) -> Result<RenameDatabaseReply, KVAppError>;
 
+    async fn upsert_database_option(
+        &self,
+        req: UpsertDatabaseOptionReq,
+    ) -> Result<UpsertDatabaseOptionReply, KVAppError>;
+
     async fn get_database_history(
         &self,
         req: ListDatabaseReq,
@@ -204,12 +211,26 @@ pub trait SchemaApi: Send + Sync {
 
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>, KVAppError>;
 
+    /// List the names and ids of the tables of a database, without fetching the table metas.
+    async fn list_table_names_and_ids(
+        &self,
+        db_id: MetaId,
+    ) -> Result<Vec<(String, MetaId)>, KVAppError>;
+
     /// Return TableMeta by table_id.
     ///
     /// It returns None instead of KVAppError, if table_id does not exist
     async fn get_table_by_id(&self, table_id: MetaId)
     -> Result<Option<SeqV<TableMeta>>, MetaError>;
 
+    /// Return TableMeta of every table_id in one batch, in the same order as `table_ids`.
+    ///
+    /// A table_id that does not exist yields None.
+    async fn mget_table_metas_by_ids(
+        &self,
+        table_ids: &[MetaId],
+    ) -> Result<Vec<Option<SeqV<TableMeta>>>, KVAppError>;
+
     async fn mget_table_names_by_ids(
         &self,
         table_ids: &[MetaId],
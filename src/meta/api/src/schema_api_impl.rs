@@ -174,6 +174,8 @@ use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReply;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::schema::UpsertTableOptionReply;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
@@ -673,6 +675,64 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn upsert_database_option(
+        &self,
+        req: UpsertDatabaseOptionReq,
+    ) -> Result<UpsertDatabaseOptionReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (_, db_id, db_meta_seq, mut db_meta) =
+                get_db_or_err(self, name_key, "upsert_database_option").await?;
+
+            let db_id_key = DatabaseId { db_id };
+            // update database options
+            let opts = &mut db_meta.options;
+
+            for (k, opt_v) in &req.options {
+                match opt_v {
+                    None => {
+                        opts.remove(k);
+                    }
+                    Some(v) => {
+                        opts.insert(k.to_string(), v.to_string());
+                    }
+                }
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // database is not changed
+                    txn_cond_seq(&db_id_key, Eq, db_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&db_id_key, serialize_struct(&db_meta)?), // db_id -> db_meta
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name :? =(name_key),
+                id :? =(&db_id_key),
+                succ = succ;
+                "upsert_database_option"
+            );
+
+            if succ {
+                return Ok(UpsertDatabaseOptionReply {});
+            }
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>, KVAppError> {
@@ -2231,6 +2291,29 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         Ok(tb_infos)
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn list_table_names_and_ids(
+        &self,
+        db_id: MetaId,
+    ) -> Result<Vec<(String, MetaId)>, KVAppError> {
+        debug!(req :? =(&db_id); "SchemaApi: {}", func_name!());
+
+        let dbid_tbname = DBIdTableName {
+            db_id,
+            // Use empty name to scan all tables
+            table_name: "".to_string(),
+        };
+
+        let (dbid_tbnames, ids) = list_u64_value(self, &dbid_tbname).await?;
+
+        Ok(dbid_tbnames
+            .into_iter()
+            .map(|dbid_tbname| dbid_tbname.table_name)
+            .zip(ids)
+            .collect())
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn get_table_by_id(
@@ -2245,6 +2328,36 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         Ok(seq_table_meta)
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn mget_table_metas_by_ids(
+        &self,
+        table_ids: &[MetaId],
+    ) -> Result<Vec<Option<SeqV<TableMeta>>>, KVAppError> {
+        debug!(req :? =(&table_ids); "SchemaApi: {}", func_name!());
+
+        let meta_kv_keys = table_ids
+            .iter()
+            .map(|id| TableId { table_id: *id }.to_string_key())
+            .collect::<Vec<_>>();
+
+        // Batch get all table-meta by id
+        let seq_metas = self.mget_kv(&meta_kv_keys).await?;
+        let mut table_metas = Vec::with_capacity(seq_metas.len());
+        for seq_meta in seq_metas {
+            let table_meta = match seq_meta {
+                Some(seq_meta) => {
+                    let table_meta: TableMeta = deserialize_struct(&seq_meta.data)?;
+                    Some(SeqV::new(seq_meta.seq, table_meta))
+                }
+                None => None,
+            };
+            table_metas.push(table_meta);
+        }
+
+        Ok(table_metas)
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn mget_table_names_by_ids(
@@ -2572,7 +2685,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             }
         }
 
-        Ok(TruncateTableReply {})
+        Ok(TruncateTableReply::default())
     }
 
     #[logcall::logcall("debug")]
@@ -93,6 +93,7 @@ use databend_common_meta_app::schema::ListTableReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockKey;
 use databend_common_meta_app::schema::RenameDatabaseReq;
+use databend_common_meta_app::schema::RenameSequenceReq;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SetLVTReq;
@@ -5154,6 +5155,105 @@ impl SchemaApiTestSuite {
             assert_eq!(resp.meta.current, 11);
         }
 
+        info!("--- get sequence nextval concurrently");
+        {
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                count: 100,
+            };
+            let (resp1, resp2) = futures::future::join(
+                mt.get_sequence_next_value(req.clone()),
+                mt.get_sequence_next_value(req),
+            )
+            .await;
+            let (resp1, resp2) = (resp1?, resp2?);
+
+            assert_eq!(resp1.end - resp1.start + 1, 100);
+            assert_eq!(resp2.end - resp2.start + 1, 100);
+            // the two reserved ranges must not overlap
+            assert!(resp1.end < resp2.start || resp2.end < resp1.start);
+            assert_eq!(resp1.start.min(resp2.start), 11);
+            assert_eq!(resp1.end.max(resp2.end), 210);
+
+            let req = GetSequenceReq {
+                ident: SequenceIdent::new(&tenant, sequence_name),
+            };
+            let resp = mt.get_sequence(req).await?;
+            assert_eq!(resp.meta.current, 211);
+        }
+
+        info!("--- rename sequence");
+        {
+            let renamed = "seq_renamed";
+            let req = RenameSequenceReq {
+                if_exists: false,
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                new_name: renamed.to_string(),
+            };
+            let _resp = mt.rename_sequence(req).await?;
+
+            let req = GetSequenceReq {
+                ident: SequenceIdent::new(&tenant, sequence_name),
+            };
+            let resp = mt.get_sequence(req).await;
+            assert!(resp.is_err());
+
+            // the renamed sequence continues from its prior value
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(&tenant, renamed),
+                count: 10,
+            };
+            let resp = mt.get_sequence_next_value(req).await?;
+            assert_eq!(resp.start, 211);
+            assert_eq!(resp.end, 220);
+
+            let req = GetSequenceReq {
+                ident: SequenceIdent::new(&tenant, renamed),
+            };
+            let resp = mt.get_sequence(req).await?;
+            assert_eq!(resp.meta.comment, Some("seq".to_string()));
+            assert_eq!(resp.meta.current, 221);
+
+            // rename to an existing sequence fails
+            let req = CreateSequenceReq {
+                create_option: CreateOption::Create,
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                create_on,
+                comment: None,
+            };
+            let _resp = mt.create_sequence(req).await?;
+
+            let req = RenameSequenceReq {
+                if_exists: false,
+                ident: SequenceIdent::new(&tenant, renamed),
+                new_name: sequence_name.to_string(),
+            };
+            let resp = mt.rename_sequence(req).await;
+            assert!(resp.is_err());
+
+            // rename an unknown sequence
+            let req = RenameSequenceReq {
+                if_exists: false,
+                ident: SequenceIdent::new(&tenant, "seq_unknown"),
+                new_name: "seq_unknown2".to_string(),
+            };
+            let resp = mt.rename_sequence(req).await;
+            assert!(resp.is_err());
+
+            let req = RenameSequenceReq {
+                if_exists: true,
+                ident: SequenceIdent::new(&tenant, "seq_unknown"),
+                new_name: "seq_unknown2".to_string(),
+            };
+            let _resp = mt.rename_sequence(req).await?;
+
+            let req = DropSequenceReq {
+                ident: SequenceIdent::new(&tenant, renamed),
+                if_exists: false,
+            };
+            let _resp = mt.drop_sequence(req).await?;
+        }
+
         info!("--- replace sequence");
         {
             let req = CreateSequenceReq {
@@ -20,6 +20,8 @@ use databend_common_meta_app::schema::GetSequenceNextValueReply;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
 use databend_common_meta_app::schema::GetSequenceReply;
 use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::RenameSequenceReply;
+use databend_common_meta_app::schema::RenameSequenceReq;
 
 use crate::kv_app_error::KVAppError;
 
@@ -38,4 +40,9 @@ pub trait SequenceApi: Send + Sync {
     ) -> Result<GetSequenceNextValueReply, KVAppError>;
 
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError>;
+
+    async fn rename_sequence(
+        &self,
+        req: RenameSequenceReq,
+    ) -> Result<RenameSequenceReply, KVAppError>;
 }
@@ -31,8 +31,11 @@ use databend_common_meta_app::schema::GetSequenceNextValueReply;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
 use databend_common_meta_app::schema::GetSequenceReply;
 use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::RenameSequenceReply;
+use databend_common_meta_app::schema::RenameSequenceReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SequenceMeta;
+use databend_common_meta_app::KeyWithTenant;
 use databend_common_meta_kvapi::kvapi;
 use databend_common_meta_types::ConditionResult::Eq;
 use databend_common_meta_types::MatchSeq;
@@ -50,6 +53,7 @@ use crate::send_txn;
 use crate::serialize_struct;
 use crate::txn_backoff::txn_backoff;
 use crate::txn_cond_seq;
+use crate::txn_op_del;
 use crate::txn_op_put;
 use crate::SequenceApi;
 
@@ -86,7 +90,9 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
                         format!("create sequence: {:?}", sequence_name),
                     )),
                 ))),
-                CreateOption::CreateIfNotExists => Ok(CreateSequenceReply {}),
+                CreateOption::CreateIfNotExists => Ok(CreateSequenceReply {
+                    new_sequence: false,
+                }),
                 CreateOption::CreateOrReplace => {
                     Err(KVAppError::AppError(AppError::SequenceError(
                         SequenceError::CreateSequenceError(CreateSequenceError::new(
@@ -97,7 +103,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
                 }
             }
         } else {
-            Ok(CreateSequenceReply {})
+            Ok(CreateSequenceReply { new_sequence: true })
         }
     }
 
@@ -205,6 +211,75 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
 
         Ok(DropSequenceReply { prev })
     }
+
+    async fn rename_sequence(
+        &self,
+        req: RenameSequenceReq,
+    ) -> Result<RenameSequenceReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let sequence_name = req.ident.name();
+        let new_ident = SequenceIdent::new(req.ident.tenant(), &req.new_name);
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (sequence_seq, sequence_meta) = get_pb_value(self, &req.ident).await?;
+            if sequence_seq == 0 {
+                return if req.if_exists {
+                    Ok(RenameSequenceReply {})
+                } else {
+                    Err(KVAppError::AppError(AppError::SequenceError(
+                        SequenceError::UnknownSequence(UnknownSequence::new(
+                            sequence_name,
+                            format!("rename_sequence: {:?}", sequence_name),
+                        )),
+                    )))
+                };
+            }
+            // the meta is moved as is, the renamed sequence continues from its current value
+            let mut sequence_meta = sequence_meta.unwrap();
+            sequence_meta.update_on = Utc::now();
+
+            let (new_sequence_seq, _) = get_pb_value(self, &new_ident).await?;
+            if new_sequence_seq > 0 {
+                return Err(KVAppError::AppError(AppError::SequenceError(
+                    SequenceError::SequenceAlreadyExists(SequenceAlreadyExists::new(
+                        &req.new_name,
+                        format!("rename_sequence: {:?} to {:?}", sequence_name, req.new_name),
+                    )),
+                )));
+            }
+
+            let condition = vec![
+                txn_cond_seq(&req.ident, Eq, sequence_seq),
+                txn_cond_seq(&new_ident, Eq, 0),
+            ];
+            let if_then = vec![
+                txn_op_del(&req.ident),                                    // del old name
+                txn_op_put(&new_ident, serialize_struct(&sequence_meta)?), // new name -> meta
+            ];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                ident :?= (req.ident),
+                new_name :?= (req.new_name),
+                succ = succ;
+                "rename_sequence"
+            );
+            if succ {
+                return Ok(RenameSequenceReply {});
+            }
+        }
+    }
 }
 
 /// Returns (seq, sequence_meta)
@@ -985,6 +985,7 @@ pub async fn convert_share_meta_to_spec(
         version: 1,
         database,
         tables,
+        views: vec![],
         tenants: Vec::from_iter(share_meta.accounts.into_iter()),
         db_privileges,
         comment: share_meta.comment.clone(),
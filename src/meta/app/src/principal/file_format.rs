@@ -18,8 +18,11 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::str::FromStr;
 
+use chrono_tz::Tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
 use databend_common_io::constants::NULL_BYTES_ESCAPE;
 use databend_common_io::escape_string;
 use databend_common_io::GeometryDataType;
@@ -32,10 +35,13 @@ use crate::principal::StageFileFormatType;
 
 const OPT_FIELD_DELIMITER: &str = "field_delimiter";
 const OPT_RECORDE_DELIMITER: &str = "record_delimiter";
+/// CSV record delimiter which is detected from the head of each file when loading.
+pub const RECORD_DELIMITER_AUTO: &str = "AUTO";
 const OPT_SKIP_HEADER: &str = "skip_header";
 const OPT_OUTPUT_HEADER: &str = "output_header";
 const OPT_NAN_DISPLAY: &str = "nan_display";
 const OPT_NULL_DISPLAY: &str = "null_display";
+const OPT_OUTPUT_NULL_DISPLAY: &str = "output_null_display";
 const OPT_ESCAPE: &str = "escape";
 const OPT_QUOTE: &str = "quote";
 const OPT_ROW_TAG: &str = "row_tag";
@@ -45,6 +51,10 @@ const NULL_FIELD_AS: &str = "null_field_as";
 const NULL_IF: &str = "null_if";
 const OPT_EMPTY_FIELD_AS: &str = "empty_field_as";
 const OPT_BINARY_FORMAT: &str = "binary_format";
+const OPT_TIME_ZONE: &str = "time_zone";
+const OPT_SKIP_BLANK_LINES: &str = "skip_blank_lines";
+const OPT_WIDTHS: &str = "widths";
+const OPT_TRIM: &str = "trim";
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FileFormatOptionsAst {
@@ -76,10 +86,10 @@ impl FileFormatOptionsAst {
         }
     }
 
-    fn take_compression(&mut self) -> Result<StageFileCompression> {
+    fn take_compression(&mut self, default: StageFileCompression) -> Result<StageFileCompression> {
         match self.options.remove("compression") {
             Some(c) => StageFileCompression::from_str(&c).map_err(ErrorCode::IllegalFileFormat),
-            None => Ok(StageFileCompression::None),
+            None => Ok(default),
         }
     }
 
@@ -113,6 +123,7 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    FixedWidth(FixedWidthFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -124,6 +135,7 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::FixedWidth(_) => StageFileFormatType::FixedWidth,
         }
     }
 
@@ -148,6 +160,26 @@ impl FileFormatParams {
         }
     }
 
+    /// The `TIME_ZONE` option, only text formats with naive timestamps have it.
+    pub fn time_zone(&self) -> Option<&str> {
+        match self {
+            FileFormatParams::Csv(v) => v.time_zone.as_deref(),
+            FileFormatParams::Tsv(v) => v.time_zone.as_deref(),
+            FileFormatParams::NdJson(v) => v.time_zone.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The `SKIP_BLANK_LINES` option, only line-based text formats have it.
+    pub fn skip_blank_lines(&self) -> bool {
+        match self {
+            FileFormatParams::Csv(v) => v.skip_blank_lines,
+            FileFormatParams::Tsv(v) => v.skip_blank_lines,
+            FileFormatParams::NdJson(v) => v.skip_blank_lines,
+            _ => false,
+        }
+    }
+
     pub fn compression(&self) -> StageFileCompression {
         match self {
             FileFormatParams::Csv(v) => v.compression,
@@ -155,7 +187,8 @@ impl FileFormatParams {
             FileFormatParams::NdJson(v) => v.compression,
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
-            FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Parquet(v) => v.compression,
+            FileFormatParams::FixedWidth(v) => v.compression,
         }
     }
 
@@ -166,21 +199,26 @@ impl FileFormatParams {
             StageFileFormatType::Xml => {
                 let default = XmlFileFormatParams::default();
                 let row_tag = ast.take_string(OPT_ROW_TAG, default.row_tag);
-                let compression = ast.take_compression()?;
+                let compression = ast.take_compression(StageFileCompression::None)?;
                 FileFormatParams::Xml(XmlFileFormatParams {
                     compression,
                     row_tag,
                 })
             }
             StageFileFormatType::Json => {
-                let compression = ast.take_compression()?;
+                let compression = ast.take_compression(StageFileCompression::None)?;
                 FileFormatParams::Json(JsonFileFormatParams { compression })
             }
             StageFileFormatType::NdJson => {
-                let compression = ast.take_compression()?;
+                let compression = ast.take_compression(StageFileCompression::None)?;
                 let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
                 let null_field_as = ast.options.remove(NULL_FIELD_AS);
                 let null_if = ast.options.remove(NULL_IF);
+                let time_zone = ast.options.remove(OPT_TIME_ZONE);
+                let skip_blank_lines = ast.take_bool(
+                    OPT_SKIP_BLANK_LINES,
+                    NdJsonFileFormatParams::default().skip_blank_lines,
+                )?;
                 let null_if = match null_if {
                     None => {
                         vec![]
@@ -198,25 +236,34 @@ impl FileFormatParams {
                     missing_field_as.as_deref(),
                     null_field_as.as_deref(),
                     null_if,
+                    time_zone,
+                    skip_blank_lines,
                 )?)
             }
             StageFileFormatType::Parquet => {
+                let default = ParquetFileFormatParams::default();
+                let compression = ast.take_compression(default.compression)?;
                 let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
                 FileFormatParams::Parquet(ParquetFileFormatParams::try_create(
+                    compression,
                     missing_field_as.as_deref(),
                 )?)
             }
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
-                let compression = ast.take_compression()?;
+                let compression = ast.take_compression(StageFileCompression::None)?;
                 let headers = ast.take_u64(OPT_SKIP_HEADER, default.headers)?;
                 let field_delimiter = ast.take_string(OPT_FIELD_DELIMITER, default.field_delimiter);
-                let record_delimiter =
+                let mut record_delimiter =
                     ast.take_string(OPT_RECORDE_DELIMITER, default.record_delimiter);
+                if record_delimiter.eq_ignore_ascii_case(RECORD_DELIMITER_AUTO) {
+                    record_delimiter = RECORD_DELIMITER_AUTO.to_string();
+                }
                 let nan_display = ast.take_string(OPT_NAN_DISPLAY, default.nan_display);
                 let escape = ast.take_string(OPT_ESCAPE, default.escape);
                 let quote = ast.take_string(OPT_QUOTE, default.quote);
                 let null_display = ast.take_string(OPT_NULL_DISPLAY, default.null_display);
+                let output_null_display = ast.options.remove(OPT_OUTPUT_NULL_DISPLAY);
                 let empty_field_as = ast
                     .options
                     .remove(OPT_EMPTY_FIELD_AS)
@@ -234,12 +281,16 @@ impl FileFormatParams {
                     default.error_on_column_count_mismatch,
                 )?;
                 let output_header = ast.take_bool(OPT_OUTPUT_HEADER, default.output_header)?;
+                let time_zone = ast.options.remove(OPT_TIME_ZONE);
+                let skip_blank_lines =
+                    ast.take_bool(OPT_SKIP_BLANK_LINES, default.skip_blank_lines)?;
                 FileFormatParams::Csv(CsvFileFormatParams {
                     compression,
                     headers,
                     field_delimiter,
                     record_delimiter,
                     null_display,
+                    output_null_display,
                     nan_display,
                     escape,
                     quote,
@@ -248,11 +299,13 @@ impl FileFormatParams {
                     binary_format,
                     output_header,
                     geometry_format: std::default::Default::default(),
+                    time_zone,
+                    skip_blank_lines,
                 })
             }
             StageFileFormatType::Tsv => {
                 let default = TsvFileFormatParams::default();
-                let compression = ast.take_compression()?;
+                let compression = ast.take_compression(StageFileCompression::None)?;
                 let headers = ast.take_u64(OPT_SKIP_HEADER, default.headers)?;
                 let field_delimiter = ast.take_string(OPT_FIELD_DELIMITER, default.field_delimiter);
                 let record_delimiter =
@@ -260,6 +313,9 @@ impl FileFormatParams {
                 let nan_display = ast.take_string(OPT_NAN_DISPLAY, default.nan_display);
                 let escape = ast.take_string(OPT_ESCAPE, default.escape);
                 let quote = ast.take_string(OPT_QUOTE, default.quote);
+                let time_zone = ast.options.remove(OPT_TIME_ZONE);
+                let skip_blank_lines =
+                    ast.take_bool(OPT_SKIP_BLANK_LINES, default.skip_blank_lines)?;
                 FileFormatParams::Tsv(TsvFileFormatParams {
                     compression,
                     headers,
@@ -268,6 +324,25 @@ impl FileFormatParams {
                     nan_display,
                     quote,
                     escape,
+                    time_zone,
+                    skip_blank_lines,
+                })
+            }
+            StageFileFormatType::FixedWidth => {
+                let compression = ast.take_compression(StageFileCompression::None)?;
+                let widths = match ast.options.remove(OPT_WIDTHS) {
+                    Some(s) => parse_widths(&s)?,
+                    None => {
+                        return Err(ErrorCode::IllegalFileFormat(
+                            "Invalid FILE_FORMAT options: WIDTHS is required for FIXED_WIDTH, e.g. WIDTHS = ('3', '10').",
+                        ));
+                    }
+                };
+                let trim = ast.take_bool(OPT_TRIM, FixedWidthFileFormatParams::default().trim)?;
+                FileFormatParams::FixedWidth(FixedWidthFileFormatParams {
+                    compression,
+                    widths,
+                    trim,
                 })
             }
             _ => {
@@ -296,6 +371,113 @@ impl FileFormatParams {
         }
     }
 
+    /// The inverse of [`FileFormatParams::try_from_ast`], every option is emitted with its
+    /// canonical key, optional ones only when they are set.
+    pub fn to_options_map(&self) -> BTreeMap<String, String> {
+        let mut options = BTreeMap::new();
+        let mut insert = |key: &str, value: String| {
+            options.insert(key.to_string(), value);
+        };
+        insert("type", self.get_type().to_string());
+        match self {
+            FileFormatParams::Csv(p) => {
+                insert("compression", p.compression.to_string());
+                insert(OPT_SKIP_HEADER, p.headers.to_string());
+                insert(OPT_FIELD_DELIMITER, p.field_delimiter.clone());
+                insert(OPT_RECORDE_DELIMITER, p.record_delimiter.clone());
+                insert(OPT_NAN_DISPLAY, p.nan_display.clone());
+                insert(OPT_ESCAPE, p.escape.clone());
+                insert(OPT_QUOTE, p.quote.clone());
+                insert(OPT_NULL_DISPLAY, p.null_display.clone());
+                if let Some(v) = &p.output_null_display {
+                    insert(OPT_OUTPUT_NULL_DISPLAY, v.clone());
+                }
+                insert(OPT_EMPTY_FIELD_AS, p.empty_field_as.to_string());
+                insert(OPT_BINARY_FORMAT, p.binary_format.to_string());
+                insert(
+                    OPT_ERROR_ON_COLUMN_COUNT_MISMATCH,
+                    p.error_on_column_count_mismatch.to_string(),
+                );
+                insert(OPT_OUTPUT_HEADER, p.output_header.to_string());
+                if let Some(v) = &p.time_zone {
+                    insert(OPT_TIME_ZONE, v.clone());
+                }
+                insert(OPT_SKIP_BLANK_LINES, p.skip_blank_lines.to_string());
+            }
+            FileFormatParams::Tsv(p) => {
+                insert("compression", p.compression.to_string());
+                insert(OPT_SKIP_HEADER, p.headers.to_string());
+                insert(OPT_FIELD_DELIMITER, p.field_delimiter.clone());
+                insert(OPT_RECORDE_DELIMITER, p.record_delimiter.clone());
+                insert(OPT_NAN_DISPLAY, p.nan_display.clone());
+                insert(OPT_ESCAPE, p.escape.clone());
+                insert(OPT_QUOTE, p.quote.clone());
+                if let Some(v) = &p.time_zone {
+                    insert(OPT_TIME_ZONE, v.clone());
+                }
+                insert(OPT_SKIP_BLANK_LINES, p.skip_blank_lines.to_string());
+            }
+            FileFormatParams::NdJson(p) => {
+                insert("compression", p.compression.to_string());
+                insert(MISSING_FIELD_AS, p.missing_field_as.to_string());
+                insert(NULL_FIELD_AS, p.null_field_as.to_string());
+                insert(NULL_IF, serde_json::to_string(&p.null_if).unwrap());
+                if let Some(v) = &p.time_zone {
+                    insert(OPT_TIME_ZONE, v.clone());
+                }
+                insert(OPT_SKIP_BLANK_LINES, p.skip_blank_lines.to_string());
+            }
+            FileFormatParams::Json(p) => {
+                insert("compression", p.compression.to_string());
+            }
+            FileFormatParams::Xml(p) => {
+                insert("compression", p.compression.to_string());
+                insert(OPT_ROW_TAG, p.row_tag.clone());
+            }
+            FileFormatParams::Parquet(p) => {
+                insert("compression", p.compression.to_string());
+                insert(MISSING_FIELD_AS, p.missing_field_as.to_string());
+            }
+            FileFormatParams::FixedWidth(p) => {
+                insert("compression", p.compression.to_string());
+                insert(OPT_WIDTHS, serde_json::to_string(&p.widths).unwrap());
+                insert(OPT_TRIM, p.trim.to_string());
+            }
+        }
+        options
+    }
+
+    /// Check the options can be used to load files into columns of `schema`, called when
+    /// the COPY is planned instead of failing in the middle of loading.
+    pub fn validate_for_schema(&self, schema: &TableSchema) -> Result<()> {
+        if let FileFormatParams::FixedWidth(p) = self {
+            if p.widths.len() != schema.num_fields() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "FIXED_WIDTH file format has {} widths but the table has {} columns, \
+                    expecting one width for each column.",
+                    p.widths.len(),
+                    schema.num_fields()
+                )));
+            }
+        }
+        if let FileFormatParams::NdJson(_) = self {
+            // There is no BINARY_FORMAT option to decode JSON strings into binary.
+            if let Some(field) = schema
+                .fields()
+                .iter()
+                .find(|f| contains_binary(f.data_type()))
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Column '{}' of type {} can not be loaded from NDJSON files, which have no BINARY_FORMAT option. \
+                    Please use CSV or load the values into a STRING column and convert them with FROM_HEX or FROM_BASE64.",
+                    field.name(),
+                    field.data_type()
+                )));
+            }
+        }
+        Ok(())
+    }
+
     pub fn check(&self) -> std::result::Result<(), String> {
         macro_rules! check_option {
             ($params:expr, $option_name:ident) => {{
@@ -310,6 +492,23 @@ impl FileFormatParams {
             }};
         }
 
+        macro_rules! check_optional_option {
+            ($params:expr, $option_name:ident) => {{
+                match &$params.$option_name {
+                    Some(v) => {
+                        paste! { let check_fn  = [<check_$option_name>]; }
+                        check_fn(v).map_err(|msg| {
+                            format!(
+                                "{} is currently set to '{v}'. {msg}",
+                                stringify!($option_name).to_ascii_uppercase(),
+                            )
+                        })
+                    }
+                    None => Ok(()),
+                }
+            }};
+        }
+
         match self {
             FileFormatParams::Tsv(p) => {
                 check_option!(p, field_delimiter)?;
@@ -317,17 +516,37 @@ impl FileFormatParams {
                 check_option!(p, quote)?;
                 check_option!(p, escape)?;
                 check_option!(p, nan_display)?;
+                check_optional_option!(p, time_zone)?;
             }
             FileFormatParams::Csv(p) => {
                 check_option!(p, field_delimiter)?;
-                check_option!(p, record_delimiter)?;
+                if !p.is_auto_record_delimiter() {
+                    check_option!(p, record_delimiter)?;
+                }
                 check_option!(p, quote)?;
                 check_option!(p, escape)?;
                 check_option!(p, nan_display)?;
+                check_optional_option!(p, time_zone)?;
+            }
+            FileFormatParams::NdJson(p) => {
+                check_optional_option!(p, time_zone)?;
             }
             FileFormatParams::Xml(p) => {
                 check_option!(p, row_tag)?;
             }
+            FileFormatParams::FixedWidth(p) => {
+                check_widths(&p.widths).map_err(|msg| {
+                    format!(
+                        "WIDTHS is currently set to {}. {msg}",
+                        display_widths(&p.widths)
+                    )
+                })?;
+            }
+            FileFormatParams::Parquet(p) => {
+                check_parquet_compression(p.compression).map_err(|msg| {
+                    format!("COMPRESSION is currently set to '{}'. {msg}", p.compression)
+                })?;
+            }
             _ => {}
         }
         Ok(())
@@ -336,9 +555,7 @@ impl FileFormatParams {
 
 impl Default for FileFormatParams {
     fn default() -> Self {
-        FileFormatParams::Parquet(ParquetFileFormatParams {
-            missing_field_as: NullAs::Error,
-        })
+        FileFormatParams::Parquet(ParquetFileFormatParams::default())
     }
 }
 
@@ -368,9 +585,16 @@ pub struct CsvFileFormatParams {
     // field
     pub binary_format: BinaryFormat,
     pub null_display: String,
+    /// Representation of NULL in the query results sent to the client, `null_display` is used
+    /// if not set. Unloading to a stage always uses `null_display`.
+    pub output_null_display: Option<String>,
     pub nan_display: String,
     pub empty_field_as: EmptyFieldAs,
     pub geometry_format: GeometryDataType,
+    /// Timezone of timestamps without zone in the files, defaults to the session timezone.
+    pub time_zone: Option<String>,
+    /// Discard empty and whitespace-only lines instead of loading them as rows.
+    pub skip_blank_lines: bool,
 }
 
 impl Default for CsvFileFormatParams {
@@ -381,6 +605,7 @@ impl Default for CsvFileFormatParams {
             field_delimiter: ",".to_string(),
             record_delimiter: "\n".to_string(),
             null_display: NULL_BYTES_ESCAPE.to_string(),
+            output_null_display: None,
             nan_display: "NaN".to_string(),
             escape: "".to_string(),
             quote: "\"".to_string(),
@@ -389,6 +614,8 @@ impl Default for CsvFileFormatParams {
             output_header: false,
             binary_format: Default::default(),
             geometry_format: GeometryDataType::default(),
+            time_zone: None,
+            skip_blank_lines: false,
         }
     }
 }
@@ -400,6 +627,17 @@ impl CsvFileFormatParams {
             _ => unreachable!(),
         }
     }
+
+    pub fn get_output_null_display(&self) -> &str {
+        self.output_null_display
+            .as_deref()
+            .unwrap_or(&self.null_display)
+    }
+
+    /// Whether the record delimiter is sniffed from the data, see [`RECORD_DELIMITER_AUTO`].
+    pub fn is_auto_record_delimiter(&self) -> bool {
+        self.record_delimiter == RECORD_DELIMITER_AUTO
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -411,6 +649,10 @@ pub struct TsvFileFormatParams {
     pub nan_display: String,
     pub escape: String,
     pub quote: String,
+    /// Timezone of timestamps without zone in the files, defaults to the session timezone.
+    pub time_zone: Option<String>,
+    /// Discard empty and whitespace-only lines instead of loading them as rows.
+    pub skip_blank_lines: bool,
 }
 
 impl Default for TsvFileFormatParams {
@@ -423,6 +665,8 @@ impl Default for TsvFileFormatParams {
             nan_display: "nan".to_string(),
             escape: "\\".to_string(),
             quote: "\'".to_string(),
+            time_zone: None,
+            skip_blank_lines: false,
         }
     }
 }
@@ -491,7 +735,7 @@ impl FromStr for EmptyFieldAs {
             "null" => Ok(Self::Null),
             "field_default" => Ok(Self::FieldDefault),
             _ => Err(ErrorCode::InvalidArgument(format!(
-                "Invalid option value. EMPTY_FILED_AS is currently set to {s}. The valid values are NULL | STRING | FIELD_DEFAULT."
+                "Invalid option value. EMPTY_FIELD_AS is currently set to {s}. The valid values are NULL | STRING | FIELD_DEFAULT."
             ))),
         }
     }
@@ -500,7 +744,7 @@ impl FromStr for EmptyFieldAs {
 impl Display for EmptyFieldAs {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::FieldDefault => write!(f, "FILED_DEFAULT"),
+            Self::FieldDefault => write!(f, "FIELD_DEFAULT"),
             Self::Null => write!(f, "NULL"),
             Self::String => write!(f, "STRING"),
         }
@@ -600,6 +844,11 @@ pub struct NdJsonFileFormatParams {
     pub missing_field_as: NullAs,
     pub null_field_as: NullAs,
     pub null_if: Vec<String>,
+    /// Timezone of timestamps without zone in the files, defaults to the session timezone.
+    pub time_zone: Option<String>,
+    /// Blank lines are never a JSON record and are always discarded, this is kept for
+    /// parity with the other line-based formats.
+    pub skip_blank_lines: bool,
 }
 
 impl NdJsonFileFormatParams {
@@ -608,9 +857,11 @@ impl NdJsonFileFormatParams {
         missing_field_as: Option<&str>,
         null_field_as: Option<&str>,
         null_if: Vec<String>,
+        time_zone: Option<String>,
+        skip_blank_lines: bool,
     ) -> Result<Self> {
         let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
-        let null_field_as = NullAs::parse(null_field_as, MISSING_FIELD_AS, NullAs::Null)?;
+        let null_field_as = NullAs::parse(null_field_as, NULL_FIELD_AS, NullAs::Null)?;
         if matches!(null_field_as, NullAs::Error) {
             return Err(ErrorCode::InvalidArgument(
                 "Invalid option value: NULL_FIELD_AS is set to ERROR. The valid values are NULL | FIELD_DEFAULT.",
@@ -621,6 +872,8 @@ impl NdJsonFileFormatParams {
             missing_field_as,
             null_field_as,
             null_if,
+            time_zone,
+            skip_blank_lines,
         })
     }
 }
@@ -632,6 +885,8 @@ impl Default for NdJsonFileFormatParams {
             missing_field_as: NullAs::Error,
             null_field_as: NullAs::FieldDefault,
             null_if: vec![],
+            time_zone: None,
+            skip_blank_lines: false,
         }
     }
 }
@@ -645,15 +900,61 @@ impl NdJsonFileFormatParams {
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {
+    /// Compression of the pages when unloading, the file itself is never compressed.
+    pub compression: StageFileCompression,
     pub missing_field_as: NullAs,
 }
 
 impl ParquetFileFormatParams {
-    pub fn try_create(missing_field_as: Option<&str>) -> Result<Self> {
+    pub fn try_create(
+        compression: StageFileCompression,
+        missing_field_as: Option<&str>,
+    ) -> Result<Self> {
         let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
-        Ok(Self { missing_field_as })
+        Ok(Self {
+            compression,
+            missing_field_as,
+        })
+    }
+}
+
+impl Default for ParquetFileFormatParams {
+    fn default() -> Self {
+        ParquetFileFormatParams {
+            compression: StageFileCompression::Snappy,
+            missing_field_as: NullAs::Error,
+        }
+    }
+}
+
+/// Fixed-width (positional) text, each field of a record occupies a fixed number of bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixedWidthFileFormatParams {
+    pub compression: StageFileCompression,
+    /// Width in bytes of each column, in column order.
+    pub widths: Vec<u64>,
+    /// Trim the padding spaces around each field.
+    pub trim: bool,
+}
+
+impl FixedWidthFileFormatParams {
+    pub fn downcast_unchecked(params: &FileFormatParams) -> &FixedWidthFileFormatParams {
+        match params {
+            FileFormatParams::FixedWidth(p) => p,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Default for FixedWidthFileFormatParams {
+    fn default() -> Self {
+        FixedWidthFileFormatParams {
+            compression: StageFileCompression::None,
+            widths: vec![],
+            trim: true,
+        }
     }
 }
 
@@ -667,7 +968,7 @@ impl Display for FileFormatParams {
                      FIELD_DELIMITER = '{}' RECORD_DELIMITER = '{}' QUOTE = '{}' ESCAPE = '{}' \
                      SKIP_HEADER= {} OUTPUT_HEADER= {} \
                      NULL_DISPLAY = '{}' NAN_DISPLAY = '{}'  EMPTY_FIELD_AS = {} BINARY_FORMAT = {} \
-                     ERROR_ON_COLUMN_COUNT_MISMATCH = {}",
+                     ERROR_ON_COLUMN_COUNT_MISMATCH = {} SKIP_BLANK_LINES = {}{}{}",
                     params.compression,
                     escape_string(&params.field_delimiter),
                     escape_string(&params.record_delimiter),
@@ -680,6 +981,12 @@ impl Display for FileFormatParams {
                     params.empty_field_as,
                     params.binary_format,
                     params.error_on_column_count_mismatch,
+                    params.skip_blank_lines,
+                    match &params.output_null_display {
+                        Some(v) => format!(" OUTPUT_NULL_DISPLAY = '{}'", escape_string(v)),
+                        None => "".to_string(),
+                    },
+                    display_time_zone(&params.time_zone),
                 )
             }
             FileFormatParams::Tsv(params) => {
@@ -688,7 +995,7 @@ impl Display for FileFormatParams {
                     "TYPE = TSV COMPRESSION = {:?} \
                      FIELD_DELIMITER = '{}' RECORD_DELIMITER = '{}' ESCAPE = '{}'  QUOTE = '{}' \
                      SKIP_HEADER = {} \
-                     NAN_DISPLAY = '{}'",
+                     NAN_DISPLAY = '{}' SKIP_BLANK_LINES = {}{}",
                     params.compression,
                     escape_string(&params.field_delimiter),
                     escape_string(&params.record_delimiter),
@@ -696,13 +1003,16 @@ impl Display for FileFormatParams {
                     escape_string(&params.quote),
                     params.headers,
                     escape_string(&params.nan_display),
+                    params.skip_blank_lines,
+                    display_time_zone(&params.time_zone),
                 )
             }
             FileFormatParams::Xml(params) => {
                 write!(
                     f,
                     "TYPE = XML COMPRESSION = {:?} ROW_TAG = '{}'",
-                    params.compression, params.row_tag
+                    params.compression,
+                    escape_string(&params.row_tag)
                 )
             }
             FileFormatParams::Json(params) => {
@@ -711,29 +1021,130 @@ impl Display for FileFormatParams {
             FileFormatParams::NdJson(params) => {
                 write!(
                     f,
-                    "TYPE = NDJSON, COMPRESSION = {:?} MISSING_FIELD_AS = {} NULL_FIELDS_AA = {}",
-                    params.compression, params.missing_field_as, params.null_field_as
+                    "TYPE = NDJSON COMPRESSION = {:?} MISSING_FIELD_AS = {} NULL_FIELD_AS = {} NULL_IF = {} SKIP_BLANK_LINES = {}{}",
+                    params.compression,
+                    params.missing_field_as,
+                    params.null_field_as,
+                    display_string_list(&params.null_if),
+                    params.skip_blank_lines,
+                    display_time_zone(&params.time_zone),
                 )
             }
             FileFormatParams::Parquet(params) => {
                 write!(
                     f,
-                    "TYPE = PARQUET MISSING_FIELD_AS = {}",
-                    params.missing_field_as
+                    "TYPE = PARQUET COMPRESSION = {:?} MISSING_FIELD_AS = {}",
+                    params.compression, params.missing_field_as
+                )
+            }
+            FileFormatParams::FixedWidth(params) => {
+                write!(
+                    f,
+                    "TYPE = FIXED_WIDTH COMPRESSION = {:?} WIDTHS = {} TRIM = {}",
+                    params.compression,
+                    display_widths(&params.widths),
+                    params.trim
                 )
             }
         }
     }
 }
 
+fn contains_binary(data_type: &TableDataType) -> bool {
+    match data_type {
+        TableDataType::Binary => true,
+        TableDataType::Nullable(ty) | TableDataType::Array(ty) | TableDataType::Map(ty) => {
+            contains_binary(ty)
+        }
+        TableDataType::Tuple { fields_type, .. } => fields_type.iter().any(contains_binary),
+        _ => false,
+    }
+}
+
+fn display_string_list(values: &[String]) -> String {
+    let values = values
+        .iter()
+        .map(|v| format!("'{}'", escape_string(v)))
+        .collect::<Vec<_>>();
+    format!("({})", values.join(", "))
+}
+
+/// Written as a string list, the form the parser accepts for list options.
+fn display_widths(widths: &[u64]) -> String {
+    let widths = widths.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+    display_string_list(&widths)
+}
+
+/// `WIDTHS` is passed as a JSON list, of numbers or of strings holding numbers.
+fn parse_widths(s: &str) -> Result<Vec<u64>> {
+    let invalid = || {
+        ErrorCode::InvalidArgument(format!(
+            "Invalid option value: WIDTHS is currently set to {s} (in JSON). The valid values are a list of positive integers."
+        ))
+    };
+    if let Ok(widths) = serde_json::from_str::<Vec<u64>>(s) {
+        return Ok(widths);
+    }
+    let values: Vec<String> = serde_json::from_str(s).map_err(|_| invalid())?;
+    values
+        .iter()
+        .map(|v| v.trim().parse::<u64>().map_err(|_| invalid()))
+        .collect()
+}
+
+pub fn check_widths(widths: &[u64]) -> std::result::Result<(), String> {
+    if widths.is_empty() {
+        return Err("Expecting at least one width.".to_string());
+    }
+    if widths.contains(&0) {
+        return Err("Expecting every width to be greater than 0.".to_string());
+    }
+    Ok(())
+}
+
+fn display_time_zone(time_zone: &Option<String>) -> String {
+    match time_zone {
+        Some(v) => format!(" TIME_ZONE = '{}'", escape_string(v)),
+        None => "".to_string(),
+    }
+}
+
 pub fn check_row_tag(option: &str) -> std::result::Result<(), String> {
     let len = option.as_bytes().len();
     let (max, min) = (1024, 1);
     if len > max || len < min {
-        Err("Expecting a non-empty string containing at most 1024 characters.".to_string())
-    } else {
-        Ok(())
+        return Err("Expecting a non-empty string containing at most 1024 characters.".to_string());
+    }
+
+    // The tag is written as an element name, so it must match the `Name` production of XML.
+    let mut chars = option.chars();
+    let valid = chars.next().is_some_and(is_xml_name_start_char) && chars.all(is_xml_name_char);
+    if !valid {
+        return Err(format!(
+            "'{}' is not a valid XML element name, expecting a letter, '_' or ':' followed by letters, digits, '_', ':', '-' or '.'.",
+            escape_string(option)
+        ));
     }
+    Ok(())
+}
+
+/// `NameStartChar` in <https://www.w3.org/TR/xml/#NT-NameStartChar>.
+fn is_xml_name_start_char(c: char) -> bool {
+    matches!(c,
+        ':' | 'A'..='Z' | '_' | 'a'..='z'
+        | '\u{C0}'..='\u{D6}' | '\u{D8}'..='\u{F6}' | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}' | '\u{37F}'..='\u{1FFF}' | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}' | '\u{2C00}'..='\u{2FEF}' | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}' | '\u{FDF0}'..='\u{FFFD}' | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+/// `NameChar` in <https://www.w3.org/TR/xml/#NT-NameChar>.
+fn is_xml_name_char(c: char) -> bool {
+    is_xml_name_start_char(c)
+        || matches!(c,
+            '-' | '.' | '0'..='9' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}'
+        )
 }
 
 pub fn check_field_delimiter(option: &str) -> std::result::Result<(), String> {
@@ -765,6 +1176,26 @@ pub fn check_escape(option: &str) -> std::result::Result<(), String> {
     check_choices(option, &["\\", ""])
 }
 
+pub fn check_time_zone(option: &str) -> std::result::Result<(), String> {
+    match option.parse::<Tz>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(
+            "Expecting a timezone name of the IANA time zone database, e.g. 'UTC' or 'Asia/Shanghai'."
+                .into(),
+        ),
+    }
+}
+
+/// The page codecs supported by the parquet writer.
+pub fn check_parquet_compression(option: StageFileCompression) -> std::result::Result<(), String> {
+    match option {
+        StageFileCompression::None | StageFileCompression::Snappy | StageFileCompression::Zstd => {
+            Ok(())
+        }
+        _ => Err("The valid values are 'none', 'snappy' and 'zstd'.".into()),
+    }
+}
+
 pub fn check_choices(v: &str, choices: &[&str]) -> std::result::Result<(), String> {
     if !choices.contains(&v) {
         let choices = choices
@@ -16,6 +16,8 @@ use std::fmt;
 use std::fmt::Display;
 
 use anyerror::AnyError;
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_exception::ErrorCode;
 
 use crate::principal::OwnershipObject;
@@ -24,6 +26,11 @@ use crate::principal::OwnershipObject;
 pub struct OwnershipInfo {
     pub object: OwnershipObject,
     pub role: String,
+    /// When the ownership was last granted or transferred.
+    ///
+    /// Records written before this field existed load as the default timestamp.
+    #[serde(default)]
+    pub updated_on: DateTime<Utc>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
@@ -17,7 +17,7 @@ use databend_common_meta_kvapi::kvapi::KeyCodec;
 
 /// [`OwnershipObject`] is used to maintain the grant object that support rename by id. Using ID over name
 /// have many benefits, it can avoid lost privileges after the object get renamed.
-/// But Stage and UDF do not support the concept of renaming and do not have ids, so names can be used.
+/// But Stage, UDF and Sequence do not support the concept of renaming and do not have ids, so names can be used.
 ///
 /// It could be a tenant's database, a tenant's table etc.
 /// It is in form of `__fd_object_owners/<tenant>/<object>`.
@@ -28,6 +28,7 @@ use databend_common_meta_kvapi::kvapi::KeyCodec;
 /// - `table-by-catalog-id/<catalog>/<table_id>`
 /// - `stage-by-name/<stage_name>`
 /// - `udf-by-name/<udf_name>`
+/// - `sequence-by-name/<sequence_name>`
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum OwnershipObject {
     /// used on the fuse databases
@@ -50,6 +51,10 @@ pub enum OwnershipObject {
     UDF {
         name: String,
     },
+
+    Sequence {
+        name: String,
+    },
 }
 
 impl OwnershipObject {
@@ -97,6 +102,7 @@ impl KeyCodec for OwnershipObject {
             }
             OwnershipObject::Stage { name } => b.push_raw("stage-by-name").push_str(name),
             OwnershipObject::UDF { name } => b.push_raw("udf-by-name").push_str(name),
+            OwnershipObject::Sequence { name } => b.push_raw("sequence-by-name").push_str(name),
         }
     }
 
@@ -143,9 +149,13 @@ impl KeyCodec for OwnershipObject {
                 let name = p.next_str()?;
                 Ok(OwnershipObject::UDF { name })
             }
+            "sequence-by-name" => {
+                let name = p.next_str()?;
+                Ok(OwnershipObject::Sequence { name })
+            }
             _ => Err(kvapi::KeyError::InvalidSegment {
                 i: p.index(),
-                expect: "database-by-id|database-by-catalog-id|table-by-id|table-by-catalog-id|stage-by-name|udf-by-name"
+                expect: "database-by-id|database-by-catalog-id|table-by-id|table-by-catalog-id|stage-by-name|udf-by-name|sequence-by-name"
                     .to_string(),
                 got: q.to_string(),
             }),
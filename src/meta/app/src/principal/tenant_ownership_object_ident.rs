@@ -48,6 +48,7 @@ impl TenantOwnershipObjectIdent {
         //     }
         //     OwnershipObject::Stage { .. } => {}
         //     OwnershipObject::UDF { .. } => {}
+        //     OwnershipObject::Sequence { .. } => {}
         // }
 
         Self::new_unchecked(tenant, subject)
@@ -252,6 +253,22 @@ mod tests {
             let parsed = TenantOwnershipObjectIdent::from_str_key(&key).unwrap();
             assert_eq!(role_grantee, parsed);
         }
+
+        // sequence
+        {
+            let role_grantee = TenantOwnershipObjectIdent::new_unchecked(
+                Tenant::new_literal("test"),
+                OwnershipObject::Sequence {
+                    name: "foo".to_string(),
+                },
+            );
+
+            let key = role_grantee.to_string_key();
+            assert_eq!("__fd_object_owners/test/sequence-by-name/foo", key);
+
+            let parsed = TenantOwnershipObjectIdent::from_str_key(&key).unwrap();
+            assert_eq!(role_grantee, parsed);
+        }
     }
 
     #[test]
@@ -14,9 +14,11 @@
 
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::str::FromStr;
 
 use chrono::DateTime;
 use chrono::Utc;
+use databend_common_exception::ErrorCode;
 use databend_common_expression::types::DataType;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -42,6 +44,51 @@ pub struct UDFScript {
     pub arg_types: Vec<DataType>,
     pub return_type: DataType,
     pub runtime_version: String,
+    pub on_error: UDFOnError,
+}
+
+/// How a script UDF handles a row whose evaluation fails.
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Hash,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum UDFOnError {
+    /// Fail the whole query.
+    #[default]
+    Abort = 0,
+    /// Return NULL for the failed row.
+    Null = 1,
+}
+
+impl FromStr for UDFOnError {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, ErrorCode> {
+        match s.to_lowercase().as_str() {
+            "abort" => Ok(UDFOnError::Abort),
+            "null" => Ok(UDFOnError::Null),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Invalid UDF on_error '{s}', must be 'abort' or 'null'"
+            ))),
+        }
+    }
+}
+
+impl Display for UDFOnError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            UDFOnError::Abort => write!(f, "abort"),
+            UDFOnError::Null => write!(f, "null"),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -120,6 +167,7 @@ impl UserDefinedFunction {
                 arg_types,
                 return_type,
                 runtime_version: runtime_version.to_string(),
+                on_error: UDFOnError::Abort,
             }),
             created_on: Utc::now(),
         }
@@ -168,6 +216,7 @@ impl Display for UDFDefinition {
                 handler,
                 language,
                 runtime_version,
+                on_error,
             }) => {
                 for (i, item) in arg_types.iter().enumerate() {
                     if i > 0 {
@@ -177,8 +226,12 @@ impl Display for UDFDefinition {
                 }
                 write!(
                     f,
-                    ") RETURNS {return_type} LANGUAGE {language} RUNTIME_VERSION = {runtime_version} HANDLER = {handler} AS $${code}$$"
+                    ") RETURNS {return_type} LANGUAGE {language} RUNTIME_VERSION = {runtime_version} HANDLER = {handler}"
                 )?;
+                if *on_error != UDFOnError::Abort {
+                    write!(f, " ON_ERROR = {on_error}")?;
+                }
+                write!(f, " AS $${code}$$")?;
             }
         }
         Ok(())
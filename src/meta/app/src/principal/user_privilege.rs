@@ -201,6 +201,14 @@ impl UserPrivilegeSet {
         }
     }
 
+    pub fn available_privileges_on_sequence(available_ownership: bool) -> Self {
+        if available_ownership {
+            make_bitflags!(UserPrivilegeType::{ Usage | Ownership }).into()
+        } else {
+            make_bitflags!(UserPrivilegeType::{ Usage }).into()
+        }
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -174,6 +174,7 @@ pub enum StageFileFormatType {
     Orc,
     Parquet,
     Xml,
+    FixedWidth,
     None,
 }
 
@@ -192,12 +193,13 @@ impl FromStr for StageFileFormatType {
             "NDJSON" | "JSONEACHROW" => Ok(StageFileFormatType::NdJson),
             "PARQUET" => Ok(StageFileFormatType::Parquet),
             "XML" => Ok(StageFileFormatType::Xml),
+            "FIXED_WIDTH" | "FIXEDWIDTH" => Ok(StageFileFormatType::FixedWidth),
             "JSON" => Ok(StageFileFormatType::Json),
             "ORC" | "AVRO" => Err(format!(
-                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | XML)"
+                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | XML | FIXED_WIDTH)"
             )),
             _ => Err(format!(
-                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | XML)"
+                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | XML | FIXED_WIDTH)"
             )),
         }
     }
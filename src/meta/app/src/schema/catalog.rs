@@ -31,6 +31,13 @@ pub enum CatalogType {
     Iceberg = 3,
 }
 
+impl CatalogType {
+    /// Returns true if the catalog is not the built-in default catalog.
+    pub fn is_external(&self) -> bool {
+        !matches!(self, CatalogType::Default)
+    }
+}
+
 impl Display for CatalogType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -62,6 +69,15 @@ impl CatalogOption {
             CatalogOption::Iceberg(_) => CatalogType::Iceberg,
         }
     }
+
+    /// Get the storage params of the catalog, `None` if the catalog has no storage of its own.
+    pub fn storage_params(&self) -> Option<&StorageParams> {
+        match self {
+            CatalogOption::Default => None,
+            CatalogOption::Hive(opt) => opt.storage_params.as_deref(),
+            CatalogOption::Iceberg(opt) => Some(opt.storage_params.as_ref()),
+        }
+    }
 }
 
 /// Option for creating a iceberg catalog
@@ -142,6 +158,21 @@ impl CatalogInfo {
         &self.name_ident.catalog_name
     }
 
+    /// Returns true if the catalog type is not the default one.
+    pub fn is_external(&self) -> bool {
+        self.catalog_type().is_external()
+    }
+
+    /// Returns true if this is the built-in default catalog.
+    pub fn is_default(&self) -> bool {
+        self.id.catalog_id == 0 && self.name_ident.catalog_name == "default"
+    }
+
+    /// Get the storage params via catalog info.
+    pub fn storage_params(&self) -> Option<&StorageParams> {
+        self.meta.catalog_option.storage_params()
+    }
+
     /// Create a new default catalog info.
     pub fn new_default() -> CatalogInfo {
         Self {
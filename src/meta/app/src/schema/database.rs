@@ -21,6 +21,8 @@ use std::ops::Deref;
 
 use chrono::DateTime;
 use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
 
 use super::CreateOption;
 use crate::schema::database_name_ident::DatabaseNameIdent;
@@ -83,6 +85,21 @@ impl DatabaseIdToName {
     }
 }
 
+/// Database option key: when set to a true value, tables in the database keep no history
+/// for time travel.
+pub const OPT_KEY_DISABLE_TIME_TRAVEL: &str = "disable_time_travel";
+
+/// Database and table option key: when set to a true value, the database or table keeps no
+/// history for time travel. `CREATE TRANSIENT` sets its upper case form to `T`.
+pub const OPT_KEY_TRANSIENT: &str = "transient";
+
+/// Database options that control how the database stores its own data, they are not allowed
+/// on a database created from a share, which only references the data of another tenant.
+pub const OWNER_ONLY_DATABASE_OPTIONS: [&str; 2] = [OPT_KEY_TRANSIENT, OPT_KEY_DISABLE_TIME_TRAVEL];
+
+/// Database options that can be changed by `ALTER DATABASE ... SET OPTIONS`.
+pub const ALTERABLE_DATABASE_OPTIONS: [&str; 1] = [OPT_KEY_DISABLE_TIME_TRAVEL];
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DatabaseMeta {
     pub engine: String,
@@ -97,6 +114,8 @@ pub struct DatabaseMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub from_share: Option<ShareNameIdentRaw>,
+    // the user who created this database, e.g. `'root'@'%'`
+    pub created_by: Option<String>,
 }
 
 impl Default for DatabaseMeta {
@@ -111,16 +130,64 @@ impl Default for DatabaseMeta {
             drop_on: None,
             shared_by: BTreeSet::new(),
             from_share: None,
+            created_by: None,
         }
     }
 }
 
+impl DatabaseMeta {
+    /// Returns true if the `disable_time_travel` option is set to a true value.
+    ///
+    /// A missing or unrecognized value is treated as `false`.
+    pub fn time_travel_disabled(&self) -> bool {
+        self.options
+            .get(OPT_KEY_DISABLE_TIME_TRAVEL)
+            .and_then(|v| parse_bool_option(OPT_KEY_DISABLE_TIME_TRAVEL, v).ok())
+            .unwrap_or(false)
+    }
+
+    /// Check the options are allowed on this database.
+    ///
+    /// A database created from a share rejects the options in [`OWNER_ONLY_DATABASE_OPTIONS`].
+    pub fn check_options(&self) -> Result<()> {
+        let Some(share) = &self.from_share else {
+            return Ok(());
+        };
+
+        for key in self.options.keys() {
+            if OWNER_ONLY_DATABASE_OPTIONS
+                .iter()
+                .any(|opt| opt.eq_ignore_ascii_case(key))
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "option '{}' is not allowed on database created from share {}",
+                    key,
+                    share.display()
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the value of a boolean option, accepting `t`/`true`/`1` and `f`/`false`/`0`,
+/// case-insensitively.
+pub fn parse_bool_option(key: &str, value: &str) -> Result<bool> {
+    match value.to_lowercase().as_str() {
+        "t" | "true" | "1" => Ok(true),
+        "f" | "false" | "0" => Ok(false),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "invalid {key} option '{value}', expect a boolean"
+        ))),
+    }
+}
+
 impl Display for DatabaseMeta {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Engine: {}={:?}, Options: {:?}, CreatedOn: {:?}",
-            self.engine, self.engine_options, self.options, self.created_on
+            "Engine: {}={:?}, Options: {:?}, CreatedOn: {:?}, CreatedBy: {:?}",
+            self.engine, self.engine_options, self.options, self.created_on, self.created_by
         )
     }
 }
@@ -237,6 +304,32 @@ impl Display for RenameDatabaseReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RenameDatabaseReply {}
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpsertDatabaseOptionReq {
+    pub name_ident: DatabaseNameIdent,
+
+    /// Add or remove options
+    ///
+    /// Some(String): add or update an option.
+    /// None: delete an option.
+    pub options: BTreeMap<String, Option<String>>,
+}
+
+impl Display for UpsertDatabaseOptionReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "upsert_database_option:{}/{}={:?}",
+            self.name_ident.tenant_name(),
+            self.name_ident.database_name(),
+            self.options
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpsertDatabaseOptionReply {}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropDatabaseReq {
     pub if_exists: bool,
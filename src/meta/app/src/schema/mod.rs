@@ -39,6 +39,7 @@ pub use catalog_id_ident::CatalogIdIdent;
 pub use catalog_id_to_name_ident::CatalogIdToNameIdent;
 pub use catalog_name_ident::CatalogNameIdent;
 pub use create_option::CreateOption;
+pub use database::parse_bool_option;
 pub use database::CreateDatabaseReply;
 pub use database::CreateDatabaseReq;
 pub use database::DatabaseId;
@@ -56,6 +57,12 @@ pub use database::RenameDatabaseReply;
 pub use database::RenameDatabaseReq;
 pub use database::UndropDatabaseReply;
 pub use database::UndropDatabaseReq;
+pub use database::UpsertDatabaseOptionReply;
+pub use database::UpsertDatabaseOptionReq;
+pub use database::ALTERABLE_DATABASE_OPTIONS;
+pub use database::OPT_KEY_DISABLE_TIME_TRAVEL;
+pub use database::OPT_KEY_TRANSIENT;
+pub use database::OWNER_ONLY_DATABASE_OPTIONS;
 pub use database_id_history_ident::DatabaseIdHistoryIdent;
 pub use index::*;
 pub use index_name_ident::IndexNameIdent;
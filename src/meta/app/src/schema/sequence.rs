@@ -54,7 +54,10 @@ pub struct CreateSequenceReq {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct CreateSequenceReply {}
+pub struct CreateSequenceReply {
+    /// False if the sequence already exists and `CREATE ... IF NOT EXISTS` left it untouched.
+    pub new_sequence: bool,
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GetSequenceNextValueReq {
@@ -92,6 +95,16 @@ pub struct DropSequenceReply {
     pub prev: Option<u64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenameSequenceReq {
+    pub if_exists: bool,
+    pub ident: SequenceIdent,
+    pub new_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenameSequenceReply {}
+
 mod kvapi_impl {
 
     use databend_common_meta_kvapi::kvapi;
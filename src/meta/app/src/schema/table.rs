@@ -961,8 +961,23 @@ pub struct TruncateTableReq {
     pub batch_size: Option<u64>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct TruncateTableReply {}
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TruncateTableReply {
+    /// Bytes of data and index removed by the truncate.
+    pub removed_bytes: u64,
+    /// Number of blocks removed by the truncate.
+    pub removed_blocks: u64,
+}
+
+impl TruncateTableReply {
+    /// Build the reply from the statistics of the table before truncating.
+    pub fn from_statistics(statistics: &TableStatistics) -> Self {
+        TruncateTableReply {
+            removed_bytes: statistics.compressed_data_bytes + statistics.index_data_bytes,
+            removed_blocks: statistics.number_of_blocks.unwrap_or_default(),
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EmptyProto {}
@@ -19,6 +19,7 @@ pub mod share_consumer_ident;
 pub mod share_end_point_ident;
 pub mod share_name_ident;
 
+pub use share::dedup_and_validate_share_tenants;
 pub use share::AddShareAccountsReply;
 pub use share::AddShareAccountsReq;
 pub use share::CreateShareEndpointReply;
@@ -66,6 +67,7 @@ pub use share::ShareMeta;
 pub use share::ShareSpec;
 pub use share::ShareTableInfoMap;
 pub use share::ShareTableSpec;
+pub use share::ShareViewSpec;
 pub use share::ShowSharesReply;
 pub use share::ShowSharesReq;
 pub use share::TableInfoMap;
@@ -20,6 +20,8 @@ use std::fmt::Formatter;
 
 use chrono::DateTime;
 use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
 use enumflags2::bitflags;
 use enumflags2::BitFlags;
 
@@ -707,6 +709,26 @@ impl ShareTableSpec {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ShareViewSpec {
+    pub name: String,
+    pub database_id: u64,
+    pub view_id: u64,
+    /// The query that defines the view.
+    pub view_query: String,
+}
+
+impl ShareViewSpec {
+    pub fn new(name: &str, database_id: u64, view_id: u64, view_query: &str) -> Self {
+        ShareViewSpec {
+            name: name.to_owned(),
+            database_id,
+            view_id,
+            view_query: view_query.to_owned(),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
 pub struct ShareDatabaseSpec {
     pub name: String,
@@ -720,12 +742,39 @@ pub struct ShareSpec {
     pub version: u64,
     pub database: Option<ShareDatabaseSpec>,
     pub tables: Vec<ShareTableSpec>,
+    #[serde(default)]
+    pub views: Vec<ShareViewSpec>,
     pub tenants: Vec<String>,
     pub db_privileges: Option<BitFlags<ShareGrantObjectPrivilege>>,
     pub comment: Option<String>,
     pub share_on: Option<DateTime<Utc>>,
 }
 
+impl ShareSpec {
+    /// Check the tenants the share is granted to, see [`dedup_and_validate_share_tenants`].
+    pub fn dedup_and_validate_tenants(&mut self, owner_tenant: &str) -> Result<()> {
+        dedup_and_validate_share_tenants(&mut self.tenants, owner_tenant)
+    }
+}
+
+/// Remove the duplicated tenants, keeping the first occurrence,
+/// and reject granting a share to the tenant owning it.
+pub fn dedup_and_validate_share_tenants(
+    tenants: &mut Vec<String>,
+    owner_tenant: &str,
+) -> Result<()> {
+    if tenants.iter().any(|t| t == owner_tenant) {
+        return Err(ErrorCode::BadArguments(format!(
+            "share can not be granted to its owner tenant {}",
+            owner_tenant
+        )));
+    }
+
+    let mut seen = BTreeSet::new();
+    tenants.retain(|t| seen.insert(t.clone()));
+    Ok(())
+}
+
 mod kvapi_key_impl {
     use databend_common_meta_kvapi::kvapi;
 
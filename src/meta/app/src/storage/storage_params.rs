@@ -79,6 +79,28 @@ impl StorageParams {
         }
     }
 
+    /// The root of the location, empty for the backends without a root.
+    pub fn root(&self) -> &str {
+        match self {
+            StorageParams::Azblob(v) => &v.root,
+            StorageParams::Fs(v) => &v.root,
+            StorageParams::Ftp(v) => &v.root,
+            StorageParams::Hdfs(v) => &v.root,
+            StorageParams::Http(_) => "",
+            StorageParams::Ipfs(v) => &v.root,
+            StorageParams::Memory => "",
+            StorageParams::Moka(_) => "",
+            StorageParams::Obs(v) => &v.root,
+            StorageParams::Oss(v) => &v.root,
+            StorageParams::S3(v) => &v.root,
+            StorageParams::Gcs(v) => &v.root,
+            StorageParams::Webhdfs(v) => &v.root,
+            StorageParams::Cos(v) => &v.root,
+            StorageParams::Huggingface(v) => &v.root,
+            StorageParams::None => "",
+        }
+    }
+
     /// map the given root with.
     pub fn map_root(mut self, f: impl Fn(&str) -> String) -> Self {
         match &mut self {
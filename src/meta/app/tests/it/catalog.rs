@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::schema::CatalogInfo;
+use databend_common_meta_app::schema::CatalogOption;
+use databend_common_meta_app::schema::CatalogType;
+use databend_common_meta_app::schema::HiveCatalogOption;
+use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_app::storage::StorageS3Config;
+
+#[test]
+fn test_catalog_storage_params() {
+    let s3 = StorageParams::S3(StorageS3Config {
+        bucket: "bucket".to_string(),
+        ..Default::default()
+    });
+    let fs = StorageParams::Fs(StorageFsConfig {
+        root: "/tmp/iceberg".to_string(),
+    });
+
+    let mut info = CatalogInfo::new_default();
+    assert_eq!(None, info.storage_params());
+
+    info.meta.catalog_option = CatalogOption::Hive(HiveCatalogOption {
+        address: "127.0.0.1:9083".to_string(),
+        storage_params: None,
+    });
+    assert_eq!(None, info.storage_params());
+
+    info.meta.catalog_option = CatalogOption::Hive(HiveCatalogOption {
+        address: "127.0.0.1:9083".to_string(),
+        storage_params: Some(Box::new(s3.clone())),
+    });
+    assert_eq!(Some(&s3), info.storage_params());
+
+    info.meta.catalog_option = CatalogOption::Iceberg(IcebergCatalogOption {
+        storage_params: Box::new(fs.clone()),
+    });
+    assert_eq!(Some(&fs), info.storage_params());
+}
+
+#[test]
+fn test_catalog_is_default() {
+    let info = CatalogInfo::new_default();
+    assert!(info.is_default());
+
+    let mut info = CatalogInfo::new_default();
+    info.id.catalog_id = 1;
+    assert!(!info.is_default());
+
+    let mut info = CatalogInfo::new_default();
+    info.name_ident.catalog_name = "ctl".to_string();
+    assert!(!info.is_default());
+}
+
+#[test]
+fn test_catalog_type_is_external() -> anyhow::Result<()> {
+    let storage_params = Box::new(StorageParams::Fs(StorageFsConfig {
+        root: "/tmp/ctl".to_string(),
+    }));
+    let cases = [
+        (CatalogOption::Default, CatalogType::Default, false),
+        (
+            CatalogOption::Hive(HiveCatalogOption {
+                address: "127.0.0.1:9083".to_string(),
+                storage_params: Some(storage_params.clone()),
+            }),
+            CatalogType::Hive,
+            true,
+        ),
+        (
+            CatalogOption::Iceberg(IcebergCatalogOption { storage_params }),
+            CatalogType::Iceberg,
+            true,
+        ),
+    ];
+
+    for (option, typ, external) in cases {
+        assert_eq!(typ, option.catalog_type());
+        assert_eq!(external, typ.is_external(), "{typ}");
+
+        let mut info = CatalogInfo::new_default();
+        info.meta.catalog_option = option.clone();
+        assert_eq!(external, info.is_external(), "{typ}");
+
+        let got: CatalogOption = serde_json::from_str(&serde_json::to_string(&option)?)?;
+        assert_eq!(option, got);
+        assert_eq!(typ, got.catalog_type());
+    }
+
+    Ok(())
+}
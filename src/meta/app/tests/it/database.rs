@@ -0,0 +1,43 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_meta_app::schema::DatabaseMeta;
+use databend_common_meta_app::schema::OPT_KEY_DISABLE_TIME_TRAVEL;
+use databend_common_meta_app::share::share_name_ident::ShareNameIdentRaw;
+
+fn db_meta(from_share: bool, option: &str) -> DatabaseMeta {
+    let mut meta = DatabaseMeta::default();
+    meta.options.insert(option.to_string(), "true".to_string());
+    if from_share {
+        meta.from_share = Some(ShareNameIdentRaw::new("provider", "share1"));
+    }
+    meta
+}
+
+#[test]
+fn test_db_meta_check_owner_only_options() {
+    for option in ["TRANSIENT", OPT_KEY_DISABLE_TIME_TRAVEL] {
+        // Accepted on an owned database.
+        assert!(db_meta(false, option).check_options().is_ok());
+
+        // Rejected on a database created from a share.
+        let err = db_meta(true, option).check_options().unwrap_err();
+        assert_eq!(ErrorCode::BAD_ARGUMENTS, err.code(), "option: {option}");
+        assert!(err.message().contains(option), "option: {option}");
+    }
+
+    // Other options are not restricted.
+    assert!(db_meta(true, "comment").check_options().is_ok());
+}
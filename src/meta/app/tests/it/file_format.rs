@@ -12,7 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_meta_app::principal::check_record_delimiter;
+use databend_common_meta_app::principal::check_row_tag;
+use databend_common_meta_app::principal::BinaryFormat;
+use databend_common_meta_app::principal::CsvFileFormatParams;
+use databend_common_meta_app::principal::EmptyFieldAs;
+use databend_common_meta_app::principal::FileFormatOptionsAst;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::FixedWidthFileFormatParams;
+use databend_common_meta_app::principal::JsonFileFormatParams;
+use databend_common_meta_app::principal::NdJsonFileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::ParquetFileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_meta_app::principal::TsvFileFormatParams;
+use databend_common_meta_app::principal::XmlFileFormatParams;
+use databend_common_meta_app::principal::RECORD_DELIMITER_AUTO;
 
 #[test]
 fn test_check_record_delimiter() {
@@ -22,3 +45,527 @@ fn test_check_record_delimiter() {
     assert!(check_record_delimiter("foo").is_err());
     assert!(check_record_delimiter("|\r").is_err());
 }
+
+#[test]
+fn test_check_row_tag() {
+    assert!(check_row_tag("row").is_ok());
+    assert!(check_row_tag("my_row").is_ok());
+    assert!(check_row_tag("_row-1.a").is_ok());
+    assert!(check_row_tag("").is_err());
+    assert!(check_row_tag(&"r".repeat(1025)).is_err());
+    assert!(check_row_tag("my row").is_err());
+    assert!(check_row_tag("1row").is_err());
+    assert!(check_row_tag("<row>").is_err());
+    assert!(check_row_tag("a&b").is_err());
+    assert!(check_row_tag("a/b").is_err());
+
+    let err = check_row_tag("my row").unwrap_err();
+    assert!(err.contains("not a valid XML element name"));
+}
+
+#[test]
+fn test_csv_output_null_display() -> Result<()> {
+    let parse = |opts: &[(&str, &str)]| -> Result<FileFormatParams> {
+        let mut options = BTreeMap::new();
+        options.insert("type".to_string(), "csv".to_string());
+        for (k, v) in opts {
+            options.insert(k.to_string(), v.to_string());
+        }
+        FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)
+    };
+
+    let params = parse(&[("null_display", "null")])?;
+    let FileFormatParams::Csv(csv) = &params else {
+        unreachable!()
+    };
+    assert_eq!(None, csv.output_null_display);
+    assert_eq!("null", csv.get_output_null_display());
+    assert!(!params.to_string().contains("OUTPUT_NULL_DISPLAY"));
+
+    let params = parse(&[("null_display", "null"), ("output_null_display", "<null>")])?;
+    let FileFormatParams::Csv(csv) = &params else {
+        unreachable!()
+    };
+    assert_eq!("null", csv.null_display);
+    assert_eq!(Some("<null>".to_string()), csv.output_null_display);
+    assert_eq!("<null>", csv.get_output_null_display());
+    let display = params.to_string();
+    assert!(display.contains(" NULL_DISPLAY = 'null' "));
+    assert!(display.ends_with(" OUTPUT_NULL_DISPLAY = '<null>'"));
+
+    Ok(())
+}
+
+#[test]
+fn test_time_zone() -> Result<()> {
+    let parse = |typ: &str, time_zone: Option<&str>| -> Result<FileFormatParams> {
+        let mut options = BTreeMap::new();
+        options.insert("type".to_string(), typ.to_string());
+        if let Some(v) = time_zone {
+            options.insert("time_zone".to_string(), v.to_string());
+        }
+        FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)
+    };
+
+    for typ in ["csv", "tsv", "ndjson"] {
+        let params = parse(typ, None)?;
+        assert_eq!(None, params.time_zone());
+        assert!(!params.to_string().contains("TIME_ZONE"));
+
+        let params = parse(typ, Some("Asia/Shanghai"))?;
+        assert_eq!(Some("Asia/Shanghai"), params.time_zone());
+        assert!(params.to_string().ends_with(" TIME_ZONE = 'Asia/Shanghai'"));
+
+        let err = parse(typ, Some("Mars/Olympus")).unwrap_err();
+        assert!(
+            err.message()
+                .contains("TIME_ZONE is currently set to 'Mars/Olympus'")
+        );
+    }
+
+    // not supported by other formats
+    assert!(parse("parquet", Some("UTC")).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_skip_blank_lines() -> Result<()> {
+    let parse = |typ: &str, skip_blank_lines: Option<&str>| -> Result<FileFormatParams> {
+        let mut options = BTreeMap::new();
+        options.insert("type".to_string(), typ.to_string());
+        if let Some(v) = skip_blank_lines {
+            options.insert("skip_blank_lines".to_string(), v.to_string());
+        }
+        FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)
+    };
+
+    for typ in ["csv", "tsv", "ndjson"] {
+        let params = parse(typ, None)?;
+        assert!(!params.skip_blank_lines());
+        assert!(params.to_string().contains(" SKIP_BLANK_LINES = false"));
+
+        let params = parse(typ, Some("true"))?;
+        assert!(params.skip_blank_lines());
+        assert!(params.to_string().contains(" SKIP_BLANK_LINES = true"));
+
+        assert!(parse(typ, Some("maybe")).is_err());
+    }
+
+    // not supported by other formats
+    assert!(parse("parquet", Some("true")).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_auto_record_delimiter() -> Result<()> {
+    let parse = |typ: &str, record_delimiter: &str| -> Result<FileFormatParams> {
+        let mut options = BTreeMap::new();
+        options.insert("type".to_string(), typ.to_string());
+        options.insert("record_delimiter".to_string(), record_delimiter.to_string());
+        FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)
+    };
+
+    for v in ["AUTO", "auto"] {
+        let params = parse("csv", v)?;
+        let FileFormatParams::Csv(csv) = &params else {
+            unreachable!()
+        };
+        assert!(csv.is_auto_record_delimiter());
+        assert_eq!(RECORD_DELIMITER_AUTO, csv.record_delimiter);
+        assert!(params.to_string().contains(" RECORD_DELIMITER = 'AUTO' "));
+    }
+
+    let params = parse("csv", "\r\n")?;
+    let FileFormatParams::Csv(csv) = &params else {
+        unreachable!()
+    };
+    assert!(!csv.is_auto_record_delimiter());
+    assert!(!CsvFileFormatParams::default().is_auto_record_delimiter());
+
+    // not supported by TSV
+    assert!(parse("tsv", "AUTO").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_parquet_compression() -> Result<()> {
+    let parse = |compression: Option<&str>| -> Result<FileFormatParams> {
+        let mut options = BTreeMap::new();
+        options.insert("type".to_string(), "parquet".to_string());
+        if let Some(v) = compression {
+            options.insert("compression".to_string(), v.to_string());
+        }
+        FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)
+    };
+
+    assert_eq!(StageFileCompression::Snappy, parse(None)?.compression());
+    assert_eq!(
+        StageFileCompression::None,
+        parse(Some("none"))?.compression()
+    );
+    assert_eq!(
+        StageFileCompression::Snappy,
+        parse(Some("SNAPPY"))?.compression()
+    );
+    let params = parse(Some("zstd"))?;
+    assert_eq!(StageFileCompression::Zstd, params.compression());
+    assert_eq!(
+        params.to_string(),
+        "TYPE = PARQUET COMPRESSION = Zstd MISSING_FIELD_AS = ERROR"
+    );
+
+    for codec in ["gzip", "bz2", "xz", "auto"] {
+        let err = parse(Some(codec)).unwrap_err();
+        assert!(
+            err.message()
+                .contains(&format!("COMPRESSION is currently set to '{codec}'")),
+            "{}",
+            err.message()
+        );
+    }
+    assert!(parse(Some("lz5")).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_for_schema() -> Result<()> {
+    let schema = TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::String),
+    ]);
+    let binary_schema = TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new(
+            "b",
+            TableDataType::Nullable(Box::new(TableDataType::Array(Box::new(
+                TableDataType::Binary,
+            )))),
+        ),
+    ]);
+
+    for typ in [
+        StageFileFormatType::Csv,
+        StageFileFormatType::Tsv,
+        StageFileFormatType::NdJson,
+        StageFileFormatType::Parquet,
+    ] {
+        let params = FileFormatParams::default_by_type(typ)?;
+        params.validate_for_schema(&schema)?;
+    }
+
+    let csv = FileFormatParams::default_by_type(StageFileFormatType::Csv)?;
+    csv.validate_for_schema(&binary_schema)?;
+    let parquet = FileFormatParams::default_by_type(StageFileFormatType::Parquet)?;
+    parquet.validate_for_schema(&binary_schema)?;
+
+    let ndjson = FileFormatParams::default_by_type(StageFileFormatType::NdJson)?;
+    let err = ndjson.validate_for_schema(&binary_schema).unwrap_err();
+    assert!(
+        err.message()
+            .contains("Column 'b' of type Array(Binary) NULL can not be loaded from NDJSON"),
+        "{}",
+        err.message()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_fixed_width() -> Result<()> {
+    let parse = |opts: &[(&str, &str)]| -> Result<FileFormatParams> {
+        let mut options = BTreeMap::new();
+        options.insert("type".to_string(), "fixed_width".to_string());
+        for (k, v) in opts {
+            options.insert(k.to_string(), v.to_string());
+        }
+        FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)
+    };
+
+    // widths as a JSON list of numbers, or of strings as `WIDTHS = ('3', '5')` is passed
+    let expect = FileFormatParams::FixedWidth(FixedWidthFileFormatParams {
+        compression: StageFileCompression::None,
+        widths: vec![3, 5, 10],
+        trim: true,
+    });
+    assert_eq!(parse(&[("widths", "[3, 5, 10]")])?, expect);
+    assert_eq!(parse(&[("widths", r#"["3", "5", "10"]"#)])?, expect);
+
+    let params = parse(&[("widths", "[2, 6]"), ("trim", "false")])?;
+    assert_eq!(
+        params.to_string(),
+        "TYPE = FIXED_WIDTH COMPRESSION = None WIDTHS = ('2', '6') TRIM = false"
+    );
+    assert_round_trip(&params)?;
+    assert_round_trip(&FileFormatParams::FixedWidth(FixedWidthFileFormatParams {
+        compression: StageFileCompression::Zstd,
+        widths: vec![1],
+        trim: true,
+    }))?;
+
+    assert!(parse(&[]).is_err());
+    assert!(parse(&[("widths", "[]")]).is_err());
+    assert!(parse(&[("widths", "[3, 0]")]).is_err());
+    assert!(parse(&[("widths", r#"["3", "a"]"#)]).is_err());
+    // widths are required, there is no default
+    assert!(FileFormatParams::default_by_type(StageFileFormatType::FixedWidth).is_err());
+
+    // one width for each column
+    let schema = TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::String),
+    ]);
+    parse(&[("widths", "[3, 5]")])?.validate_for_schema(&schema)?;
+    let err = expect.validate_for_schema(&schema).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "FIXED_WIDTH file format has 3 widths but the table has 2 columns, expecting one width for each column."
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_display_round_trip() -> Result<()> {
+    for typ in [
+        StageFileFormatType::Csv,
+        StageFileFormatType::Tsv,
+        StageFileFormatType::NdJson,
+        StageFileFormatType::Json,
+        StageFileFormatType::Xml,
+        StageFileFormatType::Parquet,
+    ] {
+        assert_round_trip(&FileFormatParams::default_by_type(typ)?)?;
+    }
+
+    for nan_display in ["nan", "NaN", "null", "NULL"] {
+        assert_round_trip(&FileFormatParams::Csv(CsvFileFormatParams {
+            nan_display: nan_display.to_string(),
+            ..Default::default()
+        }))?;
+        assert_round_trip(&FileFormatParams::Tsv(TsvFileFormatParams {
+            nan_display: nan_display.to_string(),
+            ..Default::default()
+        }))?;
+    }
+
+    for empty_field_as in [
+        EmptyFieldAs::Null,
+        EmptyFieldAs::String,
+        EmptyFieldAs::FieldDefault,
+    ] {
+        for binary_format in [BinaryFormat::Hex, BinaryFormat::Base64] {
+            assert_round_trip(&FileFormatParams::Csv(CsvFileFormatParams {
+                empty_field_as: empty_field_as.clone(),
+                binary_format,
+                ..Default::default()
+            }))?;
+        }
+    }
+
+    assert_round_trip(&FileFormatParams::Csv(CsvFileFormatParams {
+        compression: StageFileCompression::RawDeflate,
+        field_delimiter: "\t".to_string(),
+        record_delimiter: "\r\n".to_string(),
+        quote: "\'".to_string(),
+        escape: "\\".to_string(),
+        headers: 1,
+        output_header: true,
+        null_display: "".to_string(),
+        output_null_display: Some("<null>".to_string()),
+        error_on_column_count_mismatch: false,
+        time_zone: Some("Asia/Shanghai".to_string()),
+        skip_blank_lines: true,
+        ..Default::default()
+    }))?;
+    assert_round_trip(&FileFormatParams::Tsv(TsvFileFormatParams {
+        skip_blank_lines: true,
+        ..Default::default()
+    }))?;
+    assert_round_trip(&FileFormatParams::NdJson(NdJsonFileFormatParams {
+        compression: StageFileCompression::Gzip,
+        missing_field_as: NullAs::FieldDefault,
+        null_field_as: NullAs::Null,
+        skip_blank_lines: true,
+        ..Default::default()
+    }))?;
+    assert_round_trip(&FileFormatParams::NdJson(NdJsonFileFormatParams {
+        null_if: vec![
+            "".to_string(),
+            "NULL".to_string(),
+            "it's, (null)".to_string(),
+        ],
+        ..Default::default()
+    }))?;
+    assert_round_trip(&FileFormatParams::Xml(XmlFileFormatParams {
+        compression: StageFileCompression::Zstd,
+        row_tag: "my_row".to_string(),
+    }))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_ndjson_display() -> Result<()> {
+    let mut options = BTreeMap::new();
+    options.insert("type".to_string(), "ndjson".to_string());
+    options.insert("null_field_as".to_string(), "null".to_string());
+    options.insert("null_if".to_string(), r#"["", "null"]"#.to_string());
+    let params = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)?;
+
+    assert_eq!(
+        params.to_string(),
+        "TYPE = NDJSON COMPRESSION = None MISSING_FIELD_AS = ERROR NULL_FIELD_AS = NULL NULL_IF = ('', 'null') SKIP_BLANK_LINES = false"
+    );
+    assert_round_trip(&params)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_to_options_map_round_trip() -> Result<()> {
+    let round_trip = |params: FileFormatParams| -> Result<()> {
+        let options = FileFormatOptionsAst::new(params.to_options_map());
+        assert_eq!(params, FileFormatParams::try_from_ast(options, false)?);
+        Ok(())
+    };
+
+    round_trip(FileFormatParams::Csv(CsvFileFormatParams {
+        compression: StageFileCompression::RawDeflate,
+        field_delimiter: "\t".to_string(),
+        record_delimiter: "\r\n".to_string(),
+        headers: 1,
+        output_header: true,
+        nan_display: "null".to_string(),
+        null_display: "".to_string(),
+        output_null_display: Some("<null>".to_string()),
+        empty_field_as: EmptyFieldAs::FieldDefault,
+        binary_format: BinaryFormat::Base64,
+        error_on_column_count_mismatch: false,
+        time_zone: Some("UTC".to_string()),
+        skip_blank_lines: true,
+        ..Default::default()
+    }))?;
+    round_trip(FileFormatParams::Tsv(TsvFileFormatParams {
+        compression: StageFileCompression::Gzip,
+        field_delimiter: "|".to_string(),
+        headers: 2,
+        nan_display: "NaN".to_string(),
+        skip_blank_lines: true,
+        ..Default::default()
+    }))?;
+    round_trip(FileFormatParams::NdJson(NdJsonFileFormatParams {
+        missing_field_as: NullAs::Null,
+        null_field_as: NullAs::Null,
+        null_if: vec!["".to_string(), "null".to_string()],
+        time_zone: Some("Asia/Shanghai".to_string()),
+        ..Default::default()
+    }))?;
+    round_trip(FileFormatParams::Json(JsonFileFormatParams {
+        compression: StageFileCompression::Zstd,
+    }))?;
+    round_trip(FileFormatParams::Xml(XmlFileFormatParams {
+        compression: StageFileCompression::Xz,
+        row_tag: "item".to_string(),
+    }))?;
+    round_trip(FileFormatParams::Parquet(ParquetFileFormatParams {
+        compression: StageFileCompression::Zstd,
+        missing_field_as: NullAs::FieldDefault,
+    }))?;
+    round_trip(FileFormatParams::FixedWidth(FixedWidthFileFormatParams {
+        compression: StageFileCompression::Gzip,
+        widths: vec![4, 8, 2],
+        trim: false,
+    }))?;
+
+    Ok(())
+}
+
+/// Parse the output of `Display` back and check that nothing is lost.
+fn assert_round_trip(params: &FileFormatParams) -> Result<()> {
+    let display = params.to_string();
+    let options = display_to_options(&display);
+    let parsed = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)?;
+    assert_eq!(params, &parsed, "display: {display}");
+    Ok(())
+}
+
+/// Split `TYPE = CSV FIELD_DELIMITER = ',' ...` into options the way the SQL parser would,
+/// lowercasing the keys, unescaping the quoted values and turning lists into JSON.
+fn display_to_options(display: &str) -> BTreeMap<String, String> {
+    let mut tokens = vec![];
+    let mut chars = display.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() || c == '=' => {}
+            '\'' => {
+                let mut token = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => token.push(unescape_char(&mut chars)),
+                        '\'' => break,
+                        c => token.push(c),
+                    }
+                }
+                tokens.push(token);
+            }
+            '(' => {
+                // `NULL_IF = ('a', 'b')`, passed to the parser as a JSON list.
+                let mut values = vec![];
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\'' => {
+                            let mut value = String::new();
+                            while let Some(c) = chars.next() {
+                                match c {
+                                    '\\' => value.push(unescape_char(&mut chars)),
+                                    '\'' => break,
+                                    c => value.push(c),
+                                }
+                            }
+                            values.push(value);
+                        }
+                        ')' => break,
+                        _ => {}
+                    }
+                }
+                tokens.push(serde_json::to_string(&values).unwrap());
+            }
+            c => {
+                let mut token = String::from(c);
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '=' {
+                        break;
+                    }
+                    token.push(c);
+                    chars.next();
+                }
+                tokens.push(token);
+            }
+        }
+    }
+
+    assert_eq!(tokens.len() % 2, 0, "unpaired options in: {display}");
+    tokens
+        .chunks(2)
+        .map(|kv| (kv[0].to_lowercase(), kv[1].clone()))
+        .collect()
+}
+
+/// Reverse of `escape_string`, called after the leading `\`.
+fn unescape_char(chars: &mut impl Iterator<Item = char>) -> char {
+    match chars.next() {
+        Some('t') => '\t',
+        Some('r') => '\r',
+        Some('n') => '\n',
+        Some('x') => {
+            let hex: String = chars.take(2).collect();
+            u8::from_str_radix(&hex, 16).unwrap() as char
+        }
+        Some(c) => c,
+        None => panic!("dangling escape"),
+    }
+}
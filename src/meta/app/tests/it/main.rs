@@ -12,7 +12,10 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod catalog;
+mod database;
 mod file_format;
+mod share;
 mod user_grant;
 mod user_info;
 mod user_privilege;
@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_meta_app::share::ShareSpec;
+
+fn share_spec(tenants: &[&str]) -> ShareSpec {
+    ShareSpec {
+        name: "share1".to_string(),
+        tenants: tenants.iter().map(|t| t.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_share_spec_dedup_tenants() -> anyhow::Result<()> {
+    let mut spec = share_spec(&["t2", "t1", "t2", "t3", "t1"]);
+    spec.dedup_and_validate_tenants("owner")?;
+    assert_eq!(vec!["t2", "t1", "t3"], spec.tenants);
+    Ok(())
+}
+
+#[test]
+fn test_share_spec_reject_owner_tenant() {
+    let mut spec = share_spec(&["t1", "owner"]);
+    let err = spec.dedup_and_validate_tenants("owner").unwrap_err();
+    assert_eq!(ErrorCode::BAD_ARGUMENTS, err.code());
+    assert!(err.message().contains("owner tenant owner"));
+}
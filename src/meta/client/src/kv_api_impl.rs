@@ -21,6 +21,7 @@ use databend_common_meta_kvapi::kvapi::UpsertKVReq;
 use databend_common_meta_types::MetaError;
 use databend_common_meta_types::TxnReply;
 use databend_common_meta_types::TxnRequest;
+use futures::future;
 use futures::StreamExt;
 use futures::TryStreamExt;
 
@@ -47,13 +48,29 @@ impl kvapi::KVApi for ClientHandle {
 
     #[minitrace::trace]
     async fn list_kv(&self, prefix: &str) -> Result<KVStream<Self::Error>, Self::Error> {
+        let strm = self.request(Streamed(ListKVReq::new(prefix))).await?;
+
+        let strm = strm.map_err(MetaError::from);
+        Ok(strm.boxed())
+    }
+
+    #[minitrace::trace]
+    async fn list_kv_after(
+        &self,
+        prefix: &str,
+        start_after: &str,
+    ) -> Result<KVStream<Self::Error>, Self::Error> {
         let strm = self
-            .request(Streamed(ListKVReq {
-                prefix: prefix.to_string(),
-            }))
+            .request(Streamed(
+                ListKVReq::new(prefix).with_start_after(start_after),
+            ))
             .await?;
 
-        let strm = strm.map_err(MetaError::from);
+        // A meta-service not knowing `start_after` lists from the prefix.
+        let start_after = start_after.to_string();
+        let strm = strm
+            .map_err(MetaError::from)
+            .try_filter(move |x| future::ready(x.key > start_after));
         Ok(strm.boxed())
     }
 
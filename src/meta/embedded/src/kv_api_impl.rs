@@ -45,6 +45,16 @@ impl kvapi::KVApi for MetaEmbedded {
         sm.list_kv(prefix).await
     }
 
+    #[minitrace::trace]
+    async fn list_kv_after(
+        &self,
+        prefix: &str,
+        start_after: &str,
+    ) -> Result<KVStream<Self::Error>, Self::Error> {
+        let sm = self.inner.lock().await;
+        sm.list_kv_after(prefix, start_after).await
+    }
+
     #[minitrace::trace]
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         let sm = self.inner.lock().await;
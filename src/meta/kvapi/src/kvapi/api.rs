@@ -105,6 +105,23 @@ pub trait KVApi: Send + Sync {
     /// Same as `prefix_list_kv()`, except it returns a stream.
     async fn list_kv(&self, prefix: &str) -> Result<KVStream<Self::Error>, Self::Error>;
 
+    /// List key-value records that are starts with the specified prefix and come after
+    /// `start_after`, in key order.
+    ///
+    /// The default implementation skips the leading records of `list_kv()`, an implementation
+    /// backed by an ordered store should seek to `start_after` instead.
+    async fn list_kv_after(
+        &self,
+        prefix: &str,
+        start_after: &str,
+    ) -> Result<KVStream<Self::Error>, Self::Error> {
+        let start_after = start_after.to_string();
+        let strm = self.list_kv(prefix).await?;
+        Ok(strm
+            .try_filter(move |x| futures_util::future::ready(x.key > start_after))
+            .boxed())
+    }
+
     // TODO: deprecate it:
     // #[deprecated(note = "use list_kv() instead")]
     /// List key-value records that are starts with the specified prefix.
@@ -147,6 +164,14 @@ impl<U: kvapi::KVApi, T: Deref<Target = U> + Send + Sync> kvapi::KVApi for T {
         self.deref().list_kv(prefix).await
     }
 
+    async fn list_kv_after(
+        &self,
+        prefix: &str,
+        start_after: &str,
+    ) -> Result<KVStream<Self::Error>, Self::Error> {
+        self.deref().list_kv_after(prefix, start_after).await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         self.deref().transaction(txn).await
     }
@@ -57,14 +57,23 @@ impl fmt::Display for MGetKVReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ListKVReq {
     pub prefix: String,
+    /// List only the keys after it, the listing starts at `prefix` if it is not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_after: Option<String>,
 }
 
 impl ListKVReq {
     pub fn new(prefix: impl ToString) -> Self {
         Self {
             prefix: prefix.to_string(),
+            start_after: None,
         }
     }
+
+    pub fn with_start_after(mut self, start_after: impl ToString) -> Self {
+        self.start_after = Some(start_after.to_string());
+        self
+    }
 }
 
 pub type UpsertKVReply = Change<Vec<u8>>;
@@ -53,6 +53,7 @@ impl FromToProto for mt::DatabaseMeta {
                 Some(from_share) => Some(ShareNameIdentRaw::from_pb(from_share)?),
                 None => None,
             },
+            created_by: p.created_by,
         };
         Ok(v)
     }
@@ -76,6 +77,7 @@ impl FromToProto for mt::DatabaseMeta {
                 Some(from_share) => Some(from_share.to_pb()?),
                 None => None,
             },
+            created_by: self.created_by.clone(),
         };
         Ok(p)
     }
@@ -45,6 +45,9 @@ impl FromToProtoEnum for mt::principal::StageFileFormatType {
             pb::StageFileFormatType::Orc => Ok(mt::principal::StageFileFormatType::Orc),
             pb::StageFileFormatType::Parquet => Ok(mt::principal::StageFileFormatType::Parquet),
             pb::StageFileFormatType::Xml => Ok(mt::principal::StageFileFormatType::Xml),
+            pb::StageFileFormatType::FixedWidth => {
+                Ok(mt::principal::StageFileFormatType::FixedWidth)
+            }
         }
     }
 
@@ -58,6 +61,9 @@ impl FromToProtoEnum for mt::principal::StageFileFormatType {
             mt::principal::StageFileFormatType::Orc => Ok(pb::StageFileFormatType::Orc),
             mt::principal::StageFileFormatType::Parquet => Ok(pb::StageFileFormatType::Parquet),
             mt::principal::StageFileFormatType::Xml => Ok(pb::StageFileFormatType::Xml),
+            mt::principal::StageFileFormatType::FixedWidth => {
+                Ok(pb::StageFileFormatType::FixedWidth)
+            }
             mt::principal::StageFileFormatType::None => Err(Incompatible {
                 reason: "StageFileFormatType::None cannot be converted to protobuf".to_string(),
             }),
@@ -245,6 +251,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::FixedWidth(p)) => {
+                Ok(mt::principal::FileFormatParams::FixedWidth(
+                    mt::principal::FixedWidthFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -283,6 +294,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::FixedWidth(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::FixedWidth(
+                    mt::principal::FixedWidthFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -296,15 +312,27 @@ impl FromToProto for mt::principal::ParquetFileFormatParams {
     fn from_pb(p: pb::ParquetFileFormatParams) -> Result<Self, Incompatible>
     where Self: Sized {
         reader_check_msg(p.ver, p.min_reader_ver)?;
+        let compression = match p.compression {
+            Some(c) => mt::principal::StageFileCompression::from_pb_enum(
+                FromPrimitive::from_i32(c).ok_or_else(|| Incompatible {
+                    reason: format!("invalid StageFileCompression: {}", c),
+                })?,
+            )?,
+            None => mt::principal::ParquetFileFormatParams::default().compression,
+        };
         Ok(mt::principal::ParquetFileFormatParams {
+            compression,
             missing_field_as: NullAs::Error,
         })
     }
 
     fn to_pb(&self) -> Result<pb::ParquetFileFormatParams, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
         Ok(pb::ParquetFileFormatParams {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
+            compression: Some(compression),
         })
     }
 }
@@ -329,6 +357,8 @@ impl FromToProto for mt::principal::NdJsonFileFormatParams {
             p.missing_field_as.as_deref(),
             p.null_field_as.as_deref(),
             p.null_if,
+            p.time_zone,
+            p.skip_blank_lines,
         )
         .map_err(|e| Incompatible {
             reason: format!("{e}"),
@@ -345,6 +375,8 @@ impl FromToProto for mt::principal::NdJsonFileFormatParams {
             missing_field_as: Some(self.missing_field_as.to_string()),
             null_field_as: Some(self.null_field_as.to_string()),
             null_if: self.null_if.clone(),
+            time_zone: self.time_zone.clone(),
+            skip_blank_lines: self.skip_blank_lines,
         })
     }
 }
@@ -409,6 +441,40 @@ impl FromToProto for mt::principal::XmlFileFormatParams {
     }
 }
 
+impl FromToProto for mt::principal::FixedWidthFileFormatParams {
+    type PB = pb::FixedWidthFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        let compression = mt::principal::StageFileCompression::from_pb_enum(
+            FromPrimitive::from_i32(p.compression).ok_or_else(|| Incompatible {
+                reason: format!("invalid StageFileCompression: {}", p.compression),
+            })?,
+        )?;
+        Ok(Self {
+            compression,
+            widths: p.widths,
+            trim: p.trim,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
+        Ok(Self::PB {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            compression,
+            widths: self.widths.clone(),
+            trim: self.trim,
+        })
+    }
+}
+
 impl FromToProto for mt::principal::CsvFileFormatParams {
     type PB = pb::CsvFileFormatParams;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -465,11 +531,14 @@ impl FromToProto for mt::principal::CsvFileFormatParams {
             escape: p.escape,
             nan_display: p.nan_display,
             null_display,
+            output_null_display: p.output_null_display,
             error_on_column_count_mismatch: !p.allow_column_count_mismatch,
             empty_field_as,
             binary_format,
             output_header: p.output_header,
             geometry_format,
+            time_zone: p.time_zone,
+            skip_blank_lines: p.skip_blank_lines,
         })
     }
 
@@ -487,11 +556,14 @@ impl FromToProto for mt::principal::CsvFileFormatParams {
             escape: self.escape.clone(),
             nan_display: self.nan_display.clone(),
             null_display: self.null_display.clone(),
+            output_null_display: self.output_null_display.clone(),
             allow_column_count_mismatch: !self.error_on_column_count_mismatch,
             empty_field_as: Some(self.empty_field_as.to_string()),
             binary_format: Some(self.binary_format.to_string()),
             output_header: self.output_header,
             geometry_format: Some(self.geometry_format.to_string()),
+            time_zone: self.time_zone.clone(),
+            skip_blank_lines: self.skip_blank_lines,
         })
     }
 }
@@ -518,6 +590,8 @@ impl FromToProto for mt::principal::TsvFileFormatParams {
             escape: p.escape,
             nan_display: p.nan_display,
             quote: p.quote,
+            time_zone: p.time_zone,
+            skip_blank_lines: p.skip_blank_lines,
         })
     }
 
@@ -534,6 +608,8 @@ impl FromToProto for mt::principal::TsvFileFormatParams {
             escape: self.escape.clone(),
             quote: self.quote.clone(),
             nan_display: self.nan_display.clone(),
+            time_zone: self.time_zone.clone(),
+            skip_blank_lines: self.skip_blank_lines,
         })
     }
 }
@@ -15,6 +15,8 @@
 //! This mod is the key point about compatibility.
 //! Everytime update anything in this file, update the `VER` and let the tests pass.
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_meta_app as mt;
 use databend_common_protos::pb;
 
@@ -40,6 +42,10 @@ impl FromToProto for mt::principal::OwnershipInfo {
                     reason: "Object can not be None".to_string(),
                 }
             })?)?,
+            updated_on: match p.updated_on {
+                Some(t) => DateTime::<Utc>::from_pb(t)?,
+                None => DateTime::<Utc>::default(),
+            },
         })
     }
 
@@ -49,6 +55,7 @@ impl FromToProto for mt::principal::OwnershipInfo {
             min_reader_ver: MIN_READER_VER,
             role: self.role.clone(),
             object: Some(self.object.to_pb()?),
+            updated_on: Some(self.updated_on.to_pb()?),
         })
     }
 }
@@ -82,6 +89,9 @@ impl FromToProto for mt::principal::OwnershipObject {
             Some(pb::ownership_object::Object::Stage(
                 pb::ownership_object::OwnershipStageObject { stage },
             )) => Ok(mt::principal::OwnershipObject::Stage { name: stage }),
+            Some(pb::ownership_object::Object::Sequence(
+                pb::ownership_object::OwnershipSequenceObject { sequence },
+            )) => Ok(mt::principal::OwnershipObject::Sequence { name: sequence }),
             _ => Err(Incompatible {
                 reason: "OwnershipObject cannot be None".to_string(),
             }),
@@ -120,6 +130,13 @@ impl FromToProto for mt::principal::OwnershipObject {
                     stage: name.clone(),
                 }),
             ),
+            mt::principal::OwnershipObject::Sequence { name } => {
+                Some(pb::ownership_object::Object::Sequence(
+                    pb::ownership_object::OwnershipSequenceObject {
+                        sequence: name.clone(),
+                    },
+                ))
+            }
         };
         Ok(pb::OwnershipObject {
             ver: VER,
@@ -19,6 +19,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::TableDataType;
 use databend_common_meta_app::principal as mt;
 use databend_common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
@@ -132,6 +133,9 @@ impl FromToProto for mt::UDFScript {
             handler: p.handler,
             language: p.language,
             runtime_version: p.runtime_version,
+            on_error: FromPrimitive::from_i32(p.on_error).ok_or_else(|| Incompatible {
+                reason: format!("invalid UDFScript.on_error: {}", p.on_error),
+            })?,
         })
     }
 
@@ -160,6 +164,7 @@ impl FromToProto for mt::UDFScript {
             arg_types,
             return_type: Some(return_type),
             runtime_version: self.runtime_version.clone(),
+            on_error: self.on_error as i32,
         })
     }
 }
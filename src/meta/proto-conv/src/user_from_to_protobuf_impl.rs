@@ -180,6 +180,9 @@ impl FromToProto for mt::principal::GrantObject {
             Some(pb::grant_object::Object::Stage(pb::grant_object::GrantStageObject { stage })) => {
                 Ok(mt::principal::GrantObject::Stage(stage))
             }
+            Some(pb::grant_object::Object::Sequence(pb::grant_object::GrantSequenceObject {
+                sequence,
+            })) => Ok(mt::principal::GrantObject::Sequence(sequence)),
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -225,6 +228,11 @@ impl FromToProto for mt::principal::GrantObject {
                     stage: stage.clone(),
                 },
             )),
+            mt::principal::GrantObject::Sequence(sequence) => Some(
+                pb::grant_object::Object::Sequence(pb::grant_object::GrantSequenceObject {
+                    sequence: sequence.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
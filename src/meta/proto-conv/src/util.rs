@@ -119,6 +119,15 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (87, "2024-04-17: Add: UserOption::disabled"),
     (88, "2024-04-17: Add: SequenceMeta"),
     (89, "2024-04-19: Add: geometry_output_format settings"),
+    (90, "2024-04-22: Add: OwnershipInfo::updated_on"),
+    (91, "2024-04-23: Add: CsvFileFormatParams::output_null_display"),
+    (92, "2024-04-24: Add: Csv/Tsv/NdJsonFileFormatParams::time_zone"),
+    (93, "2024-04-25: Add: DatabaseMeta::created_by"),
+    (94, "2024-04-26: Add: GrantObject::Sequence and OwnershipObject::Sequence"),
+    (95, "2024-04-27: Add: ParquetFileFormatParams::compression"),
+    (96, "2024-04-28: Add: FixedWidthFileFormatParams and StageFileFormatType::FixedWidth"),
+    (97, "2024-04-29: Add: Csv/Tsv/NdJsonFileFormatParams::skip_blank_lines"),
+    (98, "2024-04-30: Add: UDFScript::on_error"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
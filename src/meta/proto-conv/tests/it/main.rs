@@ -92,3 +92,12 @@ mod v086_table_index;
 mod v087_user_option_disabled;
 mod v088_sequence_meta;
 mod v089_geometry_output_format;
+mod v090_ownership_info_updated_on;
+mod v091_csv_output_null_display;
+mod v092_file_format_time_zone;
+mod v093_database_meta_created_by;
+mod v094_sequence_ownership;
+mod v095_parquet_compression;
+mod v096_fixed_width_file_format;
+mod v097_file_format_skip_blank_lines;
+mod v098_udf_script_on_error;
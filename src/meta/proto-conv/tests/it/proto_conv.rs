@@ -57,6 +57,7 @@ fn new_db_meta_share() -> mt::DatabaseMeta {
         drop_on: None,
         shared_by: BTreeSet::new(),
         from_share: Some(ShareNameIdentRaw::new("tenant", "share")),
+        created_by: None,
     }
 }
 
@@ -71,6 +72,7 @@ fn new_db_meta() -> mt::DatabaseMeta {
         drop_on: None,
         shared_by: BTreeSet::from_iter(vec![1]),
         from_share: None,
+        created_by: None,
     }
 }
 
@@ -377,6 +379,32 @@ fn test_pb_from_to() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_db_meta_disable_time_travel_from_to() -> anyhow::Result<()> {
+    let db = new_db_meta();
+    assert!(!db.time_travel_disabled());
+
+    let cases = [
+        ("true", true),
+        ("T", true),
+        ("1", true),
+        ("false", false),
+        ("x", false),
+    ];
+    for (value, want) in cases {
+        let mut db = new_db_meta();
+        db.options
+            .insert(s(mt::OPT_KEY_DISABLE_TIME_TRAVEL), s(value));
+        assert_eq!(want, db.time_travel_disabled(), "value: {value}");
+
+        let got = mt::DatabaseMeta::from_pb(db.to_pb()?)?;
+        assert_eq!(db, got);
+        assert_eq!(want, got.time_travel_disabled(), "value: {value}");
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_incompatible() -> anyhow::Result<()> {
     let db_meta = new_db_meta();
@@ -48,6 +48,7 @@ fn test_decode_v32_csv_file_format_params() -> anyhow::Result<()> {
             field_delimiter: "fd".to_string(),
             record_delimiter: "rd".to_string(),
             null_display: "\\N".to_string(),
+            output_null_display: None,
             nan_display: "nan".to_string(),
             escape: "\\".to_string(),
             quote: "\'".to_string(),
@@ -55,6 +56,8 @@ fn test_decode_v32_csv_file_format_params() -> anyhow::Result<()> {
             empty_field_as: Default::default(),
             binary_format: Default::default(),
             geometry_format: Default::default(),
+            time_zone: None,
+            skip_blank_lines: false,
         })
     };
     common::test_load_old(func_name!(), file_format_params_v32.as_slice(), 0, want())?;
@@ -78,6 +81,8 @@ fn test_decode_v32_tsv_file_format_params() -> anyhow::Result<()> {
             nan_display: "nan".to_string(),
             escape: "\\".to_string(),
             quote: "\'".to_string(),
+            time_zone: None,
+            skip_blank_lines: false,
         })
     };
     common::test_pb_from_to(func_name!(), want())?;
@@ -95,6 +100,8 @@ fn test_decode_v32_ndjson_file_format_params() -> anyhow::Result<()> {
             missing_field_as: NullAs::Error,
             null_field_as: NullAs::Null,
             null_if: vec![],
+            time_zone: None,
+            skip_blank_lines: false,
         })
     };
     common::test_pb_from_to(func_name!(), want())?;
@@ -138,6 +145,7 @@ fn test_decode_v32_parquet_file_format_params() -> anyhow::Result<()> {
 
     let want = || {
         mt::principal::FileFormatParams::Parquet(ParquetFileFormatParams {
+            compression: StageFileCompression::Snappy,
             missing_field_as: Default::default(),
         })
     };
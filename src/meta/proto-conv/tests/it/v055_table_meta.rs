@@ -124,6 +124,7 @@ fn test_decode_v51_database_meta() -> anyhow::Result<()> {
         drop_on: None,
         shared_by: BTreeSet::new(),
         from_share: Some(ShareNameIdentRaw::new("tenant", "share")),
+        created_by: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
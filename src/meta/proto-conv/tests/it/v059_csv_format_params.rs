@@ -42,6 +42,7 @@ fn test_decode_v59_csv_file_format_params() -> anyhow::Result<()> {
             field_delimiter: "fd".to_string(),
             record_delimiter: "rd".to_string(),
             null_display: "\\N".to_string(),
+            output_null_display: None,
             nan_display: "nan".to_string(),
             escape: "\\".to_string(),
             quote: "\'".to_string(),
@@ -49,6 +50,8 @@ fn test_decode_v59_csv_file_format_params() -> anyhow::Result<()> {
             empty_field_as: Default::default(),
             binary_format: Default::default(),
             geometry_format: Default::default(),
+            time_zone: None,
+            skip_blank_lines: false,
         })
     };
     common::test_load_old(func_name!(), file_format_params_v59.as_slice(), 0, want())?;
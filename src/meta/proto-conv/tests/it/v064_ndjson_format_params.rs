@@ -42,6 +42,8 @@ fn test_decode_v64_ndjson_file_format_params() -> anyhow::Result<()> {
             missing_field_as: NullAs::FieldDefault,
             null_field_as: NullAs::Null,
             null_if: vec![],
+            time_zone: None,
+            skip_blank_lines: false,
         })
     };
     common::test_pb_from_to(func_name!(), want())?;
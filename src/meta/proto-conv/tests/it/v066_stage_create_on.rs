@@ -55,6 +55,7 @@ fn test_decode_v66_stage() -> anyhow::Result<()> {
         is_temporary: false,
         file_format_params: mt::principal::FileFormatParams::Parquet(
             mt::principal::ParquetFileFormatParams {
+                compression: mt::principal::StageFileCompression::Snappy,
                 missing_field_as: Default::default(),
             },
         ),
@@ -121,6 +121,7 @@ fn test_decode_v74_database_meta() -> anyhow::Result<()> {
         drop_on: None,
         shared_by: BTreeSet::new(),
         from_share: Some(ShareNameIdentRaw::new("tenant", "share")),
+        created_by: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -45,6 +45,7 @@ fn test_decode_v75_csv_file_format_params() -> anyhow::Result<()> {
         field_delimiter: "fd".to_string(),
         record_delimiter: "rd".to_string(),
         null_display: "Null".to_string(),
+        output_null_display: None,
         nan_display: "my_nan".to_string(),
         escape: "|".to_string(),
         quote: "\'".to_string(),
@@ -52,6 +53,8 @@ fn test_decode_v75_csv_file_format_params() -> anyhow::Result<()> {
         empty_field_as: EmptyFieldAs::String,
         binary_format: BinaryFormat::Base64,
         geometry_format: GeometryDataType::EWKT,
+        time_zone: None,
+        skip_blank_lines: false,
     };
     common::test_load_old(
         func_name!(),
@@ -14,6 +14,8 @@
 
 use std::collections::HashSet;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_meta_app as mt;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::principal::UserGrantSet;
@@ -108,6 +110,7 @@ fn test_decode_v76_ownership() -> anyhow::Result<()> {
             catalog_name: "default".to_string(),
             db_id: 1,
         },
+        updated_on: DateTime::<Utc>::default(),
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), ownership_info_v76.as_slice(), 76, want())?;
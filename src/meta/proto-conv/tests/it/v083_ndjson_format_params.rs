@@ -40,6 +40,8 @@ fn test_decode_v83_ndjson_file_format_params() -> anyhow::Result<()> {
         missing_field_as: NullAs::FieldDefault,
         null_field_as: NullAs::FieldDefault,
         null_if: vec!["".to_string()],
+        time_zone: None,
+        skip_blank_lines: false,
     };
     common::test_load_old(
         func_name!(),
@@ -35,6 +35,7 @@ fn test_decode_v89_geometry_output_format() -> anyhow::Result<()> {
         field_delimiter: "fd".to_string(),
         record_delimiter: "rd".to_string(),
         null_display: "Null".to_string(),
+        output_null_display: None,
         nan_display: "my_nan".to_string(),
         escape: "|".to_string(),
         quote: "\'".to_string(),
@@ -42,6 +43,8 @@ fn test_decode_v89_geometry_output_format() -> anyhow::Result<()> {
         empty_field_as: EmptyFieldAs::String,
         binary_format: BinaryFormat::Base64,
         geometry_format: GeometryDataType::EWKT,
+        time_zone: None,
+        skip_blank_lines: false,
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(
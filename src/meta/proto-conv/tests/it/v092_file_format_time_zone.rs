@@ -0,0 +1,126 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_io::GeometryDataType;
+use databend_common_meta_app::principal::BinaryFormat;
+use databend_common_meta_app::principal::CsvFileFormatParams;
+use databend_common_meta_app::principal::EmptyFieldAs;
+use databend_common_meta_app::principal::NdJsonFileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_meta_app::principal::TsvFileFormatParams;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v92_csv_time_zone() -> anyhow::Result<()> {
+    let csv_file_format_params_v92 = vec![
+        8, 1, 16, 1, 26, 2, 102, 100, 34, 2, 114, 100, 42, 6, 109, 121, 95, 110, 97, 110, 50, 1,
+        124, 58, 1, 39, 66, 4, 78, 117, 108, 108, 72, 1, 82, 6, 83, 84, 82, 73, 78, 71, 90, 6, 98,
+        97, 115, 101, 54, 52, 96, 1, 106, 4, 69, 87, 75, 84, 114, 6, 60, 110, 117, 108, 108, 62,
+        122, 13, 65, 115, 105, 97, 47, 83, 104, 97, 110, 103, 104, 97, 105, 160, 6, 92, 168, 6, 24,
+    ];
+    let want = || CsvFileFormatParams {
+        compression: StageFileCompression::Gzip,
+        headers: 1,
+        output_header: true,
+        field_delimiter: "fd".to_string(),
+        record_delimiter: "rd".to_string(),
+        null_display: "Null".to_string(),
+        output_null_display: Some("<null>".to_string()),
+        nan_display: "my_nan".to_string(),
+        escape: "|".to_string(),
+        quote: "\'".to_string(),
+        error_on_column_count_mismatch: false,
+        empty_field_as: EmptyFieldAs::String,
+        binary_format: BinaryFormat::Base64,
+        geometry_format: GeometryDataType::EWKT,
+        time_zone: Some("Asia/Shanghai".to_string()),
+        skip_blank_lines: false,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        csv_file_format_params_v92.as_slice(),
+        92,
+        want(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v92_tsv_time_zone() -> anyhow::Result<()> {
+    let tsv_file_format_params_v92 = vec![
+        8, 1, 16, 1, 26, 2, 102, 100, 34, 2, 114, 100, 42, 3, 110, 97, 110, 50, 1, 92, 58, 1, 39,
+        66, 13, 65, 115, 105, 97, 47, 83, 104, 97, 110, 103, 104, 97, 105, 160, 6, 92, 168, 6, 24,
+    ];
+    let want = || TsvFileFormatParams {
+        compression: StageFileCompression::Gzip,
+        headers: 1,
+        field_delimiter: "fd".to_string(),
+        record_delimiter: "rd".to_string(),
+        nan_display: "nan".to_string(),
+        escape: "\\".to_string(),
+        quote: "\'".to_string(),
+        time_zone: Some("Asia/Shanghai".to_string()),
+        skip_blank_lines: false,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        tsv_file_format_params_v92.as_slice(),
+        92,
+        want(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v92_ndjson_time_zone() -> anyhow::Result<()> {
+    let nd_json_file_format_params_v92 = vec![
+        8, 1, 18, 13, 70, 73, 69, 76, 68, 95, 68, 69, 70, 65, 85, 76, 84, 26, 13, 70, 73, 69, 76,
+        68, 95, 68, 69, 70, 65, 85, 76, 84, 34, 0, 42, 13, 65, 115, 105, 97, 47, 83, 104, 97, 110,
+        103, 104, 97, 105, 160, 6, 92, 168, 6, 24,
+    ];
+    let want = || NdJsonFileFormatParams {
+        compression: StageFileCompression::Gzip,
+        missing_field_as: NullAs::FieldDefault,
+        null_field_as: NullAs::FieldDefault,
+        null_if: vec!["".to_string()],
+        time_zone: Some("Asia/Shanghai".to_string()),
+        skip_blank_lines: false,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        nd_json_file_format_params_v92.as_slice(),
+        92,
+        want(),
+    )?;
+
+    Ok(())
+}
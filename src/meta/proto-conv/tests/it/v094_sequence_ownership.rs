@@ -0,0 +1,78 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::UserGrantSet;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v94_role_sequence_grant() -> anyhow::Result<()> {
+    let role_info_v94 = vec![
+        10, 2, 114, 49, 18, 32, 10, 24, 10, 14, 66, 6, 10, 4, 115, 101, 113, 49, 160, 6, 94, 168,
+        6, 24, 16, 1, 160, 6, 94, 168, 6, 24, 160, 6, 94, 168, 6, 24, 160, 6, 94, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::RoleInfo {
+        name: "r1".to_string(),
+        grants: UserGrantSet::new(
+            vec![mt::principal::GrantEntry::new(
+                mt::principal::GrantObject::Sequence("seq1".to_string()),
+                make_bitflags!(UserPrivilegeType::{Usage}),
+            )],
+            HashSet::new(),
+        ),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), role_info_v94.as_slice(), 94, want())
+}
+
+#[test]
+fn test_decode_v94_sequence_ownership() -> anyhow::Result<()> {
+    let ownership_info_v94 = vec![
+        10, 2, 114, 49, 18, 14, 42, 6, 10, 4, 115, 101, 113, 49, 160, 6, 94, 168, 6, 24, 26, 23,
+        50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67,
+        160, 6, 94, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::OwnershipInfo {
+        role: "r1".to_string(),
+        object: OwnershipObject::Sequence {
+            name: "seq1".to_string(),
+        },
+        updated_on: DateTime::<Utc>::from_timestamp(1417176009, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), ownership_info_v94.as_slice(), 94, want())
+}
@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::FixedWidthFileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v96_fixed_width_file_format() -> anyhow::Result<()> {
+    let fixed_width_file_format_params_v96 =
+        vec![8, 1, 18, 3, 3, 5, 10, 24, 1, 160, 6, 96, 168, 6, 24];
+    let want = || FixedWidthFileFormatParams {
+        compression: StageFileCompression::Gzip,
+        widths: vec![3, 5, 10],
+        trim: true,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        fixed_width_file_format_params_v96.as_slice(),
+        96,
+        want(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UDFOnError;
+use databend_common_meta_app::principal::UDFScript;
+use databend_common_meta_app::principal::UserDefinedFunction;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v98_udf_script_on_error() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 11, 102, 97, 105, 108, 95, 111, 110, 95, 116, 119, 111, 18, 21, 84, 104, 105, 115, 32,
+        105, 115, 32, 97, 32, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 50, 81, 10, 8,
+        116, 101, 115, 116, 99, 111, 100, 101, 18, 11, 102, 97, 105, 108, 95, 111, 110, 95, 116,
+        119, 111, 26, 10, 106, 97, 118, 97, 115, 99, 114, 105, 112, 116, 34, 17, 154, 2, 8, 58, 0,
+        160, 6, 98, 168, 6, 24, 160, 6, 98, 168, 6, 24, 42, 17, 154, 2, 8, 58, 0, 160, 6, 98, 168,
+        6, 24, 160, 6, 98, 168, 6, 24, 56, 1, 160, 6, 98, 168, 6, 24, 42, 23, 50, 48, 50, 52, 45,
+        48, 52, 45, 51, 48, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6, 98, 168, 6,
+        24,
+    ];
+
+    let want = || UserDefinedFunction {
+        name: "fail_on_two".to_string(),
+        description: "This is a description".to_string(),
+        definition: UDFDefinition::UDFScript(UDFScript {
+            code: "testcode".to_string(),
+            handler: "fail_on_two".to_string(),
+            language: "javascript".to_string(),
+            arg_types: vec![DataType::Number(NumberDataType::Int32)],
+            return_type: DataType::Number(NumberDataType::Int32),
+            runtime_version: "".to_string(),
+            on_error: UDFOnError::Null,
+        }),
+        created_on: DateTime::<Utc>::from_timestamp(1714435200, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 98, want())
+}
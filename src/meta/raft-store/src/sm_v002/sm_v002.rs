@@ -16,6 +16,7 @@ use std::fmt::Debug;
 use std::future;
 use std::io;
 use std::iter::repeat_with;
+use std::ops::Bound;
 use std::sync::Arc;
 
 use databend_common_meta_kvapi::kvapi;
@@ -106,6 +107,23 @@ impl<'a> kvapi::KVApi for SMV002KVApi<'a> {
         Ok(strm.boxed())
     }
 
+    async fn list_kv_after(
+        &self,
+        prefix: &str,
+        start_after: &str,
+    ) -> Result<KVStream<Self::Error>, Self::Error> {
+        let local_now_ms = SeqV::<()>::now_ms();
+
+        let strm = self
+            .sm
+            .list_kv_after(prefix, start_after)
+            .await?
+            .try_filter(move |(_k, v)| future::ready(!v.is_expired(local_now_ms)))
+            .map_ok(StreamItem::from);
+
+        Ok(strm.boxed())
+    }
+
     async fn transaction(&self, _txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         unreachable!("write operation SM2KVApi::transaction is disabled")
     }
@@ -306,12 +324,46 @@ impl SMV002 {
     ///
     /// If a value is expired, it is not returned.
     pub async fn list_kv(&self, prefix: &str) -> Result<ResultStream<(String, SeqV)>, io::Error> {
+        self.list_kv_range(prefix, Bound::Included(prefix.to_string()))
+            .await
+    }
+
+    /// List kv entries by prefix, that come after `start_after`.
+    ///
+    /// If a value is expired, it is not returned.
+    pub async fn list_kv_after(
+        &self,
+        prefix: &str,
+        start_after: &str,
+    ) -> Result<ResultStream<(String, SeqV)>, io::Error> {
+        if start_after < prefix {
+            self.list_kv(prefix).await
+        } else if start_after.starts_with(prefix) {
+            self.list_kv_range(prefix, Bound::Excluded(start_after.to_string()))
+                .await
+        } else {
+            // All the keys with the prefix come before `start_after`.
+            Ok(futures::stream::empty().boxed())
+        }
+    }
+
+    async fn list_kv_range(
+        &self,
+        prefix: &str,
+        start: Bound<String>,
+    ) -> Result<ResultStream<(String, SeqV)>, io::Error> {
         let p = prefix.to_string();
 
         let strm = if let Some(right) = prefix_right_bound(&p) {
-            self.levels.str_map().range(p.clone()..right).await?
+            self.levels
+                .str_map()
+                .range((start, Bound::Excluded(right)))
+                .await?
         } else {
-            self.levels.str_map().range(p.clone()..).await?
+            self.levels
+                .str_map()
+                .range((start, Bound::Unbounded))
+                .await?
         };
 
         let strm = strm
@@ -132,6 +132,42 @@ async fn test_two_level_upsert_get_range() -> anyhow::Result<()> {
 
     let got = sm.list_kv("a").await?.try_collect::<Vec<_>>().await?;
     assert_eq!(got, vec![(s("a"), SeqV::new(1, b("a0"))),]);
+
+    // list_kv_after()
+
+    let got = sm
+        .list_kv_after("", "a")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(got, vec![
+        (s("c"), SeqV::new(4, b("c1"))),
+        (s("d"), SeqV::new(5, b("d1"))),
+    ]);
+
+    let got = sm
+        .list_kv_after("", "b")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(got, vec![
+        (s("c"), SeqV::new(4, b("c1"))),
+        (s("d"), SeqV::new(5, b("d1"))),
+    ]);
+
+    let got = sm
+        .list_kv_after("d", "")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(got, vec![(s("d"), SeqV::new(5, b("d1"))),]);
+
+    let got = sm
+        .list_kv_after("c", "d")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(got, vec![]);
     Ok(())
 }
 
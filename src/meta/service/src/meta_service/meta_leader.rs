@@ -36,6 +36,7 @@ use databend_common_meta_types::RaftError;
 use databend_common_meta_types::SeqV;
 use databend_common_metrics::count::Count;
 use futures::StreamExt;
+use futures::TryStreamExt;
 use log::debug;
 use log::info;
 use maplit::btreemap;
@@ -147,12 +148,27 @@ impl<'a> Handler<MetaGrpcReadReq> for MetaLeader<'a> {
             }
 
             MetaGrpcReadReq::ListKV(req) => {
-                // safe unwrap(): Infallible
-                let kvs = kv_api.prefix_list_kv(&req.prefix).await.unwrap();
+                let Some(start_after) = &req.start_after else {
+                    // safe unwrap(): Infallible
+                    let kvs = kv_api.prefix_list_kv(&req.prefix).await.unwrap();
 
-                let kv_iter = kvs.into_iter().map(|kv| Ok(StreamItem::from(kv)));
+                    let kv_iter = kvs.into_iter().map(|kv| Ok(StreamItem::from(kv)));
 
-                let strm = futures::stream::iter(kv_iter);
+                    let strm = futures::stream::iter(kv_iter);
+
+                    return Ok(strm.boxed());
+                };
+
+                // safe unwrap(): Infallible
+                let items = kv_api
+                    .list_kv_after(&req.prefix, start_after)
+                    .await
+                    .unwrap()
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .unwrap();
+
+                let strm = futures::stream::iter(items.into_iter().map(Ok));
 
                 Ok(strm.boxed())
             }
@@ -76,9 +76,28 @@ impl kvapi::KVApi for MetaNode {
 
     #[minitrace::trace]
     async fn list_kv(&self, prefix: &str) -> Result<KVStream<Self::Error>, Self::Error> {
-        let req = ListKVReq {
-            prefix: prefix.to_string(),
-        };
+        let req = ListKVReq::new(prefix);
+
+        let res = self
+            .handle_forwardable_request(ForwardRequest::new(1, MetaGrpcReadReq::ListKV(req)))
+            .await;
+
+        server_metrics::incr_read_result(&res);
+
+        // TODO: enable returning endpoint
+        let (_endpoint, strm) = res?;
+
+        let strm = strm.map_err(MetaAPIError::from);
+        Ok(strm.boxed())
+    }
+
+    #[minitrace::trace]
+    async fn list_kv_after(
+        &self,
+        prefix: &str,
+        start_after: &str,
+    ) -> Result<KVStream<Self::Error>, Self::Error> {
+        let req = ListKVReq::new(prefix).with_start_after(start_after);
 
         let res = self
             .handle_forwardable_request(ForwardRequest::new(1, MetaGrpcReadReq::ListKV(req)))
@@ -190,9 +190,7 @@ async fn test_streamed_mget(client: &Arc<ClientHandle>, now_sec: u64) -> anyhow:
 async fn test_streamed_list(client: &Arc<ClientHandle>, _now_sec: u64) -> anyhow::Result<()> {
     info!("--- test streamed list");
 
-    let strm = client
-        .request(Streamed(ListKVReq { prefix: s("c") }))
-        .await?;
+    let strm = client.request(Streamed(ListKVReq::new("c"))).await?;
 
     let got = strm.map_err(|e| e.to_string()).collect::<Vec<_>>().await;
     assert_eq!(
@@ -106,6 +106,17 @@ impl kvapi::KVApi for MetaStore {
         }
     }
 
+    async fn list_kv_after(
+        &self,
+        prefix: &str,
+        start_after: &str,
+    ) -> Result<KVStream<Self::Error>, Self::Error> {
+        match self {
+            MetaStore::L(x) => x.list_kv_after(prefix, start_after).await,
+            MetaStore::R(x) => x.list_kv_after(prefix, start_after).await,
+        }
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         match self {
             MetaStore::L(x) => x.transaction(txn).await,
@@ -738,6 +738,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                                 ExplainOption::Verbose => "Verbose",
                                 ExplainOption::Logical => "Logical",
                                 ExplainOption::Optimized => "Optimized",
+                                ExplainOption::Verbosity(_) => "Verbosity",
                             }
                         })
                         .join(", ")
@@ -1247,6 +1248,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterDatabaseAction::SetOptions { set_options } => {
+                let mut action_name = "Action Set Option: ".to_string();
+                for (key, value) in set_options.iter() {
+                    action_name.push_str(format!("{key} to {value}").as_str());
+                }
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterDatabaseAction::UnsetOptions { unset_options } => {
+                let action_name = format!("Action Unset Option: {}", unset_options.join(", "));
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterDatabase".to_string();
@@ -1431,10 +1445,15 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 catalog,
                 database,
                 table,
+                including_options,
             } => {
                 self.visit_table_ref(catalog, database, table);
                 let child = self.children.pop().unwrap();
-                let name = "LikeTable".to_string();
+                let name = if *including_options {
+                    "LikeTable IncludingOptions".to_string()
+                } else {
+                    "LikeTable".to_string()
+                };
                 let format_ctx = AstFormatContext::with_children(name, 1);
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
@@ -1578,7 +1597,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(action_name, children.len());
                 FormatTreeNode::with_children(action_format_ctx, children)
             }
-            AlterTableAction::FlashbackTo { point } => {
+            AlterTableAction::FlashbackTo { point, .. } => {
                 self.visit_time_travel_point(point);
                 let point_node = self.children.pop().unwrap();
                 let action_name = "Action FlashbackTo".to_string();
@@ -1623,6 +1642,23 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_truncate_tables(&mut self, stmt: &'ast TruncateTablesStmt) {
+        let mut children = Vec::with_capacity(stmt.tables.len());
+        for table in &stmt.tables {
+            self.visit_table_ref(&table.catalog, &table.database, &table.table);
+            children.push(self.children.pop().unwrap());
+        }
+
+        let name = if stmt.if_exists {
+            "TruncateTables IfExists".to_string()
+        } else {
+            "TruncateTables".to_string()
+        };
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_optimize_table(&mut self, stmt: &'ast OptimizeTableStmt) {
         let mut children = Vec::new();
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
@@ -2289,6 +2325,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 handler,
                 language,
                 runtime_version,
+                on_error,
             } => {
                 if !arg_types.is_empty() {
                     let mut arg_types_children = Vec::with_capacity(arg_types.len());
@@ -2322,6 +2359,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let runtime_format_ctx: AstFormatContext =
                     AstFormatContext::new(format!("RuntimeVersion {runtime_version}"));
                 children.push(FormatTreeNode::new(runtime_format_ctx));
+
+                if let Some(on_error) = on_error {
+                    let on_error_format_ctx =
+                        AstFormatContext::new(format!("UdfOnError {on_error}"));
+                    children.push(FormatTreeNode::new(on_error_format_ctx));
+                }
             }
         }
 
@@ -2427,6 +2470,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 handler,
                 language,
                 runtime_version,
+                on_error,
             } => {
                 if !arg_types.is_empty() {
                     let mut arg_types_children = Vec::with_capacity(arg_types.len());
@@ -2459,6 +2503,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
                 let c = AstFormatContext::new(format!("RuntimeVersion {runtime_version}"));
                 children.push(FormatTreeNode::new(c));
+
+                if let Some(on_error) = on_error {
+                    let c = AstFormatContext::new(format!("UdfOnError {on_error}"));
+                    children.push(FormatTreeNode::new(c));
+                }
             }
         }
 
@@ -125,6 +125,7 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
             catalog,
             database,
             table,
+            including_options,
         } => RcDoc::space()
             .append(RcDoc::text("LIKE"))
             .append(RcDoc::space())
@@ -138,7 +139,12 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             })
-            .append(RcDoc::text(table.to_string())),
+            .append(RcDoc::text(table.to_string()))
+            .append(if including_options {
+                RcDoc::space().append(RcDoc::text("INCLUDING OPTIONS"))
+            } else {
+                RcDoc::nil()
+            }),
     }
 }
 
@@ -224,7 +230,7 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             } else {
                 RcDoc::nil()
             }),
-        AlterTableAction::FlashbackTo { point } => match point {
+        AlterTableAction::FlashbackTo { point, dry_run } => match point {
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
             TimeTravelPoint::Offset(num) => RcDoc::text(format!(" AT (OFFSET => {num})")),
@@ -249,7 +255,12 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
                         .append(RcDoc::text(name.to_string())),
                 )
                 .append(RcDoc::text(")")),
-        },
+        }
+        .append(if dry_run {
+            RcDoc::text(" DRY RUN")
+        } else {
+            RcDoc::nil()
+        }),
         AlterTableAction::SetOptions { set_options } => {
             let mut doc = RcDoc::line();
             doc = doc.append(RcDoc::text("SET OPTIONS: "));
@@ -288,6 +299,11 @@ pub(crate) fn pretty_create_view(stmt: CreateViewStmt) -> RcDoc<'static> {
                 })
                 .append(RcDoc::text(stmt.view.to_string())),
         )
+        .append(if stmt.no_schema_binding {
+            RcDoc::space().append(RcDoc::text("WITH NO SCHEMA BINDING"))
+        } else {
+            RcDoc::nil()
+        })
         .append(
             RcDoc::line().append(RcDoc::text("AS")).append(
                 RcDoc::line()
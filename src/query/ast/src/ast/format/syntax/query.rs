@@ -66,10 +66,10 @@ fn pretty_with(with: Option<With>) -> RcDoc<'static> {
 fn pretty_cte(cte: CTE) -> RcDoc<'static> {
     RcDoc::text(format!("{} AS ", cte.alias))
         .append(RcDoc::softline())
-        .append(if cte.materialized {
-            RcDoc::text("MATERIALIZED ".to_string())
-        } else {
-            RcDoc::nil()
+        .append(match cte.materialized {
+            Some(true) => RcDoc::text("MATERIALIZED ".to_string()),
+            Some(false) => RcDoc::text("NOT MATERIALIZED ".to_string()),
+            None => RcDoc::nil(),
         })
         .append(parenthesized(pretty_query(*cte.query)))
 }
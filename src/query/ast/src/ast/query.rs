@@ -108,16 +108,20 @@ pub struct CTE {
     #[drive(skip)]
     pub span: Span,
     pub alias: TableAlias,
+    /// `Some(true)` for `MATERIALIZED`, `Some(false)` for `NOT MATERIALIZED`,
+    /// `None` if not specified.
     #[drive(skip)]
-    pub materialized: bool,
+    pub materialized: Option<bool>,
     pub query: Box<Query>,
 }
 
 impl Display for CTE {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} AS ", self.alias)?;
-        if self.materialized {
-            write!(f, "MATERIALIZED ")?;
+        match self.materialized {
+            Some(true) => write!(f, "MATERIALIZED ")?,
+            Some(false) => write!(f, "NOT MATERIALIZED ")?,
+            None => {}
         }
         write!(f, "({})", self.query)?;
         Ok(())
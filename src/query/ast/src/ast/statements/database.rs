@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -22,6 +23,8 @@ use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
 use crate::ast::statements::show::ShowLimit;
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_comma_separated_string_map;
 use crate::ast::write_dot_separated_list;
 use crate::ast::DatabaseRef;
 use crate::ast::Identifier;
@@ -84,6 +87,9 @@ impl Display for CreateDatabaseStmt {
         if let CreateOption::CreateOrReplace = self.create_option {
             write!(f, "OR REPLACE ")?;
         }
+        if self.options.iter().any(|opt| opt.name == "TRANSIENT") {
+            write!(f, "TRANSIENT ")?;
+        }
         write!(f, "DATABASE ")?;
         if let CreateOption::CreateIfNotExists = self.create_option {
             write!(f, "IF NOT EXISTS ")?;
@@ -162,6 +168,16 @@ impl Display for AlterDatabaseStmt {
             AlterDatabaseAction::RenameDatabase { new_db } => {
                 write!(f, " RENAME TO {new_db}")?;
             }
+            AlterDatabaseAction::SetOptions { set_options } => {
+                write!(f, " SET OPTIONS (")?;
+                write_comma_separated_string_map(f, set_options)?;
+                write!(f, ")")?;
+            }
+            AlterDatabaseAction::UnsetOptions { unset_options } => {
+                write!(f, " UNSET OPTIONS (")?;
+                write_comma_separated_list(f, unset_options)?;
+                write!(f, ")")?;
+            }
         }
 
         Ok(())
@@ -170,7 +186,17 @@ impl Display for AlterDatabaseStmt {
 
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub enum AlterDatabaseAction {
-    RenameDatabase { new_db: Identifier },
+    RenameDatabase {
+        new_db: Identifier,
+    },
+    SetOptions {
+        #[drive(skip)]
+        set_options: BTreeMap<String, String>,
+    },
+    UnsetOptions {
+        #[drive(skip)]
+        unset_options: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
@@ -44,4 +44,6 @@ pub enum ExplainOption {
     Verbose,
     Logical,
     Optimized,
+    // `VERBOSITY = 'terse' | 'normal' | 'verbose'`
+    Verbosity(#[drive(skip)] String),
 }
@@ -58,3 +58,20 @@ impl Display for ShowOptions {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShowSettingsScope {
+    Session,
+    Global,
+    Default,
+}
+
+impl Display for ShowSettingsScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShowSettingsScope::Session => write!(f, "SESSION"),
+            ShowSettingsScope::Global => write!(f, "GLOBAL"),
+            ShowSettingsScope::Default => write!(f, "DEFAULT"),
+        }
+    }
+}
@@ -52,6 +52,8 @@ pub enum Statement {
     Call(CallStmt),
 
     ShowSettings {
+        #[drive(skip)]
+        scope: Option<ShowSettingsScope>,
         show_options: Option<ShowOptions>,
     },
     ShowProcessList {
@@ -141,6 +143,7 @@ pub enum Statement {
     AlterTable(AlterTableStmt),
     RenameTable(RenameTableStmt),
     TruncateTable(TruncateTableStmt),
+    TruncateTables(TruncateTablesStmt),
     OptimizeTable(OptimizeTableStmt),
     VacuumTable(VacuumTableStmt),
     VacuumDropTable(VacuumDropTableStmt),
@@ -397,9 +400,12 @@ impl Display for Statement {
                             .iter()
                             .map(|opt| {
                                 match opt {
-                                    ExplainOption::Verbose => "VERBOSE",
-                                    ExplainOption::Logical => "LOGICAL",
-                                    ExplainOption::Optimized => "OPTIMIZED",
+                                    ExplainOption::Verbose => "VERBOSE".to_string(),
+                                    ExplainOption::Logical => "LOGICAL".to_string(),
+                                    ExplainOption::Optimized => "OPTIMIZED".to_string(),
+                                    ExplainOption::Verbosity(level) => {
+                                        format!("VERBOSITY = '{level}'")
+                                    }
                                 }
                             })
                             .join(", ")
@@ -432,8 +438,15 @@ impl Display for Statement {
             Statement::Update(stmt) => write!(f, "{stmt}")?,
             Statement::CopyIntoTable(stmt) => write!(f, "{stmt}")?,
             Statement::CopyIntoLocation(stmt) => write!(f, "{stmt}")?,
-            Statement::ShowSettings { show_options } => {
-                write!(f, "SHOW SETTINGS")?;
+            Statement::ShowSettings {
+                scope,
+                show_options,
+            } => {
+                write!(f, "SHOW")?;
+                if let Some(scope) = scope {
+                    write!(f, " {scope}")?;
+                }
+                write!(f, " SETTINGS")?;
                 if let Some(show_options) = show_options {
                     write!(f, " {show_options}")?;
                 }
@@ -545,6 +558,7 @@ impl Display for Statement {
             Statement::AlterTable(stmt) => write!(f, "{stmt}")?,
             Statement::RenameTable(stmt) => write!(f, "{stmt}")?,
             Statement::TruncateTable(stmt) => write!(f, "{stmt}")?,
+            Statement::TruncateTables(stmt) => write!(f, "{stmt}")?,
             Statement::OptimizeTable(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumTable(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumDropTable(stmt) => write!(f, "{stmt}")?,
@@ -101,6 +101,9 @@ impl Display for DropStreamStmt {
 pub struct ShowStreamsStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
+    /// Only show the streams on this source table.
+    pub table_database: Option<Identifier>,
+    pub table: Option<Identifier>,
     #[drive(skip)]
     pub full: bool,
     pub limit: Option<ShowLimit>,
@@ -120,6 +123,10 @@ impl Display for ShowStreamsStmt {
             }
             write!(f, "{database}")?;
         }
+        if let Some(table) = &self.table {
+            write!(f, " ON TABLE ")?;
+            write_dot_separated_list(f, self.table_database.iter().chain(Some(table)))?;
+        }
         if let Some(limit) = &self.limit {
             write!(f, " {limit}")?;
         }
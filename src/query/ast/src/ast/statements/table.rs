@@ -239,6 +239,8 @@ pub enum CreateTableSource {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
+        #[drive(skip)]
+        including_options: bool,
     },
 }
 
@@ -254,9 +256,14 @@ impl Display for CreateTableSource {
                 catalog,
                 database,
                 table,
+                including_options,
             } => {
                 write!(f, "LIKE ")?;
-                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if *including_options {
+                    write!(f, " INCLUDING OPTIONS")?;
+                }
+                Ok(())
             }
         }
     }
@@ -388,6 +395,9 @@ pub enum AlterTableAction {
     },
     FlashbackTo {
         point: TimeTravelPoint,
+        /// Only report what would change, without reverting the table.
+        #[drive(skip)]
+        dry_run: bool,
     },
     SetOptions {
         #[drive(skip)]
@@ -448,8 +458,11 @@ impl Display for AlterTableAction {
                     write!(f, " LIMIT {limit}")?;
                 }
             }
-            AlterTableAction::FlashbackTo { point } => {
+            AlterTableAction::FlashbackTo { point, dry_run } => {
                 write!(f, "FLASHBACK TO {}", point)?;
+                if *dry_run {
+                    write!(f, " DRY RUN")?;
+                }
             }
         };
         Ok(())
@@ -530,6 +543,37 @@ impl Display for TruncateTableStmt {
     }
 }
 
+/// `TRUNCATE TABLE [IF EXISTS] t1, t2, ...`
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct TruncateTablesStmt {
+    #[drive(skip)]
+    pub if_exists: bool,
+    pub tables: Vec<TruncateTableStmt>,
+}
+
+impl Display for TruncateTablesStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "TRUNCATE TABLE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        for (i, table) in self.tables.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write_dot_separated_list(
+                f,
+                table
+                    .catalog
+                    .iter()
+                    .chain(&table.database)
+                    .chain(Some(&table.table)),
+            )?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct VacuumTableStmt {
     pub catalog: Option<Identifier>,
@@ -52,6 +52,8 @@ pub enum UDFDefinition {
         language: String,
         #[drive(skip)]
         runtime_version: String,
+        #[drive(skip)]
+        on_error: Option<String>,
     },
 }
 
@@ -87,13 +89,18 @@ impl Display for UDFDefinition {
                 handler,
                 language,
                 runtime_version: _,
+                on_error,
             } => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, arg_types)?;
                 write!(
                     f,
-                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = '{handler}' AS $$\n{code}\n$$"
+                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = '{handler}'"
                 )?;
+                if let Some(on_error) = on_error {
+                    write!(f, " ON_ERROR = '{on_error}'")?;
+                }
+                write!(f, " AS $$\n{code}\n$$")?;
             }
         }
         Ok(())
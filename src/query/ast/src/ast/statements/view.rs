@@ -34,6 +34,9 @@ pub struct CreateViewStmt {
     pub view: Identifier,
     pub columns: Vec<Identifier>,
     pub query: Box<Query>,
+    /// Skip binding the query against the current schemas at create time.
+    #[drive(skip)]
+    pub no_schema_binding: bool,
 }
 
 impl Display for CreateViewStmt {
@@ -58,6 +61,9 @@ impl Display for CreateViewStmt {
             write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
+        if self.no_schema_binding {
+            write!(f, " WITH NO SCHEMA BINDING")?;
+        }
         write!(f, " AS {}", self.query)
     }
 }
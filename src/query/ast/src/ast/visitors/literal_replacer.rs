@@ -0,0 +1,54 @@
+// Copyright 2024 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Span;
+
+use crate::ast::Literal;
+use crate::ast::VisitorMut;
+
+/// A `VisitorMut` that walks every `Expr::Literal` and replaces it with the
+/// literal returned by the provided closure. Returning `None` keeps the
+/// original literal.
+///
+/// This is useful to anonymize a statement, e.g. replacing all the
+/// literals with placeholders before displaying it:
+///
+/// ```ignore
+/// let mut replacer = LiteralReplacer::new(|_, _| Some(Literal::String("?".to_string())));
+/// replacer.visit_statement(&mut stmt);
+/// let anonymized = stmt.to_string();
+/// ```
+pub struct LiteralReplacer<F>
+where F: FnMut(Span, &Literal) -> Option<Literal>
+{
+    replace: F,
+}
+
+impl<F> LiteralReplacer<F>
+where F: FnMut(Span, &Literal) -> Option<Literal>
+{
+    pub fn new(replace: F) -> Self {
+        Self { replace }
+    }
+}
+
+impl<F> VisitorMut for LiteralReplacer<F>
+where F: FnMut(Span, &Literal) -> Option<Literal>
+{
+    fn visit_literal(&mut self, span: Span, lit: &mut Literal) {
+        if let Some(new_lit) = (self.replace)(span, lit) {
+            *lit = new_lit;
+        }
+    }
+}
@@ -14,11 +14,13 @@
 
 #![allow(deprecated)]
 
+mod literal_replacer;
 mod visitor;
 mod visitor_mut;
 mod walk;
 mod walk_mut;
 
+pub use literal_replacer::LiteralReplacer;
 pub use visitor::Visitor;
 pub use visitor_mut::VisitorMut;
 pub use walk::*;
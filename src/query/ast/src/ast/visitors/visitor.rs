@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_exception::Span;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_meta_app::principal::PrincipalIdentity;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::schema::CreateOption;
@@ -212,7 +213,9 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_literal(&mut self, _span: Span, _lit: &'ast Literal) {}
 
-    fn visit_count_all(&mut self, _span: Span, window: &'ast Option<Window>) {
+    fn visit_count_all(&mut self, span: Span, window: &'ast Option<Window>) {
+        self.visit_aggregate_function(span, "count", &[]);
+
         if let Some(window) = window {
             self.visit_window(window);
         }
@@ -227,14 +230,18 @@ pub trait Visitor<'ast>: Sized {
     #[allow(clippy::too_many_arguments)]
     fn visit_function_call(
         &mut self,
-        _span: Span,
-        _distinct: bool,
-        _name: &'ast Identifier,
+        span: Span,
+        distinct: bool,
+        name: &'ast Identifier,
         args: &'ast [Expr],
         params: &'ast [Expr],
         over: &'ast Option<Window>,
         lambda: &'ast Option<Lambda>,
     ) {
+        if distinct || AggregateFunctionFactory::instance().contains(&name.name) {
+            self.visit_aggregate_function(span, &name.name, args);
+        }
+
         for arg in args {
             walk_expr(self, arg);
         }
@@ -250,6 +257,11 @@ pub trait Visitor<'ast>: Sized {
         }
     }
 
+    /// Called before the arguments are walked when the function is an aggregate, i.e.
+    /// it is called with `DISTINCT`, its name is registered in the aggregate function
+    /// factory, combinators included, or it is `COUNT(*)`.
+    fn visit_aggregate_function(&mut self, _span: Span, _name: &str, _args: &'ast [Expr]) {}
+
     fn visit_window(&mut self, window: &'ast Window) {
         match window {
             Window::WindowReference(reference) => {
@@ -372,22 +384,42 @@ pub trait Visitor<'ast>: Sized {
     fn visit_explain(
         &mut self,
         _kind: &'ast ExplainKind,
-        _options: &'ast [ExplainOption],
-        _query: &'ast Statement,
+        options: &'ast [ExplainOption],
+        stmt: &'ast Statement,
     ) {
+        for option in options.iter() {
+            self.visit_explain_option(option);
+        }
+        walk_statement(self, stmt);
     }
 
+    fn visit_explain_option(&mut self, _option: &'ast ExplainOption) {}
+
     fn visit_copy_into_table(&mut self, copy: &'ast CopyIntoTableStmt) {
-        if let CopyIntoTableSource::Query(query) = &copy.src {
-            self.visit_query(query)
+        match &copy.src {
+            CopyIntoTableSource::Location(location) => self.visit_file_location(location),
+            CopyIntoTableSource::Query(query) => self.visit_query(query),
         }
+        self.visit_file_format_options(&copy.file_format);
     }
     fn visit_copy_into_location(&mut self, copy: &'ast CopyIntoLocationStmt) {
         if let CopyIntoLocationSource::Query(query) = &copy.src {
             self.visit_query(query)
         }
+        self.visit_file_location(&copy.dst);
+        self.visit_file_format_options(&copy.file_format);
     }
 
+    fn visit_file_location(&mut self, location: &'ast FileLocation) {
+        if let FileLocation::Uri(uri) = location {
+            self.visit_connection(&uri.connection);
+        }
+    }
+
+    fn visit_connection(&mut self, _connection: &'ast Connection) {}
+
+    fn visit_file_format_options(&mut self, _options: &'ast FileFormatOptions) {}
+
     fn visit_call(&mut self, _call: &'ast CallStmt) {}
 
     fn visit_show_settings(&mut self, _show_options: &'ast Option<ShowOptions>) {}
@@ -542,6 +574,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_truncate_table(&mut self, _stmt: &'ast TruncateTableStmt) {}
 
+    fn visit_truncate_tables(&mut self, stmt: &'ast TruncateTablesStmt) {
+        for table in &stmt.tables {
+            self.visit_truncate_table(table);
+        }
+    }
+
     fn visit_optimize_table(&mut self, _stmt: &'ast OptimizeTableStmt) {}
 
     fn visit_vacuum_table(&mut self, _stmt: &'ast VacuumTableStmt) {}
@@ -728,12 +766,21 @@ pub trait Visitor<'ast>: Sized {
     }
 
     fn visit_set_operation(&mut self, op: &'ast SetOperation) {
-        let SetOperation { left, right, .. } = op;
+        let SetOperation {
+            span,
+            op,
+            all,
+            left,
+            right,
+        } = op;
 
+        self.visit_set_operator(*span, op, *all);
         walk_set_expr(self, left);
         walk_set_expr(self, right);
     }
 
+    fn visit_set_operator(&mut self, _span: Span, _op: &'ast SetOperator, _all: bool) {}
+
     fn visit_order_by(&mut self, order_by: &'ast OrderByExpr) {
         let OrderByExpr { expr, .. } = order_by;
         walk_expr(self, expr);
@@ -764,7 +811,9 @@ pub trait Visitor<'ast>: Sized {
         }
 
         match group_by {
-            Some(GroupBy::Normal(exprs)) => {
+            Some(GroupBy::Normal(exprs))
+            | Some(GroupBy::Cube(exprs))
+            | Some(GroupBy::Rollup(exprs)) => {
                 for expr in exprs {
                     walk_expr(self, expr);
                 }
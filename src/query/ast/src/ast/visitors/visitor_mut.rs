@@ -385,23 +385,42 @@ pub trait VisitorMut: Sized {
     fn visit_explain(
         &mut self,
         _kind: &mut ExplainKind,
-        _options: &mut [ExplainOption],
+        options: &mut [ExplainOption],
         stmt: &mut Statement,
     ) {
+        for option in options.iter_mut() {
+            self.visit_explain_option(option);
+        }
         walk_statement_mut(self, stmt);
     }
 
+    fn visit_explain_option(&mut self, _option: &mut ExplainOption) {}
+
     fn visit_copy_into_table(&mut self, copy: &mut CopyIntoTableStmt) {
-        if let CopyIntoTableSource::Query(query) = &mut copy.src {
-            self.visit_query(query)
+        match &mut copy.src {
+            CopyIntoTableSource::Location(location) => self.visit_file_location(location),
+            CopyIntoTableSource::Query(query) => self.visit_query(query),
         }
+        self.visit_file_format_options(&mut copy.file_format);
     }
     fn visit_copy_into_location(&mut self, copy: &mut CopyIntoLocationStmt) {
         if let CopyIntoLocationSource::Query(query) = &mut copy.src {
             self.visit_query(query)
         }
+        self.visit_file_location(&mut copy.dst);
+        self.visit_file_format_options(&mut copy.file_format);
     }
 
+    fn visit_file_location(&mut self, location: &mut FileLocation) {
+        if let FileLocation::Uri(uri) = location {
+            self.visit_connection(&mut uri.connection);
+        }
+    }
+
+    fn visit_connection(&mut self, _connection: &mut Connection) {}
+
+    fn visit_file_format_options(&mut self, _options: &mut FileFormatOptions) {}
+
     fn visit_call(&mut self, _call: &mut CallStmt) {}
 
     fn visit_show_settings(&mut self, _show_options: &mut Option<ShowOptions>) {}
@@ -554,6 +573,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_truncate_table(&mut self, _stmt: &mut TruncateTableStmt) {}
 
+    fn visit_truncate_tables(&mut self, stmt: &mut TruncateTablesStmt) {
+        for table in &mut stmt.tables {
+            self.visit_truncate_table(table);
+        }
+    }
+
     fn visit_optimize_table(&mut self, _stmt: &mut OptimizeTableStmt) {}
 
     fn visit_vacuum_table(&mut self, _stmt: &mut VacuumTableStmt) {}
@@ -742,12 +767,21 @@ pub trait VisitorMut: Sized {
     }
 
     fn visit_set_operation(&mut self, op: &mut SetOperation) {
-        let SetOperation { left, right, .. } = op;
+        let SetOperation {
+            span,
+            op,
+            all,
+            left,
+            right,
+        } = op;
 
+        self.visit_set_operator(*span, op, all);
         walk_set_expr_mut(self, left);
         walk_set_expr_mut(self, right);
     }
 
+    fn visit_set_operator(&mut self, _span: Span, _op: &mut SetOperator, _all: &mut bool) {}
+
     fn visit_order_by(&mut self, order_by: &mut OrderByExpr) {
         let OrderByExpr { expr, .. } = order_by;
         Self::visit_expr(self, expr);
@@ -778,7 +812,9 @@ pub trait VisitorMut: Sized {
         }
 
         match group_by {
-            Some(GroupBy::Normal(exprs)) => {
+            Some(GroupBy::Normal(exprs))
+            | Some(GroupBy::Cube(exprs))
+            | Some(GroupBy::Rollup(exprs)) => {
                 for expr in exprs {
                     Self::visit_expr(self, expr);
                 }
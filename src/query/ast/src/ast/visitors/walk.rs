@@ -410,7 +410,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::Update(update) => visitor.visit_update(update),
         Statement::CopyIntoTable(stmt) => visitor.visit_copy_into_table(stmt),
         Statement::CopyIntoLocation(stmt) => visitor.visit_copy_into_location(stmt),
-        Statement::ShowSettings { show_options } => visitor.visit_show_settings(show_options),
+        Statement::ShowSettings { show_options, .. } => visitor.visit_show_settings(show_options),
         Statement::ShowProcessList { show_options } => {
             visitor.visit_show_process_list(show_options)
         }
@@ -463,6 +463,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::AlterTable(stmt) => visitor.visit_alter_table(stmt),
         Statement::RenameTable(stmt) => visitor.visit_rename_table(stmt),
         Statement::TruncateTable(stmt) => visitor.visit_truncate_table(stmt),
+        Statement::TruncateTables(stmt) => visitor.visit_truncate_tables(stmt),
         Statement::OptimizeTable(stmt) => visitor.visit_optimize_table(stmt),
         Statement::VacuumTable(stmt) => visitor.visit_vacuum_table(stmt),
         Statement::VacuumDropTable(stmt) => visitor.visit_vacuum_drop_table(stmt),
@@ -353,12 +353,12 @@ pub fn row_values(i: Input) -> IResult<Vec<Expr>> {
 pub fn with(i: Input) -> IResult<With> {
     let cte = map(
         consumed(rule! {
-            #table_alias_without_as ~ AS ~ MATERIALIZED? ~ "(" ~ #query ~ ")"
+            #table_alias_without_as ~ AS ~ (NOT? ~ MATERIALIZED)? ~ "(" ~ #query ~ ")"
         }),
         |(span, (table_alias, _, materialized, _, query, _))| CTE {
             span: transform_span(span.tokens),
             alias: table_alias,
-            materialized: materialized.is_some(),
+            materialized: materialized.map(|(not, _)| not.is_none()),
             query: Box::new(query),
         },
     );
@@ -119,6 +119,8 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
                 | QUOTE
                 | NAN_DISPLAY
                 | NULL_DISPLAY
+                | OUTPUT_NULL_DISPLAY
+                | TIME_ZONE
                 | ESCAPE
                 | NULL_FIELD_AS
                 | MISSING_FIELD_AS
@@ -136,7 +138,9 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
 
     let bool_options = map(
         rule! {
-            (ERROR_ON_COLUMN_COUNT_MISMATCH | OUTPUT_HEADER) ~ ^"=" ~ ^#literal_bool
+            (ERROR_ON_COLUMN_COUNT_MISMATCH
+                | OUTPUT_HEADER
+                | SKIP_BLANK_LINES) ~ ^"=" ~ ^#literal_bool
         },
         |(k, _, v)| (k.text().to_string(), FileFormatValue::Bool(v)),
     );
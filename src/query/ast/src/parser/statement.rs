@@ -280,9 +280,17 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
 
     let show_settings = map(
         rule! {
-            SHOW ~ SETTINGS ~ #show_options?
+            SHOW ~ ( SESSION | GLOBAL | DEFAULT )? ~ SETTINGS ~ #show_options?
+        },
+        |(_, opt_scope, _, show_options)| Statement::ShowSettings {
+            scope: opt_scope.map(|scope| match scope.kind {
+                TokenKind::SESSION => ShowSettingsScope::Session,
+                TokenKind::GLOBAL => ShowSettingsScope::Global,
+                TokenKind::DEFAULT => ShowSettingsScope::Default,
+                _ => unreachable!(),
+            }),
+            show_options,
         },
-        |(_, _, show_options)| Statement::ShowSettings { show_options },
     );
     let show_stages = value(Statement::ShowStages, rule! { SHOW ~ STAGES });
     let show_process_list = map(
@@ -464,14 +472,32 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         rule! {
             CREATE
             ~ ( OR ~ ^REPLACE )?
+            ~ TRANSIENT?
             ~ ( DATABASE | SCHEMA )
             ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #database_ref
             ~ #create_database_option?
         },
-        |(_, opt_or_replace, _, opt_if_not_exists, database, create_database_option)| {
+        |(
+            _,
+            opt_or_replace,
+            opt_transient,
+            _,
+            opt_if_not_exists,
+            database,
+            create_database_option,
+        )| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            // Tables created in a transient database are transient by default.
+            let options = if opt_transient.is_some() {
+                vec![SQLProperty {
+                    name: "TRANSIENT".to_string(),
+                    value: "T".to_string(),
+                }]
+            } else {
+                vec![]
+            };
 
             let statement = match create_database_option {
                 Some(CreateDatabaseOption::DatabaseEngine(engine)) => {
@@ -479,7 +505,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                         create_option,
                         database,
                         engine: Some(engine),
-                        options: vec![],
+                        options,
                         from_share: None,
                     })
                 }
@@ -488,7 +514,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                         create_option,
                         database,
                         engine: None,
-                        options: vec![],
+                        options,
                         from_share: Some(share_name),
                     })
                 }
@@ -496,7 +522,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     create_option,
                     database,
                     engine: None,
-                    options: vec![],
+                    options,
                     from_share: None,
                 }),
             };
@@ -771,14 +797,25 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let truncate_table = map(
         rule! {
-            TRUNCATE ~ TABLE ~ #dot_separated_idents_1_to_3
+            TRUNCATE ~ TABLE ~ ( IF ~ ^EXISTS )? ~ ^#comma_separated_list1(dot_separated_idents_1_to_3)
         },
-        |(_, _, (catalog, database, table))| {
-            Statement::TruncateTable(TruncateTableStmt {
-                catalog,
-                database,
-                table,
-            })
+        |(_, _, opt_if_exists, tables)| {
+            let mut tables = tables
+                .into_iter()
+                .map(|(catalog, database, table)| TruncateTableStmt {
+                    catalog,
+                    database,
+                    table,
+                })
+                .collect::<Vec<_>>();
+            if opt_if_exists.is_none() && tables.len() == 1 {
+                Statement::TruncateTable(tables.remove(0))
+            } else {
+                Statement::TruncateTables(TruncateTablesStmt {
+                    if_exists: opt_if_exists.is_some(),
+                    tables,
+                })
+            }
         },
     );
     let optimize_table = map(
@@ -865,6 +902,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             CREATE ~ ( OR ~ ^REPLACE )? ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #dot_separated_idents_1_to_3
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ ( WITH ~ ^NO ~ ^SCHEMA ~ ^BINDING )?
             ~ AS ~ #query
         },
         |(
@@ -874,6 +912,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             opt_if_not_exists,
             (catalog, database, view),
             opt_columns,
+            opt_no_schema_binding,
             _,
             query,
         )| {
@@ -888,6 +927,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     .map(|(_, columns, _)| columns)
                     .unwrap_or_default(),
                 query: Box::new(query),
+                no_schema_binding: opt_no_schema_binding.is_some(),
             }))
         },
     );
@@ -2042,7 +2082,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #map(query, |query| Statement::Query(Box::new(query)))
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
             | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
-            | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
+            | #show_settings : "`SHOW [SESSION | GLOBAL | DEFAULT] SETTINGS [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
             | #show_engines : "`SHOW ENGINES`"
             | #show_process_list : "`SHOW PROCESSLIST`"
@@ -2107,7 +2147,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
             | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
             | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
-            | #truncate_table : "`TRUNCATE TABLE [<database>.]<table>`"
+            | #truncate_table : "`TRUNCATE TABLE [IF EXISTS] [<database>.]<table>, ...`"
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
             | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
             | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM [<catalog>.]<database>] [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
@@ -3043,12 +3083,13 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
     );
     let like = map(
         rule! {
-            LIKE ~ #dot_separated_idents_1_to_3
+            LIKE ~ #dot_separated_idents_1_to_3 ~ ( INCLUDING ~ ^OPTIONS )?
         },
-        |(_, (catalog, database, table))| CreateTableSource::Like {
+        |(_, (catalog, database, table), opt_including_options)| CreateTableSource::Like {
             catalog,
             database,
             table,
+            including_options: opt_including_options.is_some(),
         },
     );
 
@@ -3066,8 +3107,29 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
         |(_, _, new_db)| AlterDatabaseAction::RenameDatabase { new_db },
     );
 
+    let set_database_options = map(
+        rule! {
+            SET ~ OPTIONS ~ "(" ~ #set_table_option ~ ")"
+        },
+        |(_, _, _, set_options, _)| AlterDatabaseAction::SetOptions { set_options },
+    );
+
+    let unset_database_options = map(
+        rule! {
+            UNSET ~ OPTIONS ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, idents, _)| AlterDatabaseAction::UnsetOptions {
+            unset_options: idents
+                .into_iter()
+                .map(|ident| ident.name.to_lowercase())
+                .collect(),
+        },
+    );
+
     rule!(
         #rename_database
+        | #set_database_options
+        | #unset_database_options
     )(i)
 }
 
@@ -3254,9 +3316,12 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
 
     let revert_table = map(
         rule! {
-            FLASHBACK ~ TO ~ #travel_point
+            FLASHBACK ~ TO ~ #travel_point ~ ( DRY ~ ^RUN )?
+        },
+        |(_, _, point, opt_dry_run)| AlterTableAction::FlashbackTo {
+            point,
+            dry_run: opt_dry_run.is_some(),
         },
-        |(_, _, point)| AlterTableAction::FlashbackTo { point },
     );
 
     let set_table_options = map(
@@ -3968,9 +4033,10 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
             ~ RETURNS ~ #udf_arg_type
             ~ LANGUAGE ~ #ident
             ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ ( ON_ERROR ~ ^"=" ~ ^#literal_string )?
             ~ AS ~ ^#code_string
         },
-        |(_, arg_types, _, _, return_type, _, language, _, _, handler, _, code)| {
+        |(_, arg_types, _, _, return_type, _, language, _, _, handler, on_error, _, code)| {
             UDFDefinition::UDFScript {
                 arg_types,
                 return_type,
@@ -3980,6 +4046,7 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
                 // TODO inject runtime_version by user
                 // Now we use fixed runtime version
                 runtime_version: "".to_string(),
+                on_error: on_error.map(|(_, _, on_error)| on_error),
             }
         },
     );
@@ -3987,7 +4054,7 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
     rule!(
         #udf_server: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>"
         | #lambda_udf: "AS (<parameter>, ...) -> <definition expr>"
-        | #udf_script: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> AS <language_codes>"
+        | #udf_script: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> [ON_ERROR=<on_error>] AS <language_codes>"
     )(i)
 }
 
@@ -4115,7 +4182,7 @@ pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
 }
 
 pub fn explain_option(i: Input) -> IResult<ExplainOption> {
-    map(
+    let flag = map(
         rule! {
             VERBOSE | LOGICAL | OPTIMIZED
         },
@@ -4125,6 +4192,17 @@ pub fn explain_option(i: Input) -> IResult<ExplainOption> {
             OPTIMIZED => ExplainOption::Optimized,
             _ => unreachable!(),
         },
+    );
+    let verbosity = map(
+        rule! {
+            VERBOSITY ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, level)| ExplainOption::Verbosity(level),
+    );
+
+    rule!(
+        #flag
+        | #verbosity
     )(i)
 }
 
@@ -36,7 +36,7 @@ pub fn stream_table(i: Input) -> IResult<Statement> {
     rule!(
          #create_stream: "`CREATE [OR REPLACE] STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [<travel_point>] [COMMENT = '<string_literal>']`"
          | #drop_stream: "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
-         | #show_streams: "`SHOW [FULL] STREAMS [FROM <database>] [<show_limit>]`"
+         | #show_streams: "`SHOW [FULL] STREAMS [FROM <database>] [ON TABLE [<database>.]<table>] [<show_limit>]`"
          | #describe_stream: "`DESCRIBE STREAM [<database>.]<stream>`"
     )(i)
 }
@@ -102,17 +102,24 @@ fn drop_stream(i: Input) -> IResult<Statement> {
 fn show_streams(i: Input) -> IResult<Statement> {
     map(
         rule! {
-            SHOW ~ FULL? ~ STREAMS ~ ( ( FROM | IN ) ~ #dot_separated_idents_1_to_2 )? ~ #show_limit?
+            SHOW ~ FULL? ~ STREAMS ~ ( ( FROM | IN ) ~ #dot_separated_idents_1_to_2 )?
+            ~ ( ON ~ TABLE ~ #dot_separated_idents_1_to_2 )? ~ #show_limit?
         },
-        |(_, opt_full, _, ctl_db, limit)| {
+        |(_, opt_full, _, ctl_db, opt_table, limit)| {
             let (catalog, database) = match ctl_db {
                 Some((_, (Some(c), d))) => (Some(c), Some(d)),
                 Some((_, (None, d))) => (None, Some(d)),
                 _ => (None, None),
             };
+            let (table_database, table) = match opt_table {
+                Some((_, _, (table_database, table))) => (table_database, Some(table)),
+                None => (None, None),
+            };
             Statement::ShowStreams(ShowStreamsStmt {
                 catalog,
                 database,
+                table_database,
+                table,
                 full: opt_full.is_some(),
                 limit,
             })
@@ -389,6 +389,8 @@ pub enum TokenKind {
     BLOB,
     #[token("BINARY_FORMAT", ignore(ascii_case))]
     BINARY_FORMAT,
+    #[token("BINDING", ignore(ascii_case))]
+    BINDING,
     #[token("BITMAP", ignore(ascii_case))]
     BITMAP,
     #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
@@ -657,6 +659,8 @@ pub enum TokenKind {
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INCLUDING", ignore(ascii_case))]
+    INCLUDING,
     #[token("INCREMENTAL", ignore(ascii_case))]
     INCREMENTAL,
     #[token("INDEX", ignore(ascii_case))]
@@ -776,6 +780,8 @@ pub enum TokenKind {
     DISABLED,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NO", ignore(ascii_case))]
+    NO,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
@@ -812,6 +818,8 @@ pub enum TokenKind {
     ORDER,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
     OUTPUT_HEADER,
+    #[token("OUTPUT_NULL_DISPLAY", ignore(ascii_case))]
+    OUTPUT_NULL_DISPLAY,
     #[token("OUTER", ignore(ascii_case))]
     OUTER,
     #[token("ON_ERROR", ignore(ascii_case))]
@@ -994,6 +1002,8 @@ pub enum TokenKind {
     SIZE_LIMIT,
     #[token("MAX_FILES", ignore(ascii_case))]
     MAX_FILES,
+    #[token("SKIP_BLANK_LINES", ignore(ascii_case))]
+    SKIP_BLANK_LINES,
     #[token("SKIP_HEADER", ignore(ascii_case))]
     SKIP_HEADER,
     #[token("SMALLINT", ignore(ascii_case))]
@@ -1016,6 +1026,8 @@ pub enum TokenKind {
     UPLOAD,
     #[token("SEQUENCE", ignore(ascii_case))]
     SEQUENCE,
+    #[token("SESSION", ignore(ascii_case))]
+    SESSION,
     #[token("SHARE", ignore(ascii_case))]
     SHARE,
     #[token("SHARES", ignore(ascii_case))]
@@ -1074,6 +1086,8 @@ pub enum TokenKind {
     TIMEZONE_MINUTE,
     #[token("TIMEZONE", ignore(ascii_case))]
     TIMEZONE,
+    #[token("TIME_ZONE", ignore(ascii_case))]
+    TIME_ZONE,
     #[token("TINYINT", ignore(ascii_case))]
     TINYINT,
     #[token("TO", ignore(ascii_case))]
@@ -1142,6 +1156,8 @@ pub enum TokenKind {
     VARIANT,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
+    #[token("VERBOSITY", ignore(ascii_case))]
+    VERBOSITY,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("VIEWS", ignore(ascii_case))]
@@ -18,3 +18,4 @@ mod decimal;
 mod display;
 mod parser;
 mod token;
+mod visitor;
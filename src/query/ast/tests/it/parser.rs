@@ -131,6 +131,7 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t like t2 including options;"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"
             create table if not exists a.b (a int) 's3://testbucket/admin/data/'
@@ -149,6 +150,7 @@ fn test_statement() {
         r#"create database t FROM SHARE a.s;"#,
         r#"CREATE TABLE `t3`(a int not null, b int not null, c int not null) bloom_index_columns='a,b,c' COMPRESSION='zstd' STORAGE_FORMAT='native';"#,
         r#"create or replace database a;"#,
+        r#"create transient database a;"#,
         r#"drop database ctl.t;"#,
         r#"drop database if exists t;"#,
         r#"create table c(a DateTime null, b DateTime(3));"#,
@@ -169,11 +171,13 @@ fn test_statement() {
         r#"create stream if not exists test2.s3 on table test.t at (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') append_only = true;"#,
         r#"create or replace stream test2.s1 on table test.t append_only = false;"#,
         r#"show full streams from default.test2 like 's%';"#,
+        r#"show streams on table db1.t1;"#,
         r#"describe stream test2.s2;"#,
         r#"drop stream if exists test2.s2;"#,
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
+        r#"truncate table if exists a, b;"#,
         r#"DROP table table1;"#,
         r#"DROP table IF EXISTS table1;"#,
         r#"CREATE TABLE t(c1 int null, c2 bigint null, c3 varchar null);"#,
@@ -275,6 +279,8 @@ fn test_statement() {
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
+        r#"ALTER DATABASE c SET OPTIONS (disable_time_travel = true);"#,
+        r#"ALTER DATABASE IF EXISTS c UNSET OPTIONS (disable_time_travel);"#,
         r#"VACUUM TABLE t;"#,
         r#"VACUUM TABLE t DRY RUN;"#,
         r#"VACUUM TABLE t DRY RUN SUMMARY;"#,
@@ -516,6 +522,7 @@ fn test_statement() {
         r#"CALL system$test(a)"#,
         r#"CALL system$test('a')"#,
         r#"show settings like 'enable%' limit 1"#,
+        r#"show global settings like 'enable%'"#,
         r#"show settings where name='max_memory_usage' limit 1"#,
         r#"show functions like 'today%' limit 1"#,
         r#"show functions where name='to_day_of_year' limit 1"#,
@@ -934,6 +941,7 @@ fn test_query() {
         r#"select * from customer natural join orders left outer join detail using (id)"#,
         r#"with t2(tt) as (select a from t) select t2.tt from t2  where t2.tt > 1"#,
         r#"with t2(tt) as materialized (select a from t) select t2.tt from t2  where t2.tt > 1"#,
+        r#"with t2(tt) as not materialized (select a from t) select t2.tt from t2  where t2.tt > 1"#,
         r#"with t2 as (select a from t) select t2.a from t2  where t2.a > 1"#,
         r#"with t2(tt) as materialized (select a from t), t3 as materialized (select * from t), t4 as (select a from t where a > 1) select t2.tt, t3.a, t4.a from t2, t3, t4 where t2.tt > 1"#,
         r#"with recursive t2(tt) as (select a from t1 union select tt from t2) select t2.tt from t2"#,
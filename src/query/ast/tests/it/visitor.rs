@@ -0,0 +1,361 @@
+// Copyright 2024 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(deprecated)]
+
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::Connection;
+use databend_common_ast::ast::ExplainOption;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FileFormatOptions;
+use databend_common_ast::ast::FileFormatValue;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::LiteralReplacer;
+use databend_common_ast::ast::SetOperator;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::Visitor;
+use databend_common_ast::ast::VisitorMut;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::Span;
+use derive_visitor::DriveMut;
+
+#[derive(Default)]
+struct OptionsCounter {
+    file_format_options: usize,
+    connection_options: usize,
+}
+
+impl<'ast> Visitor<'ast> for OptionsCounter {
+    fn visit_file_format_options(&mut self, options: &'ast FileFormatOptions) {
+        self.file_format_options += options.options.len();
+    }
+
+    fn visit_connection(&mut self, connection: &'ast Connection) {
+        self.connection_options += connection.conns.len();
+    }
+}
+
+struct FileFormatRewriter;
+
+impl VisitorMut for FileFormatRewriter {
+    fn visit_file_format_options(&mut self, options: &mut FileFormatOptions) {
+        options.options.insert(
+            "compression".to_string(),
+            FileFormatValue::Keyword("GZIP".to_string()),
+        );
+    }
+}
+
+fn parse(sql: &str) -> Statement {
+    let tokens = tokenize_sql(sql).unwrap();
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL).unwrap();
+    stmt
+}
+
+#[test]
+fn test_visit_copy_into_table_options() {
+    let stmt = parse(
+        "COPY INTO t FROM 's3://bucket/path/' \
+         CONNECTION = (endpoint_url = 'http://127.0.0.1:9900', region = 'us-east-2') \
+         FILE_FORMAT = (type = CSV, field_delimiter = ',', skip_header = 1)",
+    );
+    let mut visitor = OptionsCounter::default();
+    visitor.visit_statement(&stmt);
+    assert_eq!(visitor.file_format_options, 3);
+    assert_eq!(visitor.connection_options, 2);
+}
+
+#[test]
+fn test_visit_copy_into_location_options() {
+    let stmt = parse(
+        "COPY INTO 's3://bucket/path/' \
+         CONNECTION = (endpoint_url = 'http://127.0.0.1:9900') \
+         FROM t FILE_FORMAT = (type = PARQUET)",
+    );
+    let mut visitor = OptionsCounter::default();
+    visitor.visit_statement(&stmt);
+    assert_eq!(visitor.file_format_options, 1);
+    assert_eq!(visitor.connection_options, 1);
+}
+
+#[test]
+fn test_rewrite_copy_file_format_options() {
+    let mut stmt = parse("COPY INTO t FROM @stage FILE_FORMAT = (type = CSV)");
+    FileFormatRewriter.visit_statement(&mut stmt);
+    match &stmt {
+        Statement::CopyIntoTable(copy) => {
+            assert_eq!(
+                copy.file_format.options.get("compression"),
+                Some(&FileFormatValue::Keyword("GZIP".to_string()))
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let mut visitor = OptionsCounter::default();
+    visitor.visit_statement(&stmt);
+    assert_eq!(visitor.file_format_options, 2);
+    assert_eq!(visitor.connection_options, 0);
+}
+
+#[derive(Default)]
+struct AggregateCounter {
+    aggregates: Vec<(String, usize)>,
+}
+
+impl<'ast> Visitor<'ast> for AggregateCounter {
+    fn visit_aggregate_function(&mut self, _span: Span, name: &str, args: &'ast [Expr]) {
+        self.aggregates.push((name.to_lowercase(), args.len()));
+    }
+}
+
+#[test]
+fn test_visit_aggregate_function() {
+    let stmt = parse(
+        "SELECT COUNT(a), SUM(a + 1), upper(b), count(DISTINCT c) FROM t \
+         GROUP BY b HAVING sum(a) > 1",
+    );
+    let mut visitor = AggregateCounter::default();
+    visitor.visit_statement(&stmt);
+    assert_eq!(visitor.aggregates, vec![
+        ("count".to_string(), 1),
+        ("sum".to_string(), 1),
+        ("count".to_string(), 1),
+        ("sum".to_string(), 1),
+    ]);
+}
+
+#[test]
+fn test_literal_replacer() {
+    let mut stmt =
+        parse("SELECT a + 1, 'abc', 1.5 FROM t WHERE b > 10 AND c IN (2, 3) ORDER BY a LIMIT 5");
+    let mut replaced = 0;
+    let mut replacer = LiteralReplacer::new(|_, lit| match lit {
+        Literal::UInt64(_) => {
+            replaced += 1;
+            Some(Literal::UInt64(0))
+        }
+        _ => None,
+    });
+    replacer.visit_statement(&mut stmt);
+    assert_eq!(replaced, 5);
+
+    let expected =
+        parse("SELECT a + 0, 'abc', 1.5 FROM t WHERE b > 0 AND c IN (0, 0) ORDER BY a LIMIT 0");
+    assert_eq!(stmt.to_string(), expected.to_string());
+}
+
+struct ColumnRenamer {
+    from: &'static str,
+    to: &'static str,
+}
+
+impl VisitorMut for ColumnRenamer {
+    fn visit_column_ref(
+        &mut self,
+        _span: Span,
+        _database: &mut Option<Identifier>,
+        _table: &mut Option<Identifier>,
+        column: &mut ColumnID,
+    ) {
+        if let ColumnID::Name(ident) = column {
+            if ident.name == self.from {
+                ident.name = self.to.to_string();
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rewrite_query_level_clauses() {
+    let cases = [
+        (
+            "SELECT a FROM t ORDER BY a LIMIT 1 OFFSET 2",
+            "SELECT b FROM t ORDER BY b LIMIT 1 OFFSET 2",
+        ),
+        (
+            "SELECT a, row_number() OVER (PARTITION BY a ORDER BY c) AS r FROM t QUALIFY r = a",
+            "SELECT b, row_number() OVER (PARTITION BY b ORDER BY c) AS r FROM t QUALIFY r = b",
+        ),
+        (
+            "SELECT a, c FROM t GROUP BY CUBE (a, c)",
+            "SELECT b, c FROM t GROUP BY CUBE (b, c)",
+        ),
+        (
+            "SELECT a, c FROM t GROUP BY ROLLUP (a, c)",
+            "SELECT b, c FROM t GROUP BY ROLLUP (b, c)",
+        ),
+        (
+            "SELECT a, c FROM t GROUP BY GROUPING SETS ((a), (c))",
+            "SELECT b, c FROM t GROUP BY GROUPING SETS ((b), (c))",
+        ),
+    ];
+
+    for (sql, expected) in cases {
+        let mut stmt = parse(sql);
+        let mut renamer = ColumnRenamer { from: "a", to: "b" };
+        renamer.visit_statement(&mut stmt);
+        assert_eq!(stmt.to_string(), parse(expected).to_string(), "{sql}");
+    }
+}
+
+#[derive(Default)]
+struct UnionAllRewriter {
+    unions: usize,
+}
+
+impl VisitorMut for UnionAllRewriter {
+    fn visit_set_operator(&mut self, _span: Span, op: &mut SetOperator, all: &mut bool) {
+        if *op == SetOperator::Union {
+            self.unions += 1;
+            *all = true;
+        }
+    }
+}
+
+#[test]
+fn test_rewrite_union_to_union_all() {
+    let mut stmt = parse(
+        "SELECT a FROM t1 UNION SELECT a FROM t2 UNION ALL SELECT a FROM t3 \
+         EXCEPT SELECT a FROM t4 \
+         UNION SELECT a FROM (SELECT a FROM t5 UNION SELECT a FROM t6)",
+    );
+    let mut rewriter = UnionAllRewriter::default();
+    rewriter.visit_statement(&mut stmt);
+    assert_eq!(rewriter.unions, 4);
+
+    let expected = parse(
+        "SELECT a FROM t1 UNION ALL SELECT a FROM t2 UNION ALL SELECT a FROM t3 \
+         EXCEPT SELECT a FROM t4 \
+         UNION ALL SELECT a FROM (SELECT a FROM t5 UNION ALL SELECT a FROM t6)",
+    );
+    assert_eq!(stmt.to_string(), expected.to_string());
+}
+
+#[derive(Default)]
+struct ColumnCounter {
+    columns: Vec<String>,
+    options: usize,
+}
+
+impl<'ast> Visitor<'ast> for ColumnCounter {
+    fn visit_column_ref(
+        &mut self,
+        _span: Span,
+        _database: &'ast Option<Identifier>,
+        _table: &'ast Option<Identifier>,
+        column: &'ast ColumnID,
+    ) {
+        self.columns.push(column.name().to_string());
+    }
+
+    fn visit_explain_option(&mut self, _option: &'ast ExplainOption) {
+        self.options += 1;
+    }
+}
+
+#[test]
+fn test_visit_explain() {
+    let mut stmt = parse("EXPLAIN SELECT a FROM t");
+    let mut renamer = ColumnRenamer { from: "a", to: "b" };
+    renamer.visit_statement(&mut stmt);
+    assert_eq!(
+        stmt.to_string(),
+        parse("EXPLAIN SELECT b FROM t").to_string()
+    );
+
+    let stmt = parse("EXPLAIN(VERBOSE, LOGICAL) SELECT a, c FROM t WHERE d > 0");
+    let mut counter = ColumnCounter::default();
+    counter.visit_statement(&stmt);
+    assert_eq!(counter.options, 2);
+    assert_eq!(counter.columns, vec!["a", "c", "d"]);
+}
+
+/// Records the outermost exprs reached by the deprecated manual walker.
+#[derive(Default)]
+struct ManualExprCollector {
+    exprs: Vec<String>,
+}
+
+impl<'ast> Visitor<'ast> for ManualExprCollector {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        self.exprs.push(expr.to_string());
+    }
+}
+
+/// Records the outermost exprs reached through `DriveMut`.
+#[derive(Default, derive_visitor::VisitorMut)]
+#[visitor(Expr(enter, exit))]
+struct DriveExprCollector {
+    depth: usize,
+    exprs: Vec<String>,
+}
+
+impl DriveExprCollector {
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if self.depth == 0 {
+            self.exprs.push(expr.to_string());
+        }
+        self.depth += 1;
+    }
+
+    fn exit_expr(&mut self, _expr: &mut Expr) {
+        self.depth -= 1;
+    }
+}
+
+#[test]
+fn test_drive_merge_into_exprs() {
+    let sql = "MERGE INTO t USING s ON t.id = s.id \
+        WHEN MATCHED AND s.flag = 1 THEN UPDATE SET t.a = s.a + 1, t.b = s.b \
+        WHEN MATCHED AND s.flag = 2 THEN DELETE \
+        WHEN NOT MATCHED AND s.c > 0 THEN INSERT (id, a) VALUES (s.id, s.a * 2)";
+    let mut stmt = parse(sql);
+
+    let mut manual = ManualExprCollector::default();
+    manual.visit_statement(&stmt);
+
+    let mut derived = DriveExprCollector::default();
+    stmt.drive_mut(&mut derived);
+
+    assert_eq!(manual.exprs, vec![
+        "t.id = s.id",
+        "s.flag = 1",
+        "s.a + 1",
+        "s.b",
+        "s.flag = 2",
+        "s.c > 0",
+        "s.id",
+        "s.a * 2",
+    ]);
+    assert_eq!(derived.exprs, manual.exprs);
+}
+
+#[test]
+fn test_visit_aggregate_function_combinators() {
+    let stmt = parse(
+        "SELECT sum_if(a, a > 1), COUNT_DISTINCT(b), sum_state(a), count(*), \
+         concat_ws(',', b) FROM t",
+    );
+    let mut visitor = AggregateCounter::default();
+    visitor.visit_statement(&stmt);
+    assert_eq!(visitor.aggregates, vec![
+        ("sum_if".to_string(), 2),
+        ("count_distinct".to_string(), 1),
+        ("sum_state".to_string(), 1),
+        ("count".to_string(), 0),
+    ]);
+}
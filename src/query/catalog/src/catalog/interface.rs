@@ -69,10 +69,13 @@ use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
+use databend_common_meta_app::schema::RenameSequenceReply;
+use databend_common_meta_app::schema::RenameSequenceReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -89,6 +92,8 @@ use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReply;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReq;
 use databend_common_meta_app::schema::UpsertTableOptionReply;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_app::schema::VirtualColumnMeta;
@@ -96,6 +101,10 @@ use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::MetaId;
 use databend_common_meta_types::SeqV;
 use dyn_clone::DynClone;
+use futures::stream;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use futures::TryStreamExt;
 
 use crate::database::Database;
 use crate::table::Table;
@@ -107,6 +116,8 @@ pub struct StorageDescription {
     pub engine_name: String,
     pub comment: String,
     pub support_cluster_key: bool,
+    /// Table option keys that can be used in `CREATE TABLE` of this engine.
+    pub supported_options: Vec<String>,
 }
 
 pub trait CatalogCreator: Send + Sync + Debug {
@@ -190,6 +201,16 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
 
     async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply>;
 
+    /// Add, update or remove the options of a database.
+    async fn upsert_database_option(
+        &self,
+        _req: UpsertDatabaseOptionReq,
+    ) -> Result<UpsertDatabaseOptionReply> {
+        Err(ErrorCode::Unimplemented(
+            "'upsert_database_option' not implemented",
+        ))
+    }
+
     /// Table.
 
     // Build a `Arc<dyn Table>` from `TableInfo`.
@@ -198,6 +219,27 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
     /// Get the table meta by table id.
     async fn get_table_meta_by_id(&self, table_id: MetaId) -> Result<Option<SeqV<TableMeta>>>;
 
+    /// Get the table metas by table ids, in the same order as `table_ids`.
+    ///
+    /// The default implementation fetches them one by one, a catalog that is able to batch
+    /// the lookup should override it. A missing table id yields None.
+    async fn mget_table_metas_by_ids(
+        &self,
+        table_ids: &[MetaId],
+    ) -> Result<Vec<Option<(TableIdent, Arc<TableMeta>)>>> {
+        let mut table_metas = Vec::with_capacity(table_ids.len());
+        for table_id in table_ids {
+            let table_meta = self.get_table_meta_by_id(*table_id).await?;
+            table_metas.push(table_meta.map(|seq_meta| {
+                (
+                    TableIdent::new(*table_id, seq_meta.seq),
+                    Arc::new(seq_meta.data),
+                )
+            }));
+        }
+        Ok(table_metas)
+    }
+
     // List the tables name by meta ids.
     async fn mget_table_names_by_ids(
         &self,
@@ -224,12 +266,77 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
     ) -> Result<Arc<dyn Table>>;
 
     async fn list_tables(&self, tenant: &Tenant, db_name: &str) -> Result<Vec<Arc<dyn Table>>>;
+
+    /// List the tables of a database as a stream, so that they can be processed one by one.
+    ///
+    /// The default implementation yields the tables of [`Catalog::list_tables`], catalogs
+    /// that can fetch tables incrementally should override it.
+    fn list_tables_stream<'a>(
+        &'a self,
+        tenant: &'a Tenant,
+        db_name: &'a str,
+    ) -> BoxStream<'a, Result<Arc<dyn Table>>> {
+        stream::once(self.list_tables(tenant, db_name))
+            .map_ok(|tables| stream::iter(tables.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed()
+    }
+
+    /// List all the tables of a database, including the dropped ones.
     async fn list_tables_history(
         &self,
         tenant: &Tenant,
         db_name: &str,
     ) -> Result<Vec<Arc<dyn Table>>>;
 
+    /// List a page of the tables of a database, including the dropped ones, ordered by name.
+    ///
+    /// At most `limit` tables whose name is greater than `after` are returned, along with the
+    /// token to get the next page, which is the name of the last returned table, or None if
+    /// there are no more tables. Tables sharing a name (dropped and created again) are never
+    /// split across pages, so a page may hold a few more than `limit` tables.
+    ///
+    /// The default implementation lists the whole history with
+    /// [`Catalog::list_tables_history`] and takes the page from it in memory, it bounds the
+    /// size of the result, not the cost of listing.
+    async fn list_tables_history_paged(
+        &self,
+        tenant: &Tenant,
+        db_name: &str,
+        limit: Option<usize>,
+        after: Option<String>,
+    ) -> Result<(Vec<Arc<dyn Table>>, Option<String>)> {
+        if limit == Some(0) {
+            return Err(ErrorCode::BadArguments(
+                "The limit of a page of tables must be greater than 0",
+            ));
+        }
+
+        let mut tables = self.list_tables_history(tenant, db_name).await?;
+        tables.sort_by(|a, b| (a.name(), a.get_id()).cmp(&(b.name(), b.get_id())));
+        if let Some(after) = &after {
+            tables.retain(|t| t.name() > after.as_str());
+        }
+
+        let limit = match limit {
+            Some(limit) if limit < tables.len() => limit,
+            _ => return Ok((tables, None)),
+        };
+        let last_name = tables[limit - 1].name().to_string();
+        let same_name = tables[limit..]
+            .iter()
+            .take_while(|t| t.name() == last_name)
+            .count();
+        let end = limit + same_name;
+        let next = if end < tables.len() {
+            Some(last_name)
+        } else {
+            None
+        };
+        tables.truncate(end);
+        Ok((tables, next))
+    }
+
     async fn get_drop_table_infos(
         &self,
         _req: ListDroppedTableReq,
@@ -245,6 +352,39 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
 
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply>;
 
+    /// Create a table and return the [`TableInfo`] of it, resolved from the meta store.
+    ///
+    /// Callers that need to access the newly created table should use this instead of
+    /// building the `TableInfo` from the request and the reply by themselves.
+    #[async_backtrace::framed]
+    async fn create_table_with_info(
+        &self,
+        req: CreateTableReq,
+    ) -> Result<(CreateTableReply, TableInfo)> {
+        let name_ident = req.name_ident.clone();
+        let reply = self.create_table(req).await?;
+
+        let seq_meta = self
+            .get_table_meta_by_id(reply.table_id)
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::UnknownTableId(format!(
+                    "table {} (id {}) not found after creation",
+                    name_ident, reply.table_id
+                ))
+            })?;
+
+        let mut table_info = TableInfo::new(
+            &name_ident.db_name,
+            &name_ident.table_name,
+            TableIdent::new(reply.table_id, seq_meta.seq),
+            seq_meta.data,
+        );
+        table_info.tenant = name_ident.tenant.tenant_name().to_string();
+
+        Ok((reply, table_info))
+    }
+
     async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply>;
 
     async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply>;
@@ -369,4 +509,10 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
     ) -> Result<GetSequenceNextValueReply>;
 
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply>;
+
+    async fn rename_sequence(&self, _req: RenameSequenceReq) -> Result<RenameSequenceReply> {
+        Err(ErrorCode::Unimplemented(
+            "'rename_sequence' not implemented",
+        ))
+    }
 }
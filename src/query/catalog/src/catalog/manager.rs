@@ -127,6 +127,9 @@ impl CatalogManager {
     ///
     /// There are some place that we don't have async context, so we provide
     /// `get_default_catalog` to allow users fetch default catalog without async.
+    ///
+    /// The default catalog is built in, so resolving it never reads from metasrv.
+    /// Prefer this over `get_catalog(CATALOG_DEFAULT)` when the catalog is known to be default.
     pub fn get_default_catalog(&self, txn_mgr: TxnManagerRef) -> Result<Arc<dyn Catalog>> {
         Ok(Arc::new(SessionCatalog::create(
             self.default_catalog.clone(),
@@ -142,7 +145,7 @@ impl CatalogManager {
     ) -> Result<Arc<dyn Catalog>> {
         let typ = info.meta.catalog_option.catalog_type();
 
-        if typ == CatalogType::Default {
+        if !typ.is_external() {
             return self.get_default_catalog(txn_mgr);
         }
 
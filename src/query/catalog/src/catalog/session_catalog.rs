@@ -68,10 +68,13 @@ use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
+use databend_common_meta_app::schema::RenameSequenceReply;
+use databend_common_meta_app::schema::RenameSequenceReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -88,6 +91,8 @@ use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReply;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReq;
 use databend_common_meta_app::schema::UpsertTableOptionReply;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_app::schema::VirtualColumnMeta;
@@ -96,6 +101,7 @@ use databend_common_meta_types::MetaId;
 use databend_common_meta_types::SeqV;
 use databend_storages_common_txn::TxnManagerRef;
 use databend_storages_common_txn::TxnState;
+use futures::stream::BoxStream;
 
 use crate::catalog::Catalog;
 use crate::catalog::StorageDescription;
@@ -217,6 +223,13 @@ impl Catalog for SessionCatalog {
         self.inner.rename_database(req).await
     }
 
+    async fn upsert_database_option(
+        &self,
+        req: UpsertDatabaseOptionReq,
+    ) -> Result<UpsertDatabaseOptionReply> {
+        self.inner.upsert_database_option(req).await
+    }
+
     /// Table.
 
     // Build a `Arc<dyn Table>` from `TableInfo`.
@@ -240,6 +253,24 @@ impl Catalog for SessionCatalog {
         }
     }
 
+    // Get the table metas by meta ids.
+    async fn mget_table_metas_by_ids(
+        &self,
+        table_ids: &[MetaId],
+    ) -> Result<Vec<Option<(TableIdent, Arc<TableMeta>)>>> {
+        let mut table_metas = self.inner.mget_table_metas_by_ids(table_ids).await?;
+        let state = self.txn_mgr.lock().state();
+        if let TxnState::Active = state {
+            // Tables mutated in the transaction take precedence over the committed ones.
+            for (table_id, table_meta) in table_ids.iter().zip(table_metas.iter_mut()) {
+                if let Some(t) = self.txn_mgr.lock().get_table_from_buffer_by_id(*table_id) {
+                    *table_meta = Some((t.ident, Arc::new(t.meta)));
+                }
+            }
+        }
+        Ok(table_metas)
+    }
+
     // Mget the dbs name by meta ids.
     async fn mget_table_names_by_ids(
         &self,
@@ -297,6 +328,15 @@ impl Catalog for SessionCatalog {
     async fn list_tables(&self, tenant: &Tenant, db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
         self.inner.list_tables(tenant, db_name).await
     }
+
+    fn list_tables_stream<'a>(
+        &'a self,
+        tenant: &'a Tenant,
+        db_name: &'a str,
+    ) -> BoxStream<'a, Result<Arc<dyn Table>>> {
+        self.inner.list_tables_stream(tenant, db_name)
+    }
+
     async fn list_tables_history(
         &self,
         tenant: &Tenant,
@@ -305,6 +345,18 @@ impl Catalog for SessionCatalog {
         self.inner.list_tables_history(tenant, db_name).await
     }
 
+    async fn list_tables_history_paged(
+        &self,
+        tenant: &Tenant,
+        db_name: &str,
+        limit: Option<usize>,
+        after: Option<String>,
+    ) -> Result<(Vec<Arc<dyn Table>>, Option<String>)> {
+        self.inner
+            .list_tables_history_paged(tenant, db_name, limit, after)
+            .await
+    }
+
     async fn get_drop_table_infos(
         &self,
         req: ListDroppedTableReq,
@@ -495,4 +547,8 @@ impl Catalog for SessionCatalog {
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
         self.inner.drop_sequence(req).await
     }
+
+    async fn rename_sequence(&self, req: RenameSequenceReq) -> Result<RenameSequenceReply> {
+        self.inner.rename_sequence(req).await
+    }
 }
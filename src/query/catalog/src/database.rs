@@ -42,6 +42,8 @@ use databend_common_meta_app::KeyWithTenant;
 use dyn_clone::DynClone;
 
 use crate::table::Table;
+use crate::table::TableStatistics;
+use crate::table_context::TableContext;
 
 #[async_trait::async_trait]
 pub trait Database: DynClone + Sync + Send {
@@ -109,6 +111,18 @@ pub trait Database: DynClone + Sync + Send {
         )))
     }
 
+    // Aggregate the statistics of all the tables in the database.
+    #[async_backtrace::framed]
+    async fn summary(&self, ctx: Arc<dyn TableContext>) -> Result<TableStatistics> {
+        let mut summary = TableStatistics::default();
+        for table in self.list_tables().await? {
+            if let Some(stats) = table.table_statistics(ctx.clone(), None).await? {
+                summary.merge(&stats);
+            }
+        }
+        Ok(summary)
+    }
+
     #[async_backtrace::framed]
     async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
         Err(ErrorCode::Unimplemented(format!(
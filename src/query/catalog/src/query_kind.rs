@@ -30,6 +30,16 @@ pub enum QueryKind {
     Other,
 }
 
+impl QueryKind {
+    /// Returns true if the statement doesn't write anything, so that it can be
+    /// served by a read-only replica.
+    ///
+    /// `CopyIntoLocation` writes files to a stage, so it is not read-only.
+    pub fn is_read_only(&self) -> bool {
+        matches!(self, QueryKind::Query | QueryKind::Explain)
+    }
+}
+
 impl Display for QueryKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
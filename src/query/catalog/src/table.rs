@@ -471,6 +471,24 @@ pub struct TableStatistics {
     pub number_of_segments: Option<u64>,
 }
 
+impl TableStatistics {
+    /// Accumulate `other` into `self`, a field stays `None` only if neither side has it.
+    pub fn merge(&mut self, other: &TableStatistics) {
+        fn add(acc: &mut Option<u64>, v: Option<u64>) {
+            if let Some(v) = v {
+                *acc = Some(acc.unwrap_or_default() + v);
+            }
+        }
+
+        add(&mut self.num_rows, other.num_rows);
+        add(&mut self.data_size, other.data_size);
+        add(&mut self.data_size_compressed, other.data_size_compressed);
+        add(&mut self.index_size, other.index_size);
+        add(&mut self.number_of_blocks, other.number_of_blocks);
+        add(&mut self.number_of_segments, other.number_of_segments);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ColumnStatistics {
     pub min: Scalar,
@@ -21,6 +21,8 @@ use std::sync::Arc;
 use std::time::Duration;
 use std::time::SystemTime;
 
+use chrono::DateTime;
+use chrono::Utc;
 use dashmap::DashMap;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
@@ -32,9 +34,11 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_io::prelude::FormatSettings;
+use databend_common_meta_app::principal::FileFormatOptionsAst;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::tenant::Tenant;
@@ -167,6 +171,11 @@ pub trait TableContext: Send + Sync {
     async fn get_catalog(&self, catalog_name: &str) -> Result<Arc<dyn Catalog>>;
     fn get_default_catalog(&self) -> Result<Arc<dyn Catalog>>;
     fn get_id(&self) -> String;
+    /// The timestamp pinned at the start of the statement.
+    ///
+    /// Anything that needs a single logical "now" within a statement should use it
+    /// instead of reading the wall clock.
+    fn get_statement_timestamp(&self) -> DateTime<Utc>;
     fn get_current_catalog(&self) -> String;
     fn check_aborting(&self) -> Result<()>;
     fn get_error(&self) -> Option<ErrorCode>;
@@ -209,6 +218,14 @@ pub trait TableContext: Send + Sync {
 
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams>;
 
+    /// Resolve a stage location (`[@]<stage>[/<path>]`) together with the file format to read
+    /// it with, named or given inline, falling back to the file format of the stage.
+    async fn resolve_stage_and_format(
+        &self,
+        location: &str,
+        format_ref: &FileFormatOptionsAst,
+    ) -> Result<(StageInfo, FileFormatParams)>;
+
     async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection>;
 
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
@@ -17,7 +17,6 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use chrono::DateTime;
-use chrono::Duration;
 use chrono::Utc;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
@@ -357,7 +356,7 @@ pub async fn do_vacuum(
         start.elapsed().as_secs()
     );
     ctx.set_status_info(&status);
-    let retention = Duration::days(ctx.get_settings().get_data_retention_time_in_days()? as i64);
+    let retention = fuse_table.effective_retention(ctx.as_ref()).await?;
     // use min(now - get_retention_period(), retention_time) as gc orphan files retention time
     // to protect files that generated by txn which has not been committed being gc.
     let retention_time = std::cmp::min(chrono::Utc::now() - retention, retention_time);
@@ -222,6 +222,7 @@ async fn test_sync_agg_index() -> Result<()> {
     test_sync_agg_index_after_update().await?;
     test_sync_agg_index_after_insert().await?;
     test_sync_agg_index_after_copy_into().await?;
+    test_sync_agg_index_with_append_part().await?;
 
     Ok(())
 }
@@ -440,6 +441,48 @@ async fn test_sync_agg_index_after_insert() -> Result<()> {
     Ok(())
 }
 
+async fn test_sync_agg_index_with_append_part() -> Result<()> {
+    let fixture = TestFixture::setup_with_custom(EESetup::new()).await?;
+
+    // Create table
+    fixture
+        .execute_command(
+            "CREATE TABLE t0 (a int, b int, c int) storage_format = 'parquet' row_per_block = 2",
+        )
+        .await?;
+
+    // Create agg index `index0`
+    let index_name = "index0";
+
+    let original_query = "SELECT b, SUM(a) from t0 WHERE c > 1 GROUP BY b";
+    let ctx = fixture.new_query_ctx().await?;
+    let query = rewrite_original_query(ctx, original_query)?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let index_id0 = create_index(ctx, index_name, original_query, query.as_str(), true).await?;
+
+    // Insert data with packing of small blocks enabled
+    fixture.execute_command("set max_threads = 1").await?;
+    fixture
+        .execute_command("set fuse_append_part_file_size = 1048576")
+        .await?;
+    fixture
+        .execute_command("INSERT INTO t0 VALUES (1,1,4), (1,2,1), (1,2,4), (2,2,5)")
+        .await?;
+
+    // The blocks are not packed, each of them has its own aggregating index file.
+    let root = fixture.storage_root();
+    let block_path = find_block_path(root)?.unwrap();
+    let blocks = collect_file_names(&block_path)?;
+    assert_eq!(blocks.len(), 2);
+
+    let agg_index_path_0 = find_agg_index_path(root, index_id0)?.unwrap();
+    let indexes_0 = collect_file_names(&agg_index_path_0)?;
+    assert_eq!(blocks, indexes_0);
+
+    Ok(())
+}
+
 async fn test_sync_agg_index_after_copy_into() -> Result<()> {
     let fixture = TestFixture::setup_with_custom(EESetup::new()).await?;
     let settings = fixture.default_session().get_settings();
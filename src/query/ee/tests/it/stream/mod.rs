@@ -13,3 +13,5 @@
 // limitations under the License.
 
 mod stream_create;
+mod stream_describe;
+mod stream_show;
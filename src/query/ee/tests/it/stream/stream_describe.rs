@@ -0,0 +1,57 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_enterprise_query::test_kits::context::EESetup;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_stream_describe() -> Result<()> {
+    let fixture = TestFixture::setup_with_custom(EESetup::new()).await?;
+    fixture.create_default_database().await?;
+    fixture.create_normal_table().await?;
+
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture
+        .execute_command(&format!(
+            "alter table {db}.{tbl} set options(change_tracking=true)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "create stream {db}.s on table {db}.{tbl} append_only = false"
+        ))
+        .await?;
+
+    let blocks = fixture
+        .execute_query(&format!("desc stream {db}.s"))
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let result = pretty_format_blocks(&blocks)?;
+    assert!(result.contains(&format!("{db}.{tbl}")), "{result}");
+    assert!(result.contains("standard"), "{result}");
+
+    // A regular table cannot be described as a stream.
+    let r = fixture
+        .execute_query(&format!("desc stream {db}.{tbl}"))
+        .await;
+    assert!(r.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_enterprise_query::test_kits::context::EESetup;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_show_streams_on_table() -> Result<()> {
+    let fixture = TestFixture::setup_with_custom(EESetup::new()).await?;
+    fixture.create_default_database().await?;
+
+    let db = fixture.default_db_name();
+    for tbl in ["t1", "t2"] {
+        fixture
+            .execute_command(&format!(
+                "create table {db}.{tbl}(a int) change_tracking = true"
+            ))
+            .await?;
+    }
+    fixture
+        .execute_command(&format!("create stream {db}.s1 on table {db}.t1"))
+        .await?;
+    fixture
+        .execute_command(&format!("create stream {db}.s2 on table {db}.t2"))
+        .await?;
+    fixture
+        .execute_command(&format!("create stream {db}.s3 on table {db}.t2"))
+        .await?;
+
+    let blocks = fixture
+        .execute_query(&format!("show streams from {db} on table {db}.t2"))
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let result = pretty_format_blocks(&blocks)?;
+    assert!(result.contains("s2"), "{result}");
+    assert!(result.contains("s3"), "{result}");
+    assert!(!result.contains("s1"), "{result}");
+    assert!(!result.contains(&format!("{db}.t1")), "{result}");
+
+    // Without the filter, streams on both tables are listed.
+    let blocks = fixture
+        .execute_query(&format!("show streams from {db}"))
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let result = pretty_format_blocks(&blocks)?;
+    assert!(result.contains("s1"), "{result}");
+    assert!(result.contains("s2"), "{result}");
+
+    Ok(())
+}
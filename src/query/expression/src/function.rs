@@ -19,6 +19,8 @@ use std::ops::BitOr;
 use std::ops::Not;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_exception::ErrorCode;
@@ -111,6 +113,16 @@ pub struct FunctionContext {
 
     pub geometry_output_format: GeometryDataType,
     pub parse_datetime_ignore_remainder: bool,
+
+    /// The timestamp pinned at the start of the statement, if any.
+    pub statement_timestamp: Option<DateTime<Utc>>,
+}
+
+impl FunctionContext {
+    /// The logical "now" of the statement, falls back to the wall clock if it is not pinned.
+    pub fn now(&self) -> DateTime<Utc> {
+        self.statement_timestamp.unwrap_or_else(Utc::now)
+    }
 }
 
 #[derive(Clone)]
@@ -22,6 +22,9 @@ pub enum RecordDelimiter {
     Any(u8),
 }
 
+/// Number of leading bytes inspected by [`RecordDelimiter::sniff`].
+pub const RECORD_DELIMITER_SNIFF_BYTES: usize = 4096;
+
 impl RecordDelimiter {
     pub fn end(&self) -> u8 {
         match self {
@@ -29,6 +32,19 @@ impl RecordDelimiter {
             RecordDelimiter::Any(b) => *b,
         }
     }
+
+    /// Choose between `\r\n` and `\n` from the head of the data.
+    ///
+    /// `\r\n` is chosen as soon as it is seen, since the CRLF terminator also accepts
+    /// a bare `\n`, so files mixing both styles still split into the same records.
+    pub fn sniff(data: &[u8]) -> Self {
+        let head = &data[..data.len().min(RECORD_DELIMITER_SNIFF_BYTES)];
+        if head.windows(2).any(|w| w == b"\r\n") {
+            RecordDelimiter::Crlf
+        } else {
+            RecordDelimiter::Any(b'\n')
+        }
+    }
 }
 
 impl TryFrom<&str> for RecordDelimiter {
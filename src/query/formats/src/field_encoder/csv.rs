@@ -78,13 +78,19 @@ pub struct FieldEncoderCSV {
 
 impl FieldEncoderCSV {
     pub fn create_csv(params: &CsvFileFormatParams, options_ext: &FileFormatOptionsExt) -> Self {
+        // Unloading to a stage writes the NULL of the file format, so it can be loaded back.
+        let null_display = if options_ext.is_select || options_ext.is_clickhouse {
+            params.get_output_null_display()
+        } else {
+            &params.null_display
+        };
         Self {
             nested: FieldEncoderValues::create(options_ext),
             simple: FieldEncoderValues {
                 common_settings: OutputCommonSettings {
                     true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
                     false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
-                    null_bytes: NULL_BYTES_ESCAPE.as_bytes().to_vec(),
+                    null_bytes: null_display.as_bytes().to_vec(),
                     nan_bytes: params.nan_display.as_bytes().to_vec(),
                     inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                     timezone: options_ext.timezone,
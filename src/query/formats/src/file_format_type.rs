@@ -105,6 +105,17 @@ impl FileFormatOptionsExt {
         Ok(options)
     }
 
+    /// Interpret timestamps without zone in the `TIME_ZONE` of the file format instead of the
+    /// session timezone, if it is set.
+    pub fn apply_file_format_time_zone(&mut self, params: &FileFormatParams) -> Result<()> {
+        if let Some(tz) = params.time_zone() {
+            self.timezone = tz.parse::<Tz>().map_err(|_| {
+                ErrorCode::InvalidTimezone(format!("Invalid TIME_ZONE of file format: {tz}"))
+            })?;
+        }
+        Ok(())
+    }
+
     pub fn get_output_format_from_clickhouse_format(
         typ: ClickhouseFormatType,
         schema: TableSchemaRef,
@@ -180,7 +191,11 @@ impl FileFormatOptionsExt {
                     _ => unreachable!(),
                 }
             }
-            FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
+            FileFormatParams::Parquet(params) => Box::new(ParquetOutputFormat::create(
+                schema,
+                params.compression,
+                self,
+            )),
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
@@ -28,6 +28,7 @@ pub mod output_format;
 
 pub use clickhouse::ClickhouseFormatType;
 pub use delimiter::RecordDelimiter;
+pub use delimiter::RECORD_DELIMITER_SNIFF_BYTES;
 pub use field_decoder::*;
 pub use file_format_type::parse_timezone;
 pub use file_format_type::FileFormatOptionsExt;
@@ -46,7 +46,12 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NA
             schema,
             field_encoder,
             field_delimiter: params.field_delimiter.as_bytes()[0],
-            record_delimiter: params.record_delimiter.as_bytes().to_vec(),
+            // AUTO only makes sense when loading, unload with `\n`
+            record_delimiter: if params.is_auto_record_delimiter() {
+                b"\n".to_vec()
+            } else {
+                params.record_delimiter.as_bytes().to_vec()
+            },
             quote: params.quote.as_bytes()[0],
         }
     }
@@ -16,6 +16,7 @@ use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+use databend_common_meta_app::principal::StageFileCompression;
 use databend_storages_common_blocks::blocks_to_parquet;
 use databend_storages_common_table_meta::table::TableCompression;
 
@@ -25,13 +26,25 @@ use crate::FileFormatOptionsExt;
 #[derive(Default)]
 pub struct ParquetOutputFormat {
     schema: TableSchemaRef,
+    compression: TableCompression,
     data_blocks: Vec<DataBlock>,
 }
 
 impl ParquetOutputFormat {
-    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+    pub fn create(
+        schema: TableSchemaRef,
+        compression: StageFileCompression,
+        _options: &FileFormatOptionsExt,
+    ) -> Self {
+        let compression = match compression {
+            StageFileCompression::None => TableCompression::None,
+            StageFileCompression::Snappy => TableCompression::Snappy,
+            // Other codecs are rejected by `FileFormatParams::check`.
+            _ => TableCompression::Zstd,
+        };
         Self {
             schema,
+            compression,
             data_blocks: vec![],
         }
     }
@@ -53,7 +66,7 @@ impl OutputFormat for ParquetOutputFormat {
             return Ok(vec![]);
         }
         let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let _ = blocks_to_parquet(&self.schema, blocks, &mut buf, TableCompression::Zstd)?;
+        let _ = blocks_to_parquet(&self.schema, blocks, &mut buf, self.compression)?;
         Ok(buf)
     }
 }
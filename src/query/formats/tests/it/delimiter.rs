@@ -0,0 +1,44 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_formats::RecordDelimiter;
+use databend_common_formats::RECORD_DELIMITER_SNIFF_BYTES;
+
+fn sniff(data: &[u8]) -> String {
+    format!("{:?}", RecordDelimiter::sniff(data))
+}
+
+#[test]
+fn test_sniff_record_delimiter() {
+    let lf = format!("{:?}", RecordDelimiter::Any(b'\n'));
+    let crlf = format!("{:?}", RecordDelimiter::Crlf);
+
+    // unix style
+    assert_eq!(lf, sniff(b"1,a\n2,b\n3,c\n"));
+    // windows style
+    assert_eq!(crlf, sniff(b"1,a\r\n2,b\r\n3,c\r\n"));
+    // mixed, CRLF also splits on a bare `\n`
+    assert_eq!(crlf, sniff(b"1,a\n2,b\r\n3,c\n"));
+    // no delimiter at all
+    assert_eq!(lf, sniff(b""));
+    assert_eq!(lf, sniff(b"1,a"));
+
+    // only the head of the data is inspected
+    let mut data = vec![b'x'; RECORD_DELIMITER_SNIFF_BYTES];
+    data.extend_from_slice(b"\r\n");
+    assert_eq!(lf, sniff(&data));
+    data.insert(0, b'\n');
+    data.insert(0, b'\r');
+    assert_eq!(crlf, sniff(&data));
+}
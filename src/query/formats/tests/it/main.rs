@@ -20,6 +20,7 @@ use databend_common_formats::FileFormatOptionsExt;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_settings::Settings;
 
+mod delimiter;
 mod field_decoder;
 mod field_encoder;
 mod output_format_json_each_row;
@@ -112,6 +112,43 @@ fn test_null() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_csv_output_null_display() -> Result<()> {
+    let (schema, block) = gen_schema_and_block(
+        vec![TableField::new(
+            "c1",
+            TableDataType::Number(NumberDataType::Int32).wrap_nullable(),
+        )],
+        vec![Int32Type::from_opt_data(vec![Some(1i32), None])],
+    );
+
+    let settings = Settings::create(Tenant::new_literal("default"));
+    let serialize = |opts: &[(&str, &str)], is_select: bool| -> Result<String> {
+        let mut options = BTreeMap::<String, String>::new();
+        options.insert("type".to_string(), "csv".to_string());
+        for (k, v) in opts {
+            options.insert(k.to_string(), v.to_string());
+        }
+        let params = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)?;
+        let mut options = FileFormatOptionsExt::create_from_settings(&settings, is_select)?;
+        let mut output_format = options.get_output_format(schema.clone(), params)?;
+        Ok(String::from_utf8(output_format.serialize_block(&block)?)?)
+    };
+
+    // falls back to null_display
+    assert_eq!("1\n\\N\n", serialize(&[], true)?);
+    assert_eq!("1\nnull\n", serialize(&[("null_display", "null")], true)?);
+
+    // output_null_display overrides null_display in the client output
+    let opts = [("null_display", "null"), ("output_null_display", "<null>")];
+    assert_eq!("1\n<null>\n", serialize(&opts, true)?);
+
+    // unloading keeps null_display
+    assert_eq!("1\nnull\n", serialize(&opts, false)?);
+
+    Ok(())
+}
+
 #[test]
 fn test_data_block_nullable() -> Result<()> {
     test_data_block(true)
@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::hash::Hash;
+use std::sync::Arc;
 
 use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::AnyType;
 use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::EmptyArrayType;
 use databend_common_expression::types::EmptyMapType;
 use databend_common_expression::types::GenericType;
@@ -26,9 +30,19 @@ use databend_common_expression::types::NumberType;
 use databend_common_expression::types::SimpleDomain;
 use databend_common_expression::vectorize_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Domain;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
 use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
 use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
 use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
 use databend_common_hashtable::StackHashSet;
 use siphasher::sip128::Hasher128;
 use siphasher::sip128::SipHasher24;
@@ -47,11 +61,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         vectorize_with_builder_2_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<1>>, MapType<GenericType<0>, GenericType<1>>>(
             |keys, vals, output, ctx| {
                 let key_type = &ctx.generics[0];
-                if !key_type.is_boolean()
-                        && !key_type.is_string()
-                        && !key_type.is_numeric()
-                        && !key_type.is_decimal()
-                        && !key_type.is_date_or_date_time() {
+                if !is_valid_map_key_type(key_type) {
                     ctx.set_error(output.len(), format!("map keys can not be {}", key_type));
                 } else if keys.len() != vals.len() {
                     ctx.set_error(output.len(), format!(
@@ -170,4 +180,199 @@ pub fn register(registry: &mut FunctionRegistry) {
         |_, _| FunctionDomain::Full,
         |map, _| map.len() as u64,
     );
+
+    registry.register_1_arg_core::<NullType, NullType, _, _>(
+        "map_entries",
+        |_, _| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry.register_1_arg_core::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_entries",
+        |_, _| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    // A map is stored as an array of key-value tuples, so the entries share the same layout.
+    registry.register_function_factory("map_entries", |_, args_type| {
+        let (map_type, is_nullable) = match args_type {
+            [DataType::Nullable(box ty @ DataType::Map(_))] => (ty.clone(), true),
+            [ty @ DataType::Map(_)] => (ty.clone(), false),
+            _ => return None,
+        };
+        let entry_type = map_type.as_map()?.as_ref().clone();
+
+        let function = Function {
+            signature: FunctionSignature {
+                name: "map_entries".to_string(),
+                args_type: vec![map_type],
+                return_type: DataType::Array(Box::new(entry_type)),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, args_domain| match &args_domain[0] {
+                    Domain::Map(entry_domain) => {
+                        FunctionDomain::Domain(Domain::Array(entry_domain.clone()))
+                    }
+                    _ => unreachable!(),
+                }),
+                eval: Box::new(|args, _| match &args[0] {
+                    ValueRef::Scalar(ScalarRef::Map(entries)) => {
+                        Value::Scalar(Scalar::Array(entries.clone()))
+                    }
+                    ValueRef::Column(Column::Map(col)) => Value::Column(Column::Array(col.clone())),
+                    _ => unreachable!(),
+                }),
+            },
+        };
+
+        if is_nullable {
+            Some(Arc::new(function.passthrough_nullable()))
+        } else {
+            Some(Arc::new(function))
+        }
+    });
+
+    registry.register_1_arg_core::<NullType, NullType, _, _>(
+        "map_from_entries",
+        |_, _| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry.register_1_arg_core::<EmptyArrayType, EmptyMapType, _, _>(
+        "map_from_entries",
+        |_, _| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    // `map_from_entries(entries [, last_wins])` builds a map from an array of key-value tuples.
+    // Duplicate keys are rejected unless `last_wins` is true, in which case the last value is kept.
+    registry.register_function_factory("map_from_entries", |_, args_type| {
+        if args_type.is_empty() || args_type.len() > 2 {
+            return None;
+        }
+        let is_nullable = args_type.iter().any(|ty| ty.is_nullable_or_null());
+        let entries_type = args_type[0].remove_nullable();
+        let entry_type = entries_type.as_array()?.as_ref().clone();
+        let (key_type, val_type) = match entry_type.remove_nullable() {
+            DataType::Tuple(fields) if fields.len() == 2 => (fields[0].clone(), fields[1].clone()),
+            _ => return None,
+        };
+        if !is_valid_map_key_type(&key_type) {
+            return None;
+        }
+        let mut sig_args_type = vec![entries_type];
+        if args_type.len() == 2 {
+            if args_type[1].remove_nullable() != DataType::Boolean {
+                return None;
+            }
+            sig_args_type.push(DataType::Boolean);
+        }
+        let return_type = DataType::Map(Box::new(DataType::Tuple(vec![key_type, val_type])));
+
+        let function = Function {
+            signature: FunctionSignature {
+                name: "map_from_entries".to_string(),
+                args_type: sig_args_type,
+                return_type: return_type.clone(),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| eval_map_from_entries(args, ctx, &return_type)),
+            },
+        };
+
+        if is_nullable {
+            Some(Arc::new(function.passthrough_nullable()))
+        } else {
+            Some(Arc::new(function))
+        }
+    });
+}
+
+fn is_valid_map_key_type(key_type: &DataType) -> bool {
+    key_type.is_boolean()
+        || key_type.is_string()
+        || key_type.is_numeric()
+        || key_type.is_decimal()
+        || key_type.is_date_or_date_time()
+}
+
+fn eval_map_from_entries(
+    args: &[ValueRef<AnyType>],
+    ctx: &mut EvalContext,
+    return_type: &DataType,
+) -> Value<AnyType> {
+    let entry_type = return_type.as_map().unwrap();
+    let last_wins = |row: usize| {
+        args.get(1)
+            .and_then(|arg| arg.index(row))
+            .and_then(|v| v.as_boolean().cloned())
+            .unwrap_or(false)
+    };
+
+    match &args[0] {
+        ValueRef::Scalar(scalar) => {
+            let entries = scalar.as_array().unwrap();
+            match build_map_entries(entries, entry_type, last_wins(0)) {
+                Ok(col) => Value::Scalar(Scalar::Map(col)),
+                Err(err) => {
+                    ctx.set_error(0, err);
+                    Value::Scalar(Scalar::default_value(return_type))
+                }
+            }
+        }
+        ValueRef::Column(column) => {
+            let mut builder = ColumnBuilder::with_capacity(return_type, column.len());
+            for (row, entries) in column.iter().enumerate() {
+                let entries = entries.as_array().unwrap();
+                match build_map_entries(entries, entry_type, last_wins(row)) {
+                    Ok(col) => builder.push(ScalarRef::Map(col)),
+                    Err(err) => {
+                        ctx.set_error(row, err);
+                        builder.push_default();
+                    }
+                }
+            }
+            Value::Column(builder.build())
+        }
+    }
+}
+
+/// Collects the non-null `(key, value)` tuples of `entries` into the inner column of a map.
+/// A repeated key keeps the position of its first occurrence.
+fn build_map_entries(
+    entries: &Column,
+    entry_type: &DataType,
+    last_wins: bool,
+) -> Result<Column, String> {
+    let mut items: Vec<(ScalarRef, ScalarRef)> = Vec::with_capacity(entries.len());
+    let mut positions: HashMap<u128, usize> = HashMap::with_capacity(entries.len());
+    for entry in entries.iter() {
+        let (key, val) = match entry {
+            ScalarRef::Null => continue,
+            ScalarRef::Tuple(mut fields) => {
+                let val = fields.pop().unwrap();
+                let key = fields.pop().unwrap();
+                (key, val)
+            }
+            _ => unreachable!(),
+        };
+        let mut hasher = SipHasher24::new();
+        key.hash(&mut hasher);
+        let hash_key: u128 = hasher.finish128().into();
+        match positions.get(&hash_key) {
+            Some(pos) if last_wins => items[*pos].1 = val,
+            Some(_) => return Err("map keys have to be unique".to_string()),
+            None => {
+                positions.insert(hash_key, items.len());
+                items.push((key, val));
+            }
+        }
+    }
+
+    let mut builder = ColumnBuilder::with_capacity(entry_type, items.len());
+    for (key, val) in items {
+        builder.push(ScalarRef::Tuple(vec![key, val]));
+    }
+    Ok(builder.build())
 }
@@ -24,6 +24,7 @@ mod role;
 mod serde;
 mod setting;
 mod stage;
+mod table_location;
 pub mod udf;
 mod user;
 
@@ -37,7 +38,9 @@ pub use network_policy::NetworkPolicyMgr;
 pub use password_policy::PasswordPolicyMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
+pub use role::OwnershipCache;
 pub use role::RoleApi;
+pub use role::RoleCache;
 pub use role::RoleMgr;
 pub use serde::check_and_upgrade_to_pb;
 pub use serde::deserialize_struct;
@@ -46,5 +49,6 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use table_location::TableLocationMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod ownership_cache;
 mod role_api;
+mod role_cache;
 mod role_mgr;
 
+pub use ownership_cache::OwnershipCache;
 pub use role_api::RoleApi;
+pub use role_cache::RoleCache;
 pub use role_mgr::RoleMgr;
@@ -0,0 +1,76 @@
+// Copyright 2024 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_meta_app::principal::OwnershipInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::SeqV;
+use parking_lot::RwLock;
+
+/// Ownership listings keyed by tenant.
+///
+/// An entry is only returned while it is younger than the `ttl` of the reader, which bounds how
+/// long changes made by other query nodes stay invisible. Every invalidation bumps a generation,
+/// a listing read before an invalidation is not inserted after it.
+#[derive(Default)]
+pub struct OwnershipCache {
+    inner: RwLock<OwnershipCacheInner>,
+}
+
+#[derive(Default)]
+struct OwnershipCacheInner {
+    generation: u64,
+    ownerships: HashMap<String, (Instant, Vec<SeqV<OwnershipInfo>>)>,
+}
+
+impl OwnershipCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, tenant: &Tenant, ttl: Duration) -> Option<Vec<SeqV<OwnershipInfo>>> {
+        let inner = self.inner.read();
+        let (filled_at, ownerships) = inner.ownerships.get(tenant.tenant_name())?;
+        if filled_at.elapsed() < ttl {
+            Some(ownerships.clone())
+        } else {
+            None
+        }
+    }
+
+    /// The generation to pass to `insert`, it must be taken before reading the listing.
+    pub fn generation(&self) -> u64 {
+        self.inner.read().generation
+    }
+
+    /// Insert a listing, unless the cache has been invalidated since `generation` was taken.
+    pub fn insert(&self, tenant: &Tenant, generation: u64, ownerships: Vec<SeqV<OwnershipInfo>>) {
+        let mut inner = self.inner.write();
+        if inner.generation == generation {
+            inner.ownerships.insert(
+                tenant.tenant_name().to_string(),
+                (Instant::now(), ownerships),
+            );
+        }
+    }
+
+    pub fn invalidate(&self, tenant: &Tenant) {
+        let mut inner = self.inner.write();
+        inner.generation += 1;
+        inner.ownerships.remove(tenant.tenant_name());
+    }
+}
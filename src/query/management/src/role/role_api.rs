@@ -28,8 +28,23 @@ pub trait RoleApi: Sync + Send {
 
     async fn get_roles(&self) -> Result<Vec<SeqV<RoleInfo>>>;
 
+    /// List at most `limit` roles, in the order of role name, that come after `start_after`.
+    ///
+    /// Pass the name of the last role of a page as `start_after` to get the next page,
+    /// an empty page means there are no more roles.
+    async fn get_roles_page(
+        &self,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SeqV<RoleInfo>>>;
+
     async fn get_ownerships(&self) -> Result<Vec<SeqV<OwnershipInfo>>>;
 
+    /// Same as `get_ownerships`, but served from the ownership cache if it is enabled.
+    ///
+    /// `get_ownerships` always reads from the meta service and stays authoritative.
+    async fn get_ownerships_cached(&self) -> Result<Vec<SeqV<OwnershipInfo>>>;
+
     /// General role update.
     ///
     /// It fetches the role that matches the specified seq number, update it in place, then write it back with the seq it sees.
@@ -67,6 +82,12 @@ pub trait RoleApi: Sync + Send {
     #[allow(clippy::ptr_arg)]
     async fn grant_ownership(&self, object: &OwnershipObject, role: &str) -> Result<()>;
 
+    /// Grant ownership of all the `objects` to `role` in a single transaction.
+    ///
+    /// The objects may be owned by different old roles: the ownership privileges are revoked
+    /// from every old role and granted to the new role with one role update each.
+    async fn grant_ownership_batch(&self, objects: &[OwnershipObject], role: &str) -> Result<()>;
+
     /// Remember to call this method when you dropped a OwnerObject like table/database/stage/udf.
     /// Revoke ownership used when drop old object, contains two step:
     /// 1. revoke ownership privilege obj to new role,
@@ -79,4 +100,11 @@ pub trait RoleApi: Sync + Send {
     async fn get_ownership(&self, object: &OwnershipObject) -> Result<Option<OwnershipInfo>>;
 
     async fn drop_role(&self, role: String, seq: MatchSeq) -> Result<()>;
+
+    /// Drop the role and revoke it from all the roles and users which are granted it, in a
+    /// single transaction guarded by the seq of every updated key.
+    ///
+    /// Dropping a role which does not exist only cleans up the remaining grants, so it is
+    /// safe to retry.
+    async fn drop_role_cascade(&self, role: &str) -> Result<()>;
 }
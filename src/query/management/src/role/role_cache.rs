@@ -0,0 +1,80 @@
+// Copyright 2024 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::SeqV;
+use parking_lot::RwLock;
+
+/// In-memory read-through cache of roles, keyed by tenant and role name.
+///
+/// Used by `RoleMgr::get_role` to avoid a meta-service round trip on every access check.
+/// An entry is only returned while it is younger than the `ttl` of the reader. Every
+/// invalidation bumps a generation, a role read before an invalidation is not inserted after it.
+#[derive(Default)]
+pub struct RoleCache {
+    inner: RwLock<RoleCacheInner>,
+}
+
+#[derive(Default)]
+struct RoleCacheInner {
+    generation: u64,
+    roles: HashMap<(String, String), (Instant, SeqV<RoleInfo>)>,
+}
+
+impl RoleCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, tenant: &Tenant, role: &str, ttl: Duration) -> Option<SeqV<RoleInfo>> {
+        let inner = self.inner.read();
+        let (filled_at, role_info) = inner
+            .roles
+            .get(&(tenant.tenant_name().to_string(), role.to_string()))?;
+        if filled_at.elapsed() < ttl {
+            Some(role_info.clone())
+        } else {
+            None
+        }
+    }
+
+    /// The generation to pass to `insert`, it must be taken before reading the role.
+    pub fn generation(&self) -> u64 {
+        self.inner.read().generation
+    }
+
+    /// Insert a role, unless the cache has been invalidated since `generation` was taken.
+    pub fn insert(&self, tenant: &Tenant, role: &str, generation: u64, role_info: SeqV<RoleInfo>) {
+        let mut inner = self.inner.write();
+        if inner.generation == generation {
+            inner.roles.insert(
+                (tenant.tenant_name().to_string(), role.to_string()),
+                (Instant::now(), role_info),
+            );
+        }
+    }
+
+    pub fn invalidate(&self, tenant: &Tenant, role: &str) {
+        let mut inner = self.inner.write();
+        inner.generation += 1;
+        inner
+            .roles
+            .remove(&(tenant.tenant_name().to_string(), role.to_string()));
+    }
+}
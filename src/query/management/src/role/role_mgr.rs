@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::Utc;
 use databend_common_exception::ErrorCode;
 use databend_common_meta_api::reply::txn_reply_to_api_result;
 use databend_common_meta_api::txn_backoff::txn_backoff;
@@ -27,6 +31,8 @@ use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::principal::RoleIdent;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::TenantOwnershipObjectIdent;
+use databend_common_meta_app::principal::TenantUserIdent;
+use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_app::KeyWithTenant;
@@ -42,11 +48,16 @@ use databend_common_meta_types::Operation;
 use databend_common_meta_types::SeqV;
 use databend_common_meta_types::TxnRequest;
 use enumflags2::make_bitflags;
+use futures::TryStreamExt;
 use log::debug;
 use minitrace::func_name;
 
+use crate::role::ownership_cache::OwnershipCache;
 use crate::role::role_api::RoleApi;
+use crate::role::role_cache::RoleCache;
 use crate::serde::check_and_upgrade_to_pb;
+use crate::serde::check_and_upgrade_to_pb_batch;
+use crate::serde::deserialize_struct;
 use crate::serde::Quota;
 use crate::serialize_struct;
 
@@ -57,6 +68,10 @@ static BUILTIN_ROLE_ACCOUNT_ADMIN: &str = "account_admin";
 pub struct RoleMgr {
     kv_api: Arc<dyn kvapi::KVApi<Error = MetaError> + Send + Sync>,
     tenant: Tenant,
+    ownership_cache: Option<Arc<OwnershipCache>>,
+    ownership_cache_ttl: Duration,
+    role_cache: Option<Arc<RoleCache>>,
+    role_cache_ttl: Duration,
 }
 
 impl RoleMgr {
@@ -67,6 +82,71 @@ impl RoleMgr {
         RoleMgr {
             kv_api,
             tenant: tenant.clone(),
+            ownership_cache: None,
+            ownership_cache_ttl: Duration::ZERO,
+            role_cache: None,
+            role_cache_ttl: Duration::ZERO,
+        }
+    }
+
+    /// Enable caching of the ownership listing returned by `get_ownerships_cached`.
+    ///
+    /// The cache can be shared by several `RoleMgr`, it is keyed by tenant and invalidated
+    /// whenever this `RoleMgr` changes an ownership. Changes made by other query nodes are
+    /// seen once the cached listing is older than `ttl`, a zero `ttl` only invalidates.
+    pub fn with_ownership_cache(mut self, cache: Arc<OwnershipCache>, ttl: Duration) -> Self {
+        self.ownership_cache = Some(cache);
+        self.ownership_cache_ttl = ttl;
+        self
+    }
+
+    fn invalidate_ownership_cache(&self) {
+        if let Some(cache) = &self.ownership_cache {
+            cache.invalidate(&self.tenant);
+        }
+    }
+
+    /// Enable a read-through cache for `get_role`.
+    ///
+    /// The cache can be shared by several `RoleMgr`, it is keyed by tenant and role name, and an
+    /// entry is invalidated whenever this `RoleMgr` changes that role. Changes made by other
+    /// query nodes are seen once the cached role is older than `ttl`, a zero `ttl` disables it.
+    pub fn with_role_cache(mut self, cache: Arc<RoleCache>, ttl: Duration) -> Self {
+        self.role_cache = Some(cache);
+        self.role_cache_ttl = ttl;
+        self
+    }
+
+    fn invalidate_role_cache(&self, role: &str) {
+        if let Some(cache) = &self.role_cache {
+            cache.invalidate(&self.tenant, role);
+        }
+    }
+
+    /// Read a role from meta-service, bypassing the role cache.
+    ///
+    /// Used where the returned seq guards a subsequent write.
+    #[async_backtrace::framed]
+    async fn read_role(&self, role: &str, seq: MatchSeq) -> Result<SeqV<RoleInfo>, ErrorCode> {
+        let key = self.role_ident(role).to_string_key();
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownRole(format!("Role '{}' does not exist.", role)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => {
+                let mut quota = Quota::new(func_name!());
+
+                let u = check_and_upgrade_to_pb(&mut quota, key, &seq_value, self.kv_api.as_ref())
+                    .await?;
+
+                // Keep the original seq.
+                Ok(SeqV::new(seq_value.seq, u.data))
+            }
+            Err(_) => Err(ErrorCode::UnknownRole(format!(
+                "Role '{}' does not exist.",
+                role
+            ))),
         }
     }
 
@@ -149,6 +229,7 @@ impl RoleApi for RoleMgr {
         let res_seq = upsert_kv.await?.added_seq_or_else(|_v| {
             ErrorCode::RoleAlreadyExists(format!("Role '{}' already exists.", role_info.name))
         })?;
+        self.invalidate_role_cache(&role_info.name);
 
         Ok(res_seq)
     }
@@ -156,26 +237,25 @@ impl RoleApi for RoleMgr {
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn get_role(&self, role: &String, seq: MatchSeq) -> Result<SeqV<RoleInfo>, ErrorCode> {
-        let key = self.role_ident(role).to_string_key();
-        let res = self.kv_api.get_kv(&key).await?;
-        let seq_value =
-            res.ok_or_else(|| ErrorCode::UnknownRole(format!("Role '{}' does not exist.", role)))?;
-
-        match seq.match_seq(&seq_value) {
-            Ok(_) => {
-                let mut quota = Quota::new(func_name!());
-
-                let u = check_and_upgrade_to_pb(&mut quota, key, &seq_value, self.kv_api.as_ref())
-                    .await?;
+        let Some(cache) = &self.role_cache else {
+            return self.read_role(role, seq).await;
+        };
+        if self.role_cache_ttl.is_zero() {
+            return self.read_role(role, seq).await;
+        }
 
-                // Keep the original seq.
-                Ok(SeqV::new(seq_value.seq, u.data))
+        if let Some(seq_value) = cache.get(&self.tenant, role, self.role_cache_ttl) {
+            // A cached role that does not match `seq` may be stale, re-read it from meta-service.
+            if seq.match_seq(&seq_value).is_ok() {
+                return Ok(seq_value);
             }
-            Err(_) => Err(ErrorCode::UnknownRole(format!(
-                "Role '{}' does not exist.",
-                role
-            ))),
         }
+
+        // Taken before the read, so a role that races with an invalidation is not cached.
+        let generation = cache.generation();
+        let seq_value = self.read_role(role, seq).await?;
+        cache.insert(&self.tenant, role, generation, seq_value.clone());
+        Ok(seq_value)
     }
 
     #[async_backtrace::framed]
@@ -184,16 +264,41 @@ impl RoleApi for RoleMgr {
         let role_prefix = self.role_prefix();
         let values = self.kv_api.prefix_list_kv(role_prefix.as_str()).await?;
 
-        let mut r = vec![];
-
         let mut quota = Quota::new(func_name!());
 
-        for (key, val) in values {
-            let u = check_and_upgrade_to_pb(&mut quota, key, &val, self.kv_api.as_ref()).await?;
-            r.push(u);
+        check_and_upgrade_to_pb_batch(&mut quota, values, self.kv_api.as_ref()).await
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_roles_page(
+        &self,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SeqV<RoleInfo>>, ErrorCode> {
+        let role_prefix = self.role_prefix();
+        // Keys are listed in order, the listing starts right after the key of `start_after`.
+        let mut strm = match start_after {
+            Some(role) => {
+                let start_key = self.role_ident(role).to_string_key();
+                self.kv_api.list_kv_after(&role_prefix, &start_key).await?
+            }
+            None => self.kv_api.list_kv(&role_prefix).await?,
+        };
+
+        let mut values = vec![];
+        while values.len() < limit {
+            let Some(item) = strm.try_next().await? else {
+                break;
+            };
+
+            // Safe unwrap(): list_kv() does not return None value
+            values.push((item.key, SeqV::from(item.value.unwrap())));
         }
 
-        Ok(r)
+        let mut quota = Quota::new(func_name!());
+
+        check_and_upgrade_to_pb_batch(&mut quota, values, self.kv_api.as_ref()).await
     }
 
     #[async_backtrace::framed]
@@ -205,16 +310,30 @@ impl RoleApi for RoleMgr {
             .prefix_list_kv(object_owner_prefix.as_str())
             .await?;
 
-        let mut r = vec![];
-
         let mut quota = Quota::new(func_name!());
 
-        for (key, val) in values {
-            let u = check_and_upgrade_to_pb(&mut quota, key, &val, self.kv_api.as_ref()).await?;
-            r.push(u);
+        check_and_upgrade_to_pb_batch(&mut quota, values, self.kv_api.as_ref()).await
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_ownerships_cached(&self) -> Result<Vec<SeqV<OwnershipInfo>>, ErrorCode> {
+        let Some(cache) = &self.ownership_cache else {
+            return self.get_ownerships().await;
+        };
+        if self.ownership_cache_ttl.is_zero() {
+            return self.get_ownerships().await;
+        }
+
+        if let Some(ownerships) = cache.get(&self.tenant, self.ownership_cache_ttl) {
+            return Ok(ownerships);
         }
 
-        Ok(r)
+        // Taken before the read, so a listing that races with an invalidation is not cached.
+        let generation = cache.generation();
+        let ownerships = self.get_ownerships().await?;
+        cache.insert(&self.tenant, generation, ownerships.clone());
+        Ok(ownerships)
     }
 
     /// General role update.
@@ -237,14 +356,15 @@ impl RoleApi for RoleMgr {
             seq,
             data: mut role_info,
             ..
-        } = self.get_role(role, seq).await?;
+        } = self.read_role(role, seq).await?;
 
         f(&mut role_info);
 
-        let seq = self
+        let res = self
             .upsert_role_info(&role_info, MatchSeq::Exact(seq))
-            .await?;
-        Ok(Some(seq))
+            .await;
+        self.invalidate_role_cache(role);
+        Ok(Some(res?))
     }
 
     /// Only drop role will call transfer.
@@ -280,6 +400,7 @@ impl RoleApi for RoleMgr {
                         &OwnershipInfo {
                             object,
                             role: BUILTIN_ROLE_ACCOUNT_ADMIN.to_string(),
+                            updated_on: Utc::now(),
                         },
                         ErrorCode::IllegalUserInfoFormat,
                         || "",
@@ -303,6 +424,7 @@ impl RoleApi for RoleMgr {
                     "transfer_ownership_to_admin"
                 );
                 if succ {
+                    self.invalidate_ownership_cache();
                     break;
                 }
             } else {
@@ -329,6 +451,7 @@ impl RoleApi for RoleMgr {
             &OwnershipInfo {
                 object: object.clone(),
                 role: new_role.to_string(),
+                updated_on: Utc::now(),
             },
             ErrorCode::IllegalUserInfoFormat,
             || "",
@@ -342,7 +465,7 @@ impl RoleApi for RoleMgr {
 
             if let Some(ref old_role) = old_role {
                 // BUILTIN role or Dropped role may get err, no need to revoke
-                if let Ok(seqv) = self.get_role(old_role, MatchSeq::GE(1)).await {
+                if let Ok(seqv) = self.read_role(old_role, MatchSeq::GE(1)).await {
                     let old_key = self.role_ident(old_role);
                     let old_seq = seqv.seq;
                     let mut old_role_info = seqv.data;
@@ -365,7 +488,7 @@ impl RoleApi for RoleMgr {
                     seq: new_seq,
                     data: mut new_role_info,
                     ..
-                } = self.get_role(&new_role.to_owned(), MatchSeq::GE(1)).await?;
+                } = self.read_role(new_role, MatchSeq::GE(1)).await?;
                 new_role_info.grants.grant_privileges(
                     &grant_object,
                     make_bitflags!(UserPrivilegeType::{ Ownership }).into(),
@@ -387,6 +510,11 @@ impl RoleApi for RoleMgr {
             let (succ, _) = txn_reply_to_api_result(tx_reply)?;
 
             if succ {
+                self.invalidate_ownership_cache();
+                if let Some(ref old_role) = old_role {
+                    self.invalidate_role_cache(old_role);
+                }
+                self.invalidate_role_cache(new_role);
                 return Ok(());
             }
         }
@@ -396,6 +524,145 @@ impl RoleApi for RoleMgr {
         ))
     }
 
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn grant_ownership_batch(
+        &self,
+        objects: &[OwnershipObject],
+        new_role: &str,
+    ) -> databend_common_exception::Result<()> {
+        // The same object listed twice would add conflicting ops on its owner key.
+        let mut seen = HashSet::new();
+        let objects = objects
+            .iter()
+            .filter(|object| seen.insert(*object))
+            .collect::<Vec<_>>();
+        if objects.is_empty() {
+            return Ok(());
+        }
+
+        let owner_keys = objects
+            .iter()
+            .map(|object| self.ownership_object_ident(object))
+            .collect::<Vec<_>>();
+        let owner_string_keys = owner_keys
+            .iter()
+            .map(|key| key.to_string_key())
+            .collect::<Vec<_>>();
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+            let mut condition = vec![];
+            let mut if_then = vec![];
+
+            // Read all the current owners at once, their seqs guard the owner keys.
+            let seq_values = self.kv_api.mget_kv(&owner_string_keys).await?;
+            let mut quota = Quota::new(func_name!());
+
+            // Group the objects by their old owner, so that each old role is updated only once.
+            let mut old_roles: BTreeMap<String, Vec<GrantObject>> = BTreeMap::new();
+            for ((object, owner_key), seq_value) in
+                objects.iter().zip(owner_keys.iter()).zip(seq_values)
+            {
+                let owner_value = serialize_struct(
+                    &OwnershipInfo {
+                        object: (*object).clone(),
+                        role: new_role.to_string(),
+                        updated_on: Utc::now(),
+                    },
+                    ErrorCode::IllegalUserInfoFormat,
+                    || "",
+                )?;
+
+                match seq_value {
+                    Some(seq_value) => {
+                        condition.push(txn_cond_seq(owner_key, Eq, seq_value.seq));
+                        let old: SeqV<OwnershipInfo> = check_and_upgrade_to_pb(
+                            &mut quota,
+                            owner_key.to_string_key(),
+                            &seq_value,
+                            self.kv_api.as_ref(),
+                        )
+                        .await?;
+                        if old.data.role != new_role {
+                            old_roles
+                                .entry(old.data.role)
+                                .or_default()
+                                .push(convert_to_grant_obj(object));
+                        }
+                    }
+                    None => condition.push(txn_cond_seq(owner_key, Eq, 0)),
+                }
+                if_then.push(txn_op_put(owner_key, owner_value));
+            }
+
+            let changed_roles = old_roles.keys().cloned().collect::<Vec<_>>();
+            for (old_role, grant_objects) in old_roles {
+                // BUILTIN role or Dropped role may get err, no need to revoke
+                if let Ok(seqv) = self.read_role(&old_role, MatchSeq::GE(1)).await {
+                    let old_key = self.role_ident(&old_role);
+                    let old_seq = seqv.seq;
+                    let mut old_role_info = seqv.data;
+                    for grant_object in &grant_objects {
+                        old_role_info.grants.revoke_privileges(
+                            grant_object,
+                            make_bitflags!(UserPrivilegeType::{ Ownership }).into(),
+                        );
+                    }
+                    condition.push(txn_cond_seq(&old_key, Eq, old_seq));
+                    if_then.push(txn_op_put(
+                        &old_key,
+                        serialize_struct(&old_role_info, ErrorCode::IllegalUserInfoFormat, || "")?,
+                    ));
+                }
+            }
+
+            // account_admin has all privilege, no need to grant ownership.
+            if new_role != BUILTIN_ROLE_ACCOUNT_ADMIN {
+                let new_key = self.role_ident(new_role);
+                let SeqV {
+                    seq: new_seq,
+                    data: mut new_role_info,
+                    ..
+                } = self.read_role(new_role, MatchSeq::GE(1)).await?;
+                for object in &objects {
+                    new_role_info.grants.grant_privileges(
+                        &convert_to_grant_obj(object),
+                        make_bitflags!(UserPrivilegeType::{ Ownership }).into(),
+                    );
+                }
+                condition.push(txn_cond_seq(&new_key, Eq, new_seq));
+                if_then.push(txn_op_put(
+                    &new_key,
+                    serialize_struct(&new_role_info, ErrorCode::IllegalUserInfoFormat, || "")?,
+                ));
+            }
+
+            let txn_req = TxnRequest {
+                condition: condition.clone(),
+                if_then: if_then.clone(),
+                else_then: vec![],
+            };
+
+            let tx_reply = self.kv_api.transaction(txn_req.clone()).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                self.invalidate_ownership_cache();
+                for old_role in &changed_roles {
+                    self.invalidate_role_cache(old_role);
+                }
+                self.invalidate_role_cache(new_role);
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("grant_ownership_batch", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn get_ownership(
@@ -433,9 +700,9 @@ impl RoleApi for RoleMgr {
         let mut if_then = vec![txn_op_del(&owner_key)];
         let mut condition = vec![];
 
-        if let Some(role) = role {
-            if let Ok(seqv) = self.get_role(&role.to_owned(), MatchSeq::GE(1)).await {
-                let old_key = self.role_ident(&role);
+        if let Some(role) = &role {
+            if let Ok(seqv) = self.read_role(role, MatchSeq::GE(1)).await {
+                let old_key = self.role_ident(role);
                 let grant_object = convert_to_grant_obj(object);
                 let old_seq = seqv.seq;
                 let mut old_role_info = seqv.data;
@@ -465,6 +732,10 @@ impl RoleApi for RoleMgr {
             let (succ, _) = txn_reply_to_api_result(tx_reply)?;
 
             if succ {
+                self.invalidate_ownership_cache();
+                if let Some(role) = &role {
+                    self.invalidate_role_cache(role);
+                }
                 return Ok(());
             }
         }
@@ -487,8 +758,98 @@ impl RoleApi for RoleMgr {
         res.removed_or_else(|_p| {
             ErrorCode::UnknownRole(format!("Role '{}' does not exist.", role))
         })?;
+        self.invalidate_role_cache(&role);
         Ok(())
     }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_role_cascade(&self, role: &str) -> databend_common_exception::Result<()> {
+        let role_key = self.role_ident(role);
+        let user_prefix =
+            TenantUserIdent::new_user_host(self.tenant.clone(), "dummy", "dummy").tenant_prefix();
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+            let mut condition = vec![];
+            let mut if_then = vec![];
+            let mut updated_roles = vec![];
+
+            // Revoke the role from the roles which inherit it.
+            for seqv in self.get_roles().await? {
+                let mut role_info = seqv.data;
+                if role_info.name != role && role_info.grants.roles().iter().any(|r| r == role) {
+                    role_info.grants.revoke_role(&role.to_string());
+                    let key = self.role_ident(&role_info.name);
+                    condition.push(txn_cond_seq(&key, Eq, seqv.seq));
+                    if_then.push(txn_op_put(
+                        &key,
+                        serialize_struct(&role_info, ErrorCode::IllegalUserInfoFormat, || "")?,
+                    ));
+                    updated_roles.push(role_info.name);
+                }
+            }
+
+            // Revoke the role from the users which are granted it.
+            for (_key, val) in self.kv_api.prefix_list_kv(&user_prefix).await? {
+                let mut user_info: UserInfo =
+                    deserialize_struct(&val.data, ErrorCode::IllegalUserInfoFormat, || "")?;
+                if user_info.grants.roles().iter().any(|r| r == role) {
+                    user_info.grants.revoke_role(&role.to_string());
+                    let key = TenantUserIdent::new_user_host(
+                        self.tenant.clone(),
+                        &user_info.name,
+                        &user_info.hostname,
+                    );
+                    condition.push(txn_cond_seq(&key, Eq, val.seq));
+                    if_then.push(txn_op_put(
+                        &key,
+                        serialize_struct(&user_info, ErrorCode::IllegalUserInfoFormat, || "")?,
+                    ));
+                }
+            }
+
+            // Drop the role itself, or make sure it is still absent.
+            match self.kv_api.get_kv(&role_key.to_string_key()).await? {
+                Some(seqv) => {
+                    condition.push(txn_cond_seq(&role_key, Eq, seqv.seq));
+                    if_then.push(txn_op_del(&role_key));
+                }
+                None => {
+                    if if_then.is_empty() {
+                        return Ok(());
+                    }
+                    condition.push(txn_cond_seq(&role_key, Eq, 0));
+                }
+            }
+
+            let txn_req = TxnRequest {
+                condition: condition.clone(),
+                if_then: if_then.clone(),
+                else_then: vec![],
+            };
+
+            let tx_reply = self.kv_api.transaction(txn_req.clone()).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+            debug!(
+                succ = succ;
+                "drop_role_cascade"
+            );
+
+            if succ {
+                for updated_role in &updated_roles {
+                    self.invalidate_role_cache(updated_role);
+                }
+                self.invalidate_role_cache(role);
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("drop_role_cascade", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
 }
 
 fn convert_to_grant_obj(owner_obj: &OwnershipObject) -> GrantObject {
@@ -504,5 +865,6 @@ fn convert_to_grant_obj(owner_obj: &OwnershipObject) -> GrantObject {
         } => GrantObject::TableById(catalog_name.to_string(), *db_id, *table_id),
         OwnershipObject::Stage { name } => GrantObject::Stage(name.to_string()),
         OwnershipObject::UDF { name } => GrantObject::UDF(name.to_string()),
+        OwnershipObject::Sequence { name } => GrantObject::Sequence(name.to_string()),
     }
 }
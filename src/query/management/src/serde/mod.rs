@@ -16,6 +16,7 @@ mod pb_serde;
 mod quota;
 
 pub use pb_serde::check_and_upgrade_to_pb;
+pub use pb_serde::check_and_upgrade_to_pb_batch;
 pub use pb_serde::deserialize_struct;
 pub use pb_serde::serialize_struct;
 pub use quota::Quota;
@@ -17,8 +17,10 @@ use std::fmt::Display;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ToErrorCode;
+use databend_common_meta_api::reply::txn_reply_to_api_result;
 use databend_common_meta_kvapi::kvapi;
 use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::txn_op_response::Response;
 use databend_common_meta_types::InvalidReply;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::MetaError;
@@ -26,6 +28,9 @@ use databend_common_meta_types::MetaNetworkError;
 use databend_common_meta_types::Operation;
 use databend_common_meta_types::SeqV;
 use databend_common_meta_types::SeqValue;
+use databend_common_meta_types::TxnCondition;
+use databend_common_meta_types::TxnOp;
+use databend_common_meta_types::TxnRequest;
 use databend_common_proto_conv::FromToProto;
 
 use crate::serde::Quota;
@@ -118,3 +123,90 @@ where
 
     Ok(SeqV::new(seq, data))
 }
+
+/// Same as [`check_and_upgrade_to_pb`], but for the records of a listing.
+///
+/// Instead of one write per record, all the records to upgrade are written back in one transaction.
+/// If any of them is changed concurrently, the transaction fails and nothing is upgraded:
+/// the converted data is still returned, and the upgrade is left to a later listing.
+pub async fn check_and_upgrade_to_pb_batch<T>(
+    quota: &mut Quota,
+    items: Vec<(String, SeqV)>,
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+) -> Result<Vec<SeqV<T>>>
+where
+    T: FromToProto + serde::de::DeserializeOwned + 'static,
+{
+    let mut res = Vec::with_capacity(items.len());
+    // (index in `res`, key, seq) of the records to upgrade.
+    let mut to_upgrade = vec![];
+    let mut condition = vec![];
+    let mut if_then = vec![];
+
+    for (key, seq_value) in items {
+        let deserialize_result = databend_common_meta_api::deserialize_struct(&seq_value.data);
+
+        let err = match deserialize_result {
+            Ok(data) => {
+                res.push(SeqV::new(seq_value.seq, data));
+                continue;
+            }
+            Err(err) => err,
+        };
+
+        log::debug!("deserialize as pb err: {}, rollback to use serde json", err);
+
+        let data = serde_json::from_slice::<T>(&seq_value.data).map_err(|e| {
+            let inv_reply = InvalidReply::new("non json non pb data", &e);
+            MetaError::from(MetaNetworkError::from(inv_reply))
+        })?;
+
+        if !quota.is_used_up() {
+            quota.decrement();
+
+            let value =
+                databend_common_meta_api::serialize_struct(&data).map_err(MetaError::from)?;
+            condition.push(TxnCondition::eq_seq(&key, seq_value.seq));
+            if_then.push(TxnOp::put(&key, value));
+            to_upgrade.push((res.len(), key, seq_value.seq));
+        }
+
+        res.push(SeqV::new(seq_value.seq, data));
+    }
+
+    if to_upgrade.is_empty() {
+        return Ok(res);
+    }
+
+    // Read back the upgraded records in the same transaction to get their new seq.
+    for (_, key, _) in &to_upgrade {
+        if_then.push(TxnOp::get(key));
+    }
+
+    let txn_req = TxnRequest {
+        condition,
+        if_then,
+        else_then: vec![],
+    };
+    let tx_reply = kv_api.transaction(txn_req).await?;
+    let (succ, responses) = txn_reply_to_api_result(tx_reply)?;
+
+    if !succ {
+        log::info!(
+            "upgrade {} records to pb is skipped, they are changed concurrently",
+            to_upgrade.len()
+        );
+        return Ok(res);
+    }
+
+    let get_responses = &responses[responses.len() - to_upgrade.len()..];
+    for ((index, _, _), resp) in to_upgrade.iter().zip(get_responses) {
+        if let Some(Response::Get(get)) = &resp.response {
+            if let Some(v) = &get.value {
+                res[*index].seq = v.seq;
+            }
+        }
+    }
+
+    Ok(res)
+}
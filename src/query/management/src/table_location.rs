@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::Operation;
+
+pub static TABLE_LOCATION_API_KEY_PREFIX: &str = "__fd_table_location";
+
+/// Index of the tables having their own storage location, i.e. the tables created
+/// with an external location and the attached tables.
+///
+/// It finds the tables over a location without listing all the tables:
+/// "__fd_table_location/<tenant>/<location>/<table_id>", where the location is the
+/// storage params without root followed by the root path, escaped as one segment.
+/// As the escaping is done byte by byte, the locations inside a location share its
+/// escaped prefix.
+///
+/// Entries are not removed when a table is dropped, the callers check the table ids
+/// they get against the table metas.
+pub struct TableLocationMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant_prefix: String,
+}
+
+impl TableLocationMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &Tenant,
+    ) -> Result<Self> {
+        Ok(TableLocationMgr {
+            kv_api,
+            tenant_prefix: format!(
+                "{}/{}",
+                TABLE_LOCATION_API_KEY_PREFIX,
+                escape_for_key(tenant.tenant_name())?
+            ),
+        })
+    }
+
+    /// Record that `table_id` stores its data at `params`.
+    #[async_backtrace::framed]
+    pub async fn add_table(&self, params: &StorageParams, table_id: u64) -> Result<()> {
+        let (storage, root) = split_location(params);
+        let key = format!("{}{}", self.location_key(&storage, &root)?, table_id);
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                Operation::Update(vec![]),
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// Remove the record of `table_id` at `params`, e.g. once the table is gone.
+    #[async_backtrace::framed]
+    pub async fn remove_table(&self, params: &StorageParams, table_id: u64) -> Result<()> {
+        let (storage, root) = split_location(params);
+        let key = format!("{}{}", self.location_key(&storage, &root)?, table_id);
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// The ids of the tables stored at `params`, inside it, or at a location containing it.
+    ///
+    /// It takes one prefix listing for the location and its sub locations, and one for
+    /// each parent location, so the cost is bounded by the depth of the root path.
+    #[async_backtrace::framed]
+    pub async fn list_overlapping_tables(&self, params: &StorageParams) -> Result<Vec<u64>> {
+        let (storage, root) = split_location(params);
+
+        // The location itself and the locations inside it.
+        let mut prefixes = vec![
+            self.location_key(&storage, &root)?,
+            self.sub_locations_prefix(&storage, &root)?,
+        ];
+        // The parent locations, from the storage root down.
+        if !root.is_empty() {
+            let segments = root.split('/').collect::<Vec<_>>();
+            for depth in 0..segments.len() {
+                prefixes.push(self.location_key(&storage, &segments[..depth].join("/"))?);
+            }
+        }
+
+        let mut table_ids = vec![];
+        for prefix in prefixes {
+            for (key, _) in self.kv_api.prefix_list_kv(&prefix).await? {
+                let table_id = key.rsplit('/').next().unwrap_or_default();
+                let table_id = table_id.parse::<u64>().map_err(|e| {
+                    ErrorCode::Internal(format!("invalid table location key {}: {}", key, e))
+                })?;
+                table_ids.push(table_id);
+            }
+        }
+        table_ids.sort();
+        table_ids.dedup();
+        Ok(table_ids)
+    }
+
+    fn location(storage: &str, root: &str) -> String {
+        if root.is_empty() {
+            storage.to_string()
+        } else {
+            format!("{}/{}", storage, root)
+        }
+    }
+
+    /// The key prefix of the tables stored exactly at the location.
+    fn location_key(&self, storage: &str, root: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/",
+            self.tenant_prefix,
+            escape_for_key(&Self::location(storage, root))?
+        ))
+    }
+
+    /// The key prefix of the tables stored inside the location.
+    fn sub_locations_prefix(&self, storage: &str, root: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.tenant_prefix,
+            escape_for_key(&format!("{}/", Self::location(storage, root)))?
+        ))
+    }
+}
+
+/// Split the location into the storage params without root, and the root path.
+fn split_location(params: &StorageParams) -> (String, String) {
+    let storage = params.clone().map_root(|_| String::new()).to_string();
+    let root = params.root().trim_matches('/').to_string();
+    (storage, root)
+}
@@ -18,5 +18,6 @@ mod cluster;
 mod role;
 mod setting;
 mod stage;
+mod table_location;
 mod udf;
 mod user;
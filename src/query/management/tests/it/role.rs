@@ -63,6 +63,442 @@ mod add {
     }
 }
 
+mod upgrade_in_batch {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use databend_common_meta_app::principal::RoleInfo;
+    use databend_common_meta_kvapi::kvapi;
+    use databend_common_meta_kvapi::kvapi::KVApi;
+    use databend_common_meta_kvapi::kvapi::KVStream;
+    use databend_common_meta_kvapi::kvapi::UpsertKVReply;
+    use databend_common_meta_types::MetaError;
+    use databend_common_meta_types::Operation;
+    use databend_common_meta_types::TxnReply;
+    use databend_common_meta_types::TxnRequest;
+
+    use super::*;
+
+    /// Counts the writes issued to the underlying kv store.
+    struct CountingKV {
+        inner: Arc<MetaEmbedded>,
+        upserts: AtomicUsize,
+        transactions: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl kvapi::KVApi for CountingKV {
+        type Error = MetaError;
+
+        async fn upsert_kv(&self, req: UpsertKVReq) -> Result<UpsertKVReply, MetaError> {
+            self.upserts.fetch_add(1, Ordering::SeqCst);
+            self.inner.upsert_kv(req).await
+        }
+
+        async fn get_kv_stream(&self, keys: &[String]) -> Result<KVStream<MetaError>, MetaError> {
+            self.inner.get_kv_stream(keys).await
+        }
+
+        async fn list_kv(&self, prefix: &str) -> Result<KVStream<MetaError>, MetaError> {
+            self.inner.list_kv(prefix).await
+        }
+
+        async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
+            self.transactions.fetch_add(1, Ordering::SeqCst);
+            self.inner.transaction(txn).await
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_get_roles_upgrade_in_one_txn() -> databend_common_exception::Result<()> {
+        let (kv_api, _) = new_role_api().await?;
+
+        // legacy json records
+        for name in ["role1", "role2", "role3"] {
+            let v = serde_json::to_vec(&RoleInfo::new(name))?;
+            kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    make_role_key(name),
+                    MatchSeq::Exact(0),
+                    Operation::Update(v),
+                    None,
+                ))
+                .await?;
+        }
+
+        let counting = Arc::new(CountingKV {
+            inner: kv_api.clone(),
+            upserts: AtomicUsize::new(0),
+            transactions: AtomicUsize::new(0),
+        });
+        let role_api = RoleMgr::create(counting.clone(), &Tenant::new_literal("admin"));
+
+        let roles = role_api.get_roles().await?;
+        let names = roles
+            .iter()
+            .map(|r| r.data.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["role1", "role2", "role3"], names);
+
+        // all the records are upgraded in a single transaction
+        assert_eq!(0, counting.upserts.load(Ordering::SeqCst));
+        assert_eq!(1, counting.transactions.load(Ordering::SeqCst));
+
+        // the returned seq is the one after upgrading
+        for role in &roles {
+            let got = kv_api
+                .get_kv(&make_role_key(&role.data.name))
+                .await?
+                .unwrap();
+            assert_eq!(got.seq, role.seq);
+        }
+
+        // nothing to upgrade any more
+        let _ = role_api.get_roles().await?;
+        assert_eq!(1, counting.transactions.load(Ordering::SeqCst));
+
+        Ok(())
+    }
+}
+
+mod get_roles_page {
+    use databend_common_meta_app::principal::RoleInfo;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_get_roles_page() -> databend_common_exception::Result<()> {
+        let (_kv_api, role_api) = new_role_api().await?;
+
+        let mut expected = (0..25).map(|i| format!("role{:02}", i)).collect::<Vec<_>>();
+        // add in reverse order, listing is in the order of role name anyway
+        for name in expected.iter().rev() {
+            role_api.add_role(RoleInfo::new(name)).await?;
+        }
+        expected.sort();
+
+        let mut got = vec![];
+        let mut page_sizes = vec![];
+        let mut start_after: Option<String> = None;
+        loop {
+            let page = role_api.get_roles_page(start_after.as_deref(), 10).await?;
+            if page.is_empty() {
+                break;
+            }
+            page_sizes.push(page.len());
+            start_after = page.last().map(|r| r.data.name.clone());
+            got.extend(page.into_iter().map(|r| r.data.name));
+        }
+
+        assert_eq!(vec![10, 10, 5], page_sizes);
+        // no duplicates or omissions
+        assert_eq!(expected, got);
+
+        // start after a role that does not exist
+        let page = role_api.get_roles_page(Some("role10a"), 3).await?;
+        let names = page.into_iter().map(|r| r.data.name).collect::<Vec<_>>();
+        assert_eq!(vec!["role11", "role12", "role13"], names);
+
+        Ok(())
+    }
+}
+
+mod ownership_updated_on {
+    use chrono::Utc;
+    use databend_common_meta_app::principal::OwnershipObject;
+    use databend_common_meta_app::principal::RoleInfo;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_grant_and_transfer_ownership_updated_on() -> databend_common_exception::Result<()>
+    {
+        let (_kv_api, role_api) = new_role_api().await?;
+        role_api.add_role(RoleInfo::new("r1")).await?;
+
+        let object = OwnershipObject::Database {
+            catalog_name: "default".to_string(),
+            db_id: 1,
+        };
+
+        let before_grant = Utc::now();
+        role_api.grant_ownership(&object, "r1").await?;
+        let granted = role_api.get_ownership(&object).await?.unwrap();
+        assert_eq!("r1", granted.role);
+        assert!(granted.updated_on >= before_grant);
+        assert!(granted.updated_on <= Utc::now());
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        role_api.transfer_ownership_to_admin("r1").await?;
+        let transferred = role_api.get_ownership(&object).await?.unwrap();
+        assert_eq!("account_admin", transferred.role);
+        assert!(transferred.updated_on > granted.updated_on);
+
+        Ok(())
+    }
+}
+
+mod grant_ownership_batch {
+    use databend_common_meta_app::principal::GrantObject;
+    use databend_common_meta_app::principal::OwnershipObject;
+    use databend_common_meta_app::principal::RoleInfo;
+    use databend_common_meta_app::principal::UserPrivilegeType;
+
+    use super::*;
+
+    fn table(table_id: u64) -> OwnershipObject {
+        OwnershipObject::Table {
+            catalog_name: "default".to_string(),
+            db_id: 1,
+            table_id,
+        }
+    }
+
+    fn table_grant_object(table_id: u64) -> GrantObject {
+        GrantObject::TableById("default".to_string(), 1, table_id)
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_grant_ownership_batch() -> databend_common_exception::Result<()> {
+        let (_kv_api, role_api) = new_role_api().await?;
+        role_api.add_role(RoleInfo::new("old1")).await?;
+        role_api.add_role(RoleInfo::new("old2")).await?;
+        role_api.add_role(RoleInfo::new("new")).await?;
+
+        // Table 1 and 2 have different old owners, table 3 has no owner.
+        role_api.grant_ownership(&table(1), "old1").await?;
+        role_api.grant_ownership(&table(2), "old2").await?;
+
+        let objects = vec![table(1), table(2), table(3)];
+        role_api.grant_ownership_batch(&objects, "new").await?;
+
+        for object in &objects {
+            let ownership = role_api.get_ownership(object).await?.unwrap();
+            assert_eq!("new", ownership.role);
+        }
+
+        let new_role = role_api
+            .get_role(&"new".to_string(), MatchSeq::GE(1))
+            .await?
+            .data;
+        for table_id in 1..=3 {
+            assert!(
+                new_role
+                    .grants
+                    .verify_privilege(&table_grant_object(table_id), UserPrivilegeType::Ownership)
+            );
+        }
+
+        let old1 = role_api
+            .get_role(&"old1".to_string(), MatchSeq::GE(1))
+            .await?
+            .data;
+        assert!(
+            !old1
+                .grants
+                .verify_privilege(&table_grant_object(1), UserPrivilegeType::Ownership)
+        );
+
+        let old2 = role_api
+            .get_role(&"old2".to_string(), MatchSeq::GE(1))
+            .await?
+            .data;
+        assert!(
+            !old2
+                .grants
+                .verify_privilege(&table_grant_object(2), UserPrivilegeType::Ownership)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_grant_ownership_batch_duplicated_objects() -> databend_common_exception::Result<()>
+    {
+        let (_kv_api, role_api) = new_role_api().await?;
+        role_api.add_role(RoleInfo::new("old")).await?;
+        role_api.add_role(RoleInfo::new("new")).await?;
+        role_api.grant_ownership(&table(1), "old").await?;
+
+        let objects = vec![table(1), table(2), table(1)];
+        role_api.grant_ownership_batch(&objects, "new").await?;
+
+        for table_id in 1..=2 {
+            let ownership = role_api.get_ownership(&table(table_id)).await?.unwrap();
+            assert_eq!("new", ownership.role);
+        }
+
+        let old = role_api
+            .get_role(&"old".to_string(), MatchSeq::GE(1))
+            .await?
+            .data;
+        assert!(
+            !old.grants
+                .verify_privilege(&table_grant_object(1), UserPrivilegeType::Ownership)
+        );
+
+        Ok(())
+    }
+}
+
+mod ownership_cache {
+    use std::time::Duration;
+
+    use databend_common_meta_app::principal::OwnershipObject;
+    use databend_common_meta_app::principal::RoleInfo;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_grant_ownership_invalidates_cache() -> databend_common_exception::Result<()> {
+        let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+        let tenant = Tenant::new_literal("admin");
+        let ttl = Duration::from_secs(60);
+        let cache = Arc::new(OwnershipCache::new());
+        let role_api =
+            RoleMgr::create(test_api.clone(), &tenant).with_ownership_cache(cache.clone(), ttl);
+        role_api.add_role(RoleInfo::new("r1")).await?;
+
+        let object = OwnershipObject::Database {
+            catalog_name: "default".to_string(),
+            db_id: 1,
+        };
+
+        // Populate the cache.
+        assert!(role_api.get_ownerships_cached().await?.is_empty());
+        assert!(cache.get(&tenant, ttl).is_some());
+        // Entries older than the ttl of the reader are not served.
+        assert!(cache.get(&tenant, Duration::ZERO).is_none());
+
+        role_api.grant_ownership(&object, "r1").await?;
+        assert!(cache.get(&tenant, ttl).is_none());
+
+        let ownerships = role_api.get_ownerships_cached().await?;
+        assert_eq!(1, ownerships.len());
+        assert_eq!("r1", ownerships[0].data.role);
+        assert_eq!(object, ownerships[0].data.object);
+        assert_eq!(ownerships, role_api.get_ownerships().await?);
+
+        role_api.revoke_ownership(&object).await?;
+        assert!(role_api.get_ownerships_cached().await?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_fill_after_invalidation_is_dropped() {
+        let tenant = Tenant::new_literal("admin");
+        let ttl = Duration::from_secs(60);
+        let cache = OwnershipCache::new();
+
+        // A listing read before an invalidation must not be cached after it.
+        let generation = cache.generation();
+        cache.invalidate(&tenant);
+        cache.insert(&tenant, generation, vec![]);
+        assert!(cache.get(&tenant, ttl).is_none());
+
+        cache.insert(&tenant, cache.generation(), vec![]);
+        assert!(cache.get(&tenant, ttl).is_some());
+    }
+}
+
+mod role_cache {
+    use std::time::Duration;
+
+    use databend_common_exception::ErrorCode;
+    use databend_common_meta_app::principal::OwnershipObject;
+    use databend_common_meta_app::principal::RoleInfo;
+    use databend_common_meta_kvapi::kvapi::KVApi;
+    use databend_common_meta_types::Operation;
+    use databend_common_meta_types::SeqV;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_get_role_read_through_cache() -> databend_common_exception::Result<()> {
+        let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+        let tenant = Tenant::new_literal("admin");
+        let ttl = Duration::from_secs(60);
+        let cache = Arc::new(RoleCache::new());
+        let role_api =
+            RoleMgr::create(test_api.clone(), &tenant).with_role_cache(cache.clone(), ttl);
+        let role_name = "r1".to_string();
+        role_api.add_role(RoleInfo::new("r1")).await?;
+        assert!(cache.get(&tenant, "r1", ttl).is_none());
+
+        // Populate the cache.
+        let got = role_api.get_role(&role_name, MatchSeq::GE(1)).await?;
+        assert_eq!(Some(got.clone()), cache.get(&tenant, "r1", ttl));
+
+        // Change the role behind the cache's back, repeated reads are served from the cache.
+        let mut changed = RoleInfo::new("r1");
+        changed.grants.grant_role("r2".to_string());
+        let value = serialize_struct(&changed, ErrorCode::IllegalUserInfoFormat, || "")?;
+        test_api
+            .upsert_kv(UpsertKVReq::new(
+                &make_role_key("r1"),
+                MatchSeq::GE(1),
+                Operation::Update(value),
+                None,
+            ))
+            .await?;
+        assert_eq!(got, role_api.get_role(&role_name, MatchSeq::GE(1)).await?);
+        assert_eq!(got, role_api.get_role(&role_name, MatchSeq::GE(1)).await?);
+
+        // A mutation through `RoleMgr` invalidates the entry.
+        role_api
+            .update_role_with(&role_name, MatchSeq::GE(1), |ri| {
+                ri.grants.grant_role("r3".to_string())
+            })
+            .await?;
+        assert!(cache.get(&tenant, "r1", ttl).is_none());
+        let got = role_api.get_role(&role_name, MatchSeq::GE(1)).await?;
+        assert!(got.data.grants.roles().contains(&"r2".to_string()));
+        assert!(got.data.grants.roles().contains(&"r3".to_string()));
+
+        let object = OwnershipObject::UDF {
+            name: "f1".to_string(),
+        };
+        role_api.grant_ownership(&object, "r1").await?;
+        assert!(cache.get(&tenant, "r1", ttl).is_none());
+        assert_eq!(
+            role_api.get_role(&role_name, MatchSeq::GE(1)).await?,
+            role_api.get_role(&role_name, MatchSeq::GE(1)).await?
+        );
+
+        role_api
+            .drop_role(role_name.clone(), MatchSeq::GE(1))
+            .await?;
+        assert!(cache.get(&tenant, "r1", ttl).is_none());
+        let res = role_api.get_role(&role_name, MatchSeq::GE(1)).await;
+        assert_eq!(ErrorCode::UNKNOWN_ROLE, res.unwrap_err().code());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_fill_after_invalidation_is_dropped() {
+        let tenant = Tenant::new_literal("admin");
+        let ttl = Duration::from_secs(60);
+        let cache = RoleCache::new();
+
+        // A role read before an invalidation must not be cached after it.
+        let generation = cache.generation();
+        cache.invalidate(&tenant, "r1");
+        cache.insert(&tenant, "r1", generation, SeqV::new(1, RoleInfo::new("r1")));
+        assert!(cache.get(&tenant, "r1", ttl).is_none());
+
+        cache.insert(
+            &tenant,
+            "r1",
+            cache.generation(),
+            SeqV::new(1, RoleInfo::new("r1")),
+        );
+        assert!(cache.get(&tenant, "r1", ttl).is_some());
+    }
+}
+
 async fn new_role_api() -> databend_common_exception::Result<(Arc<MetaEmbedded>, RoleMgr)> {
     let test_api = Arc::new(MetaEmbedded::new_temp().await?);
     let tenant = Tenant::new_literal("admin");
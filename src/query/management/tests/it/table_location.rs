@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_embedded::MetaEmbedded;
+use minitrace::func_name;
+
+fn fs(root: &str) -> StorageParams {
+    StorageParams::Fs(StorageFsConfig {
+        root: root.to_string(),
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_list_overlapping_tables() -> Result<()> {
+    let mgr = new_table_location_api().await?;
+
+    mgr.add_table(&fs("/data/a"), 1).await?;
+    mgr.add_table(&fs("/data/a/1/2/"), 2).await?;
+    mgr.add_table(&fs("/data/ab"), 3).await?;
+    mgr.add_table(&fs("/other"), 4).await?;
+
+    // The location itself, the locations inside it and the ones containing it.
+    assert_eq!(
+        vec![1, 2],
+        mgr.list_overlapping_tables(&fs("/data/a")).await?
+    );
+    assert_eq!(
+        vec![1, 2],
+        mgr.list_overlapping_tables(&fs("/data/a/1")).await?
+    );
+    assert_eq!(
+        vec![1],
+        mgr.list_overlapping_tables(&fs("/data/a/3")).await?
+    );
+    assert_eq!(
+        vec![1, 2, 3],
+        mgr.list_overlapping_tables(&fs("/data/")).await?
+    );
+    assert_eq!(
+        vec![1, 2, 3, 4],
+        mgr.list_overlapping_tables(&fs("/")).await?
+    );
+    assert!(mgr.list_overlapping_tables(&fs("/new")).await?.is_empty());
+
+    mgr.remove_table(&fs("/data/a/1/2"), 2).await?;
+    assert_eq!(
+        vec![1],
+        mgr.list_overlapping_tables(&fs("/data/a/1")).await?
+    );
+
+    Ok(())
+}
+
+async fn new_table_location_api() -> Result<TableLocationMgr> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    TableLocationMgr::create(
+        test_api,
+        &Tenant::new_or_err("databend_query", func_name!()).unwrap(),
+    )
+}
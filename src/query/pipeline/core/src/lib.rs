@@ -35,6 +35,7 @@ pub use pipe::SinkPipeBuilder;
 pub use pipe::SourcePipeBuilder;
 pub use pipe::TransformPipeBuilder;
 pub use pipeline::query_spill_prefix;
+pub use pipeline::query_spill_prefix_with_path;
 pub use pipeline::DynTransformBuilder;
 pub use pipeline::Pipeline;
 pub use processors::PlanProfile;
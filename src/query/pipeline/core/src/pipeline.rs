@@ -534,8 +534,17 @@ impl Drop for Pipeline {
 }
 
 pub fn query_spill_prefix(tenant: &str, query_id: &str) -> String {
+    query_spill_prefix_with_path("", tenant, query_id)
+}
+
+/// Spill prefix under `spill_path`, falls back to `_query_spill` if `spill_path` is empty.
+pub fn query_spill_prefix_with_path(spill_path: &str, tenant: &str, query_id: &str) -> String {
+    let spill_path = match spill_path.trim_matches('/') {
+        "" => "_query_spill",
+        path => path,
+    };
     match query_id.is_empty() {
-        true => format!("_query_spill/{}", tenant),
-        false => format!("_query_spill/{}/{}", tenant, query_id),
+        true => format!("{}/{}", spill_path, tenant),
+        false => format!("{}/{}/{}", spill_path, tenant, query_id),
     }
 }
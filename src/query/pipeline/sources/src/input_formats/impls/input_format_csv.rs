@@ -201,14 +201,20 @@ impl InputFormatTextBase for InputFormatCSV {
         } else {
             Some(csv_params.escape.as_bytes()[0])
         };
+        // AUTO is only sniffed by the stage reader, CRLF accepts both `\r\n` and `\n`
+        let terminator = if csv_params.is_auto_record_delimiter() {
+            csv_core::Terminator::CRLF
+        } else {
+            match csv_params.record_delimiter.as_str().try_into()? {
+                RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
+                RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
+            }
+        };
         let reader = csv_core::ReaderBuilder::new()
             .delimiter(csv_params.field_delimiter.as_bytes()[0])
             .quote(csv_params.quote.as_bytes()[0])
             .escape(escape)
-            .terminator(match csv_params.record_delimiter.as_str().try_into()? {
-                RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
-                RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
-            })
+            .terminator(terminator)
             .build();
         let projection = ctx.projection.clone();
         let max_fields = match &projection {
@@ -218,12 +224,14 @@ impl InputFormatTextBase for InputFormatCSV {
         Ok(CsvReaderState {
             common: AligningStateCommon::create(split_info, false, csv_params.headers as usize),
             error_on_column_count_mismatch: csv_params.error_on_column_count_mismatch,
+            skip_blank_lines: csv_params.skip_blank_lines,
             ctx: ctx.clone(),
             split_info: split_info.clone(),
             reader,
             out: vec![],
             field_ends: vec![0; max_fields],
             n_end: 0,
+            blank_record: true,
             num_fields: ctx.schema.num_fields(),
             projection,
         })
@@ -276,6 +284,7 @@ impl InputFormatTextBase for InputFormatCSV {
 pub struct CsvReaderState {
     common: AligningStateCommon,
     error_on_column_count_mismatch: bool,
+    skip_blank_lines: bool,
     #[allow(unused)]
     ctx: Arc<InputContext>,
     split_info: Arc<SplitInfo>,
@@ -289,6 +298,8 @@ pub struct CsvReaderState {
     // flush to RowBatch when a complete row is read
     pub field_ends: Vec<usize>,
     pub n_end: usize,
+    // whether the output of the current (maybe partial) row is whitespace only so far
+    blank_record: bool,
 
     num_fields: usize,
     projection: Option<Vec<usize>>,
@@ -313,6 +324,7 @@ impl CsvReaderState {
         self.n_end += n_end;
         // shadow the n_end return from reader to avoid misuse
         let n_end = self.n_end;
+        self.blank_record &= output[..n_out].iter().all(u8::is_ascii_whitespace);
 
         match result {
             ReadRecordResult::InputEmpty => {
@@ -326,8 +338,11 @@ impl CsvReaderState {
             ReadRecordResult::OutputFull => Err(self.error_output_full()),
             ReadRecordResult::OutputEndsFull => Err(self.error_output_ends_full()),
             ReadRecordResult::Record => {
+                let is_blank = mem::replace(&mut self.blank_record, true) && n_end <= 1;
                 let output = {
-                    if self.projection.is_some() {
+                    if self.skip_blank_lines && is_blank {
+                        ReadRecordOutput::RecordSkipped
+                    } else if self.projection.is_some() {
                         // select $1, $2, $3 ..  from csv, not check num of fields here
 
                         ReadRecordOutput::Record {
@@ -409,7 +424,7 @@ impl AligningStateTextBased for CsvReaderState {
         let mut buf_out_pos = 0usize;
         let mut buf_out_row_end: usize = 0;
 
-        let last_batch_remain_len = self.out.len();
+        let mut last_batch_remain_len = self.out.len();
 
         let mut row_batch = RowBatch {
             data: vec![],
@@ -440,7 +455,14 @@ impl AligningStateTextBased for CsvReaderState {
                 ReadRecordOutput::PartialRecord { bytes } => {
                     buf_out_pos += bytes;
                 }
-                _ => {}
+                ReadRecordOutput::RecordSkipped => {
+                    // drop what is decoded for the skipped row
+                    buf_out_pos = buf_out_row_end;
+                    if row_batch.row_ends.is_empty() {
+                        self.out.clear();
+                        last_batch_remain_len = 0;
+                    }
+                }
             }
         }
 
@@ -118,7 +118,7 @@ impl InputFormatNDJson {
                                         column_index,
                                         column_name: field.name().to_owned(),
                                         column_type: field.data_type.to_string(),
-                                        decode_error: "null value is not allowed for non-nullable field, when NULL_FIELDS_AS=NULL".to_owned(),
+                                        decode_error: "null value is not allowed for non-nullable field, when NULL_FIELD_AS=NULL (use NULL_FIELD_AS=FIELD_DEFAULT to load the field default instead)".to_owned(),
                                         column_data: "null".to_owned(),
                                     });
                             }
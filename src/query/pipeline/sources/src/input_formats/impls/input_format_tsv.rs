@@ -216,15 +216,18 @@ impl InputFormatTextBase for InputFormatTSV {
             .as_any()
             .downcast_ref::<SeparatedTextDecoder>()
             .expect("must success");
-        let field_delimiter =
-            TsvFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params)
-                .field_delimiter
-                .as_bytes()[0];
+        let tsv_params = TsvFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params);
+        let field_delimiter = tsv_params.field_delimiter.as_bytes()[0];
+        let skip_blank_lines = tsv_params.skip_blank_lines;
         let schema = &builder.ctx.schema;
         let columns = &mut builder.mutable_columns;
         let mut start = 0usize;
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end]; // include \n
+            if skip_blank_lines && buf.iter().all(u8::is_ascii_whitespace) {
+                start = *end;
+                continue;
+            }
             if let Err(e) = Self::read_row(
                 field_delimiter,
                 field_decoder,
@@ -183,6 +183,7 @@ impl InputContext {
             FileFormatOptionsExt::create_from_settings(&settings, is_select)?;
         file_format_options_ext.disable_variant_check =
             stage_info.copy_options.disable_variant_check;
+        file_format_options_ext.apply_file_format_time_zone(&stage_info.file_format_params)?;
         let on_error_mode = stage_info.copy_options.on_error.clone();
         let plan = Box::new(CopyIntoPlan { stage_info });
         let file_format_params = plan.stage_info.file_format_params.clone();
@@ -282,12 +283,14 @@ impl InputContext {
         on_error_mode: OnErrorMode,
     ) -> Result<Self> {
         let read_batch_size = settings.get_input_read_buffer_size()? as usize;
-        let file_format_options_ext = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+        let mut file_format_options_ext =
+            FileFormatOptionsExt::create_from_settings(&settings, false)?;
+        file_format_options_ext.apply_file_format_time_zone(&file_format_params)?;
         let format = Self::get_input_format(&file_format_params)?;
 
         let plan = StreamPlan {
             is_multi_part,
-            compression: file_format_params.compression(),
+            compression: Self::file_compression(&file_format_params),
         };
 
         Ok(InputContext {
@@ -325,12 +328,20 @@ impl InputContext {
 
     pub fn get_compression_alg(&self, path: &str) -> Result<Option<CompressAlgorithm>> {
         let opt = match &self.plan {
-            InputPlan::CopyInto(p) => p.stage_info.file_format_params.compression(),
+            InputPlan::CopyInto(p) => Self::file_compression(&p.stage_info.file_format_params),
             InputPlan::StreamingLoad(p) => p.compression,
         };
         Self::get_compression_alg_copy(opt, path)
     }
 
+    /// Compression of the whole file, parquet only compresses the pages inside.
+    fn file_compression(file_format_params: &FileFormatParams) -> StageFileCompression {
+        match file_format_params {
+            FileFormatParams::Parquet(_) => StageFileCompression::None,
+            _ => file_format_params.compression(),
+        }
+    }
+
     pub fn get_compression_alg_copy(
         compress_option: StageFileCompression,
         path: &str,
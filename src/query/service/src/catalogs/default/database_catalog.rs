@@ -76,10 +76,13 @@ use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
+use databend_common_meta_app::schema::RenameSequenceReply;
+use databend_common_meta_app::schema::RenameSequenceReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -96,6 +99,8 @@ use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReply;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReq;
 use databend_common_meta_app::schema::UpsertTableOptionReply;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_app::schema::VirtualColumnMeta;
@@ -103,6 +108,10 @@ use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_app::KeyWithTenant;
 use databend_common_meta_types::MetaId;
 use databend_common_meta_types::SeqV;
+use futures::stream;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use futures::TryStreamExt;
 use log::info;
 
 use crate::catalogs::default::ImmutableCatalog;
@@ -246,6 +255,24 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.rename_database(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn upsert_database_option(
+        &self,
+        req: UpsertDatabaseOptionReq,
+    ) -> Result<UpsertDatabaseOptionReply> {
+        info!("Upsert database option from req:{:?}", req);
+
+        if self
+            .immutable_catalog
+            .exists_database(req.name_ident.tenant(), req.name_ident.database_name())
+            .await?
+        {
+            return self.immutable_catalog.upsert_database_option(req).await;
+        }
+
+        self.mutable_catalog.upsert_database_option(req).await
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let res = self.immutable_catalog.get_table_by_info(table_info);
         match res {
@@ -271,6 +298,41 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn mget_table_metas_by_ids(
+        &self,
+        table_ids: &[MetaId],
+    ) -> Result<Vec<Option<(TableIdent, Arc<TableMeta>)>>> {
+        let mut table_metas = Vec::with_capacity(table_ids.len());
+        // Positions and ids of the tables that are not system tables
+        let mut mut_positions = Vec::new();
+        let mut mut_table_ids = Vec::new();
+        for (i, table_id) in table_ids.iter().enumerate() {
+            match self.immutable_catalog.get_table_meta_by_id(*table_id).await {
+                Ok(Some(seq_meta)) => table_metas.push(Some((
+                    TableIdent::new(*table_id, seq_meta.seq),
+                    Arc::new(seq_meta.data),
+                ))),
+                _ => {
+                    table_metas.push(None);
+                    mut_positions.push(i);
+                    mut_table_ids.push(*table_id);
+                }
+            }
+        }
+
+        // Fetch the remaining table metas in one batch
+        let other = self
+            .mutable_catalog
+            .mget_table_metas_by_ids(&mut_table_ids)
+            .await?;
+        for (i, table_meta) in mut_positions.into_iter().zip(other) {
+            table_metas[i] = table_meta;
+        }
+
+        Ok(table_metas)
+    }
+
     #[async_backtrace::framed]
     async fn mget_table_names_by_ids(
         &self,
@@ -403,6 +465,23 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
+    fn list_tables_stream<'a>(
+        &'a self,
+        tenant: &'a Tenant,
+        db_name: &'a str,
+    ) -> BoxStream<'a, Result<Arc<dyn Table>>> {
+        stream::once(self.immutable_catalog.get_database(tenant, db_name))
+            .map(move |r| match r {
+                Ok(_) => Ok(self.immutable_catalog.list_tables_stream(tenant, db_name)),
+                Err(e) if e.code() == ErrorCode::UNKNOWN_DATABASE => {
+                    Ok(self.mutable_catalog.list_tables_stream(tenant, db_name))
+                }
+                Err(e) => Err(e),
+            })
+            .try_flatten()
+            .boxed()
+    }
+
     #[async_backtrace::framed]
     async fn list_tables_history(
         &self,
@@ -725,4 +804,8 @@ impl Catalog for DatabaseCatalog {
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
         self.mutable_catalog.drop_sequence(req).await
     }
+
+    async fn rename_sequence(&self, req: RenameSequenceReq) -> Result<RenameSequenceReply> {
+        self.mutable_catalog.rename_sequence(req).await
+    }
 }
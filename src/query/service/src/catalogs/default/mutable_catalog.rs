@@ -22,6 +22,7 @@ use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_meta_api::SchemaApi;
 use databend_common_meta_api::SequenceApi;
+use databend_common_meta_api::DEFAULT_MGET_SIZE;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CreateDatabaseReply;
@@ -81,10 +82,13 @@ use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
+use databend_common_meta_app::schema::RenameSequenceReply;
+use databend_common_meta_app::schema::RenameSequenceReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -101,6 +105,8 @@ use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReply;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReq;
 use databend_common_meta_app::schema::UpsertTableOptionReply;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_app::schema::VirtualColumnMeta;
@@ -109,6 +115,10 @@ use databend_common_meta_app::KeyWithTenant;
 use databend_common_meta_store::MetaStoreProvider;
 use databend_common_meta_types::MetaId;
 use databend_common_meta_types::SeqV;
+use futures::stream;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use futures::TryStreamExt;
 use log::info;
 use minitrace::func_name;
 
@@ -196,6 +206,77 @@ impl MutableCatalog {
             .database_factory
             .build_database_by_engine(ctx, db_info)
     }
+
+    /// Stream the tables of a database, the metas of the tables are fetched in batches of
+    /// [`DEFAULT_MGET_SIZE`] as the stream is polled.
+    async fn table_batches<'a>(
+        &'a self,
+        tenant: &'a Tenant,
+        db_name: &'a str,
+    ) -> Result<BoxStream<'a, Result<Arc<dyn Table>>>> {
+        let db = self.get_database(tenant, db_name).await?;
+
+        // The tables of a shared database are listed by the share, not by name.
+        if db.get_db_info().meta.from_share.is_some() {
+            let tables = db.list_tables().await?;
+            return Ok(stream::iter(tables.into_iter().map(Ok)).boxed());
+        }
+
+        let db_id = db.get_db_info().ident.db_id;
+        let names_and_ids = self.ctx.meta.list_table_names_and_ids(db_id).await?;
+        let batches = names_and_ids
+            .chunks(DEFAULT_MGET_SIZE)
+            .map(|batch| batch.to_vec())
+            .collect::<Vec<_>>();
+
+        Ok(stream::iter(batches)
+            .then(move |batch| self.load_table_batch(db.clone(), batch))
+            .map_ok(|tables| stream::iter(tables.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed())
+    }
+
+    async fn load_table_batch(
+        &self,
+        db: Arc<dyn Database>,
+        batch: Vec<(String, MetaId)>,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        let table_ids = batch.iter().map(|(_, id)| *id).collect::<Vec<_>>();
+        let seq_metas = self.ctx.meta.mget_table_metas_by_ids(&table_ids).await?;
+
+        let mut tables = Vec::with_capacity(batch.len());
+        for ((table_name, table_id), seq_meta) in batch.into_iter().zip(seq_metas) {
+            // The table may be dropped after its name is listed.
+            let Some(seq_meta) = seq_meta else {
+                continue;
+            };
+
+            let table_info = Arc::new(TableInfo {
+                ident: TableIdent {
+                    table_id,
+                    seq: seq_meta.seq,
+                },
+                desc: format!("'{}'.'{}'", db.get_db_name(), table_name),
+                name: table_name,
+                meta: seq_meta.data,
+                tenant: db.get_tenant().tenant_name().to_string(),
+                db_type: DatabaseType::NormalDB,
+            });
+            let table_info = self
+                .ctx
+                .storage_factory
+                .refresh_table_info(table_info.clone())
+                .await
+                .map_err(|err| {
+                    err.add_message_back(format!(
+                        "(while refresh table info on {})",
+                        table_info.name
+                    ))
+                })?;
+            tables.push(db.get_table_by_info(table_info.as_ref())?);
+        }
+        Ok(tables)
+    }
 }
 
 #[async_trait::async_trait]
@@ -357,6 +438,15 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    #[async_backtrace::framed]
+    async fn upsert_database_option(
+        &self,
+        req: UpsertDatabaseOptionReq,
+    ) -> Result<UpsertDatabaseOptionReply> {
+        let res = self.ctx.meta.upsert_database_option(req).await?;
+        Ok(res)
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let storage = self.ctx.storage_factory.clone();
         storage.get_table(table_info)
@@ -371,6 +461,21 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    async fn mget_table_metas_by_ids(
+        &self,
+        table_ids: &[MetaId],
+    ) -> Result<Vec<Option<(TableIdent, Arc<TableMeta>)>>> {
+        let seq_metas = self.ctx.meta.mget_table_metas_by_ids(table_ids).await?;
+        let table_metas = table_ids
+            .iter()
+            .zip(seq_metas)
+            .map(|(table_id, seq_meta)| {
+                seq_meta.map(|v| (TableIdent::new(*table_id, v.seq), Arc::new(v.data)))
+            })
+            .collect();
+        Ok(table_metas)
+    }
+
     async fn mget_table_names_by_ids(
         &self,
         _tenant: &Tenant,
@@ -412,6 +517,16 @@ impl Catalog for MutableCatalog {
         db.list_tables().await
     }
 
+    fn list_tables_stream<'a>(
+        &'a self,
+        tenant: &'a Tenant,
+        db_name: &'a str,
+    ) -> BoxStream<'a, Result<Arc<dyn Table>>> {
+        stream::once(self.table_batches(tenant, db_name))
+            .try_flatten()
+            .boxed()
+    }
+
     #[async_backtrace::framed]
     async fn list_tables_history(
         &self,
@@ -551,13 +666,19 @@ impl Catalog for MutableCatalog {
         req: TruncateTableReq,
     ) -> Result<TruncateTableReply> {
         match table_info.db_type.clone() {
-            DatabaseType::NormalDB => Ok(self.ctx.meta.truncate_table(req).await?),
+            DatabaseType::NormalDB => {
+                self.ctx.meta.truncate_table(req).await?;
+            }
             DatabaseType::ShareDB(share_ident) => {
                 let tenant = Tenant::new_or_err(share_ident.tenant_name(), func_name!())?;
                 let db = self.get_database(&tenant, share_ident.share_name()).await?;
-                db.truncate_table(req).await
+                db.truncate_table(req).await?;
             }
         }
+        // The statistics of `table_info` are the ones of the snapshot before truncating.
+        Ok(TruncateTableReply::from_statistics(
+            &table_info.meta.statistics,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -617,4 +738,8 @@ impl Catalog for MutableCatalog {
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
         Ok(self.ctx.meta.drop_sequence(req).await?)
     }
+
+    async fn rename_sequence(&self, req: RenameSequenceReq) -> Result<RenameSequenceReply> {
+        Ok(self.ctx.meta.rename_sequence(req).await?)
+    }
 }
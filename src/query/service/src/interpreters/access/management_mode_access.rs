@@ -67,6 +67,7 @@ impl AccessChecker for ManagementModeAccess {
                 Plan::ShowCreateDatabase(_)
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowGrants(_)
+                | Plan::ShowSettings(_)
 
                 // Set
                 | Plan::SetVariable(_)
@@ -82,6 +83,7 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateView(_)
                 | Plan::CreateStream(_)
                 | Plan::DropStream(_)
+                | Plan::DescribeStream(_)
 
                 // Dynamic table.
                 | Plan::CreateDynamicTable(_)
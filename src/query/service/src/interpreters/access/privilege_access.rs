@@ -30,9 +30,12 @@ use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_sql::optimizer::get_udf_names;
+use databend_common_sql::plans::DeletePlan;
 use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::MergeInto;
 use databend_common_sql::plans::PresignAction;
 use databend_common_sql::plans::RewriteKind;
+use databend_common_sql::plans::UpdatePlan;
 use databend_common_sql::Planner;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
@@ -141,6 +144,9 @@ impl PrivilegeAccess {
             GrantObject::UDF(name) => OwnershipObject::UDF {
                 name: name.to_string(),
             },
+            GrantObject::Sequence(name) => OwnershipObject::Sequence {
+                name: name.to_string(),
+            },
             GrantObject::Global => return Ok(None),
         };
 
@@ -364,6 +370,7 @@ impl PrivilegeAccess {
             | GrantObject::DatabaseById(_, _)
             | GrantObject::UDF(_)
             | GrantObject::Stage(_)
+            | GrantObject::Sequence(_)
             | GrantObject::TableById(_, _, _) => true,
             GrantObject::Global => false,
         };
@@ -394,6 +401,7 @@ impl PrivilegeAccess {
                     GrantObject::Global
                     | GrantObject::UDF(_)
                     | GrantObject::Stage(_)
+                    | GrantObject::Sequence(_)
                     | GrantObject::Database(_, _)
                     | GrantObject::Table(_, _, _) => Err(ErrorCode::PermissionDenied(format!(
                         "Permission denied: privilege [{:?}] is required on {} for user {} with roles [{}]",
@@ -470,6 +478,232 @@ impl PrivilegeAccess {
         }
         Ok(ObjectId::Database(db_id))
     }
+
+    // The UDFs are checked only if `enable_experimental_rbac_check` is on.
+    #[async_backtrace::framed]
+    async fn validate_merge_into_udfs(&self, plan: &MergeInto) -> Result<()> {
+        if !self
+            .ctx
+            .get_settings()
+            .get_enable_experimental_rbac_check()?
+        {
+            return Ok(());
+        }
+        let s_expr = &plan.input;
+        match s_expr.get_udfs() {
+            Ok(udfs) => {
+                if !udfs.is_empty() {
+                    self.validate_udf_access(udfs).await?;
+                }
+            }
+            Err(err) => {
+                return Err(err.add_message("get udf error on validating access"));
+            }
+        }
+        let matched_evaluators = &plan.matched_evaluators;
+        let unmatched_evaluators = &plan.unmatched_evaluators;
+        for matched_evaluator in matched_evaluators {
+            if let Some(condition) = &matched_evaluator.condition {
+                let udf = get_udf_names(condition)?;
+                self.validate_udf_access(udf).await?;
+            }
+            if let Some(updates) = &matched_evaluator.update {
+                for scalar in updates.values() {
+                    let udf = get_udf_names(scalar)?;
+                    self.validate_udf_access(udf).await?;
+                }
+            }
+        }
+        for unmatched_evaluator in unmatched_evaluators {
+            if let Some(condition) = &unmatched_evaluator.condition {
+                let udf = get_udf_names(condition)?;
+                self.validate_udf_access(udf).await?;
+            }
+            for value in &unmatched_evaluator.values {
+                let udf = get_udf_names(value)?;
+                self.validate_udf_access(udf).await?;
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn validate_delete_udfs(&self, plan: &DeletePlan) -> Result<()> {
+        if !self
+            .ctx
+            .get_settings()
+            .get_enable_experimental_rbac_check()?
+        {
+            return Ok(());
+        }
+        if let Some(selection) = &plan.selection {
+            let udf = get_udf_names(selection)?;
+            self.validate_udf_access(udf).await?;
+        }
+        for subquery in &plan.subquery_desc {
+            match subquery.input_expr.get_udfs() {
+                Ok(udfs) => {
+                    if !udfs.is_empty() {
+                        self.validate_udf_access(udfs).await?;
+                    }
+                }
+                Err(err) => {
+                    return Err(err.add_message("Unable to access necessary user-defined functions for executing the DELETE operation"));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn validate_update_udfs(&self, plan: &UpdatePlan) -> Result<()> {
+        if !self
+            .ctx
+            .get_settings()
+            .get_enable_experimental_rbac_check()?
+        {
+            return Ok(());
+        }
+        for scalar in plan.update_list.values() {
+            let udf = get_udf_names(scalar)?;
+            self.validate_udf_access(udf).await?;
+        }
+        if let Some(selection) = &plan.selection {
+            let udf = get_udf_names(selection)?;
+            self.validate_udf_access(udf).await?;
+        }
+        for subquery in &plan.subquery_desc {
+            match subquery.input_expr.get_udfs() {
+                Ok(udfs) => {
+                    if !udfs.is_empty() {
+                        self.validate_udf_access(udfs).await?;
+                    }
+                }
+                Err(err) => {
+                    return Err(err.add_message("Failed to retrieve necessary user-defined functions for executing the UPDATE operation."));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// EXPLAIN doesn't execute the wrapped plan, so seeing the schema of the tables it
+    /// writes is enough, the write privileges on them are not required. The other checks,
+    /// on the stage and the UDFs it reads, are kept.
+    #[async_backtrace::framed]
+    async fn check_explain(&self, ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        let select = UserPrivilegeType::Select;
+        match plan {
+            Plan::Insert(plan) => {
+                self.validate_table_access(
+                    &plan.catalog,
+                    &plan.database,
+                    &plan.table,
+                    select,
+                    false,
+                )
+                .await?;
+                self.check_insert_source(ctx, &plan.source).await
+            }
+            Plan::Replace(plan) => {
+                self.validate_table_access(
+                    &plan.catalog,
+                    &plan.database,
+                    &plan.table,
+                    select,
+                    false,
+                )
+                .await?;
+                self.check_insert_source(ctx, &plan.source).await
+            }
+            Plan::InsertMultiTable(plan) => {
+                let targets = plan
+                    .whens
+                    .iter()
+                    .flat_map(|when| when.intos.iter())
+                    .chain(plan.opt_else.iter().flat_map(|e| e.intos.iter()));
+                for target in targets {
+                    self.validate_table_access(
+                        &target.catalog,
+                        &target.database,
+                        &target.table,
+                        select,
+                        false,
+                    )
+                    .await?;
+                }
+                self.check(ctx, &plan.input_source).await
+            }
+            Plan::MergeInto(plan) => {
+                self.validate_merge_into_udfs(plan).await?;
+                self.validate_table_access(
+                    &plan.catalog,
+                    &plan.database,
+                    &plan.table,
+                    select,
+                    false,
+                )
+                .await
+            }
+            Plan::Delete(plan) => {
+                self.validate_delete_udfs(plan).await?;
+                self.validate_table_access(
+                    &plan.catalog_name,
+                    &plan.database_name,
+                    &plan.table_name,
+                    select,
+                    false,
+                )
+                .await
+            }
+            Plan::Update(plan) => {
+                self.validate_update_udfs(plan).await?;
+                self.validate_table_access(
+                    &plan.catalog,
+                    &plan.database,
+                    &plan.table,
+                    select,
+                    false,
+                )
+                .await
+            }
+            Plan::CopyIntoTable(plan) => {
+                self.validate_stage_access(
+                    &plan.stage_table_info.stage_info,
+                    UserPrivilegeType::Read,
+                )
+                .await?;
+                self.validate_table_access(
+                    plan.catalog_info.catalog_name(),
+                    &plan.database_name,
+                    &plan.table_name,
+                    select,
+                    false,
+                )
+                .await?;
+                match &plan.query {
+                    Some(query) => self.check(ctx, query).await,
+                    None => Ok(()),
+                }
+            }
+            _ => self.check(ctx, plan).await,
+        }
+    }
+
+    async fn check_insert_source(
+        &self,
+        ctx: &Arc<QueryContext>,
+        source: &InsertInputSource,
+    ) -> Result<()> {
+        match source {
+            InsertInputSource::SelectPlan(plan) | InsertInputSource::Stage(plan) => {
+                self.check(ctx, plan).await
+            }
+            InsertInputSource::StreamingWithFormat(..)
+            | InsertInputSource::StreamingWithFileFormat { .. }
+            | InsertInputSource::Values(_) => Ok(()),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -603,9 +837,12 @@ impl AccessChecker for PrivilegeAccess {
                     }
                 }
             }
-            Plan::ExplainAnalyze { plan } | Plan::Explain { plan, .. } => {
+            Plan::ExplainAnalyze { plan } => {
                 self.check(ctx, plan).await?
             }
+            Plan::Explain { plan, .. } => {
+                self.check_explain(ctx, plan).await?
+            }
 
             // Database.
             Plan::ShowCreateDatabase(plan) => {
@@ -618,6 +855,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Drop, plan.if_exists).await?;
             }
+            Plan::AlterDatabase(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Alter, plan.if_exists).await?;
+            }
             Plan::UndropDatabase(_)
             | Plan::DropIndex(_)
             | Plan::DropTableIndex(_) => {
@@ -766,6 +1006,11 @@ impl AccessChecker for PrivilegeAccess {
             Plan::TruncateTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Delete, false).await?
             }
+            Plan::TruncateTables(plan) => {
+                for table in &plan.tables {
+                    self.validate_table_access(&table.catalog, &table.database, &table.table, UserPrivilegeType::Delete, plan.if_exists).await?
+                }
+            }
             Plan::OptimizeTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false).await?
             }
@@ -835,92 +1080,18 @@ impl AccessChecker for PrivilegeAccess {
                 }
             }
             Plan::MergeInto(plan) => {
-                if enable_experimental_rbac_check {
-                    let s_expr = &plan.input;
-                    match s_expr.get_udfs() {
-                        Ok(udfs) => {
-                            if !udfs.is_empty() {
-                                self.validate_udf_access(udfs).await?;
-                            }
-                        }
-                        Err(err) => {
-                            return Err(err.add_message("get udf error on validating access"));
-                        }
-                    }
-                    let matched_evaluators = &plan.matched_evaluators;
-                    let unmatched_evaluators = &plan.unmatched_evaluators;
-                    for matched_evaluator in matched_evaluators {
-                        if let Some(condition) = &matched_evaluator.condition {
-                            let udf = get_udf_names(condition)?;
-                            self.validate_udf_access(udf).await?;
-                        }
-                        if let Some(updates) = &matched_evaluator.update {
-                            for scalar in updates.values() {
-                                let udf = get_udf_names(scalar)?;
-                                self.validate_udf_access(udf).await?;
-                            }
-                        }
-                    }
-                    for unmatched_evaluator in unmatched_evaluators {
-                        if let Some(condition) = &unmatched_evaluator.condition {
-                            let udf = get_udf_names(condition)?;
-                            self.validate_udf_access(udf).await?;
-                        }
-                        for value in &unmatched_evaluator.values {
-                            let udf = get_udf_names(value)?;
-                            self.validate_udf_access(udf).await?;
-                        }
-                    }
-                }
+                self.validate_merge_into_udfs(plan).await?;
                 let privileges = vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete];
                 for privilege in privileges {
                     self.validate_table_access(&plan.catalog, &plan.database, &plan.table, privilege, false).await?;
                 }
             }
             Plan::Delete(plan) => {
-                if enable_experimental_rbac_check {
-                    if let Some(selection) = &plan.selection {
-                        let udf = get_udf_names(selection)?;
-                        self.validate_udf_access(udf).await?;
-                    }
-                    for subquery in &plan.subquery_desc {
-                        match subquery.input_expr.get_udfs() {
-                            Ok(udfs) => {
-                                if !udfs.is_empty() {
-                                    self.validate_udf_access(udfs).await?;
-                                }
-                            }
-                            Err(err) => {
-                                return Err(err.add_message("Unable to access necessary user-defined functions for executing the DELETE operation"));
-                            }
-                        }
-                    }
-                }
+                self.validate_delete_udfs(plan).await?;
                 self.validate_table_access(&plan.catalog_name, &plan.database_name, &plan.table_name, UserPrivilegeType::Delete, false).await?
             }
             Plan::Update(plan) => {
-                if enable_experimental_rbac_check {
-                    for scalar in plan.update_list.values() {
-                        let udf = get_udf_names(scalar)?;
-                        self.validate_udf_access(udf).await?;
-                    }
-                    if let Some(selection) = &plan.selection {
-                        let udf = get_udf_names(selection)?;
-                        self.validate_udf_access(udf).await?;
-                    }
-                    for subquery in &plan.subquery_desc {
-                        match subquery.input_expr.get_udfs() {
-                            Ok(udfs) => {
-                                if !udfs.is_empty() {
-                                    self.validate_udf_access(udfs).await?;
-                                }
-                            }
-                            Err(err) => {
-                                return Err(err.add_message("Failed to retrieve necessary user-defined functions for executing the UPDATE operation."));
-                            }
-                        }
-                    }
-                }
+                self.validate_update_udfs(plan).await?;
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Update, false).await?;
             }
             Plan::CreateView(plan) => {
@@ -946,6 +1117,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropStream(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Drop, plan.if_exists).await?
             }
+            Plan::DescribeStream(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.stream_name, UserPrivilegeType::Select, false).await?
+            }
             Plan::CreateDynamicTable(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
             }
@@ -1100,6 +1274,8 @@ impl AccessChecker for PrivilegeAccess {
             Plan::SetRole(_) => {}
             Plan::SetSecondaryRoles(_) => {}
             Plan::ShowRoles(_) => {}
+            // SHOW SETTINGS lists the settings of the current session, same as system.settings
+            Plan::ShowSettings(_) => {}
             Plan::Presign(plan) => {
                 let privilege = match &plan.action {
                     PresignAction::Upload => UserPrivilegeType::Write,
@@ -17,6 +17,8 @@ use std::sync::Arc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_users::UserApiProvider;
 
 use crate::sessions::QueryContext;
@@ -93,6 +95,13 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Sequence(sequence) => {
+            let catalog = ctx.get_default_catalog()?;
+            let req = GetSequenceReq {
+                ident: SequenceIdent::new(tenant.clone(), sequence),
+            };
+            catalog.get_sequence(req).await?;
+        }
         GrantObject::Global => (),
     }
 
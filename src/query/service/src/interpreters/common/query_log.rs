@@ -133,6 +133,7 @@ impl InterpreterQueryLog {
             None => "".to_string(),
         };
         let user_agent = ctx.get_ua();
+        let application_name = ctx.get_application_name().unwrap_or_default();
         // Session settings
         let mut session_settings = String::new();
         let current_session = ctx.get_current_session();
@@ -204,6 +205,7 @@ impl InterpreterQueryLog {
             client_info: "".to_string(),
             client_address,
             user_agent,
+            application_name,
 
             exception_code,
             exception_text,
@@ -290,6 +292,7 @@ impl InterpreterQueryLog {
             None => "".to_string(),
         };
         let user_agent = ctx.get_ua();
+        let application_name = ctx.get_application_name().unwrap_or_default();
 
         // Schema.
         let current_database = ctx.get_current_database();
@@ -366,6 +369,7 @@ impl InterpreterQueryLog {
             client_info: "".to_string(),
             client_address,
             user_agent,
+            application_name,
             current_database,
 
             exception_code,
@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::plans::AnalyzeTablePlan;
+use log::info;
+
+use crate::interpreters::AnalyzeTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::sessions::QueryContext;
+
+pub struct AnalyzeDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+/// Hook analyze action with a on-finished callback.
+/// errors (if any) are ignored.
+pub async fn hook_analyze(ctx: Arc<QueryContext>, pipeline: &mut Pipeline, desc: AnalyzeDesc) {
+    if pipeline.is_empty() {
+        return;
+    }
+
+    pipeline.set_on_finished(move |(_profiles, err)| {
+        if err.is_ok() {
+            info!(
+                "execute analyze table {}.{} after write",
+                desc.database, desc.table
+            );
+            if let Err(e) = GlobalIORuntime::instance().block_on(analyze_table(ctx, desc)) {
+                info!("analyze hook with error (ignored): {:?}", e);
+            }
+        }
+        Ok(())
+    });
+}
+
+async fn analyze_table(ctx: Arc<QueryContext>, desc: AnalyzeDesc) -> Result<()> {
+    // evict the table from cache, the analyze must see the data just written.
+    ctx.evict_table_from_cache(&desc.catalog, &desc.database, &desc.table)?;
+
+    let analyze_interpreter = AnalyzeTableInterpreter::try_create(ctx.clone(), AnalyzeTablePlan {
+        catalog: desc.catalog,
+        database: desc.database,
+        table: desc.table,
+    })?;
+
+    let mut build_res = analyze_interpreter.execute2().await?;
+    if build_res.main_pipeline.is_empty() {
+        return Ok(());
+    }
+
+    let settings = ctx.get_settings();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let settings = ExecutorSettings::try_create(ctx.clone())?;
+
+    let mut pipelines = build_res.sources_pipelines;
+    pipelines.push(build_res.main_pipeline);
+    let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+    ctx.set_executor(complete_executor.get_inner())?;
+    complete_executor.execute()
+}
@@ -20,7 +20,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_license::license::Feature::Vacuum;
 use databend_common_license::license_manager::get_license_manager;
-use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_core::query_spill_prefix_with_path;
 use databend_common_storage::DataOperator;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
 
@@ -29,7 +29,11 @@ use crate::sessions::QueryContext;
 pub fn hook_vacuum_temp_files(query_ctx: &Arc<QueryContext>) -> Result<()> {
     let tenant = query_ctx.get_tenant();
     let settings = query_ctx.get_settings();
-    let spill_prefix = query_spill_prefix(tenant.tenant_name(), &query_ctx.get_id());
+    let spill_prefix = query_spill_prefix_with_path(
+        &settings.get_spill_path()?,
+        tenant.tenant_name(),
+        &query_ctx.get_id(),
+    );
     let license_manager = get_license_manager();
 
     if license_manager
@@ -41,7 +41,9 @@ use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::short_sql;
+use crate::sessions::QueryCompletion;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryRouting;
 use crate::sessions::SessionManager;
 use crate::stream::DataBlockStream;
 use crate::stream::ProgressStream;
@@ -215,6 +217,13 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
         SessionManager::instance().status.write().query_finish(now)
     }
 
+    SessionManager::instance().on_query_completed(&QueryCompletion {
+        query_id: ctx.get_id(),
+        query_kind: ctx.get_query_kind(),
+        duration_ms: ctx.get_query_duration_ms(),
+        error_code: error.as_ref().map(|e| e.code()),
+    });
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error, has_profiles) {
         error!("interpreter.finish.error: {:?}", error)
     }
@@ -224,16 +233,25 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
 /// 1. Plan the SQL
 /// 2. Execute the plan -- interpreter
 ///
-/// This function is used to plan the SQL. If an error occurs, we will log the query start and finished.
+/// This function is used to plan the SQL. If an error occurs, we will log the query start and finished,
+/// otherwise the routing hint of the plan is passed to the query routing hooks.
 pub async fn interpreter_plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<(Plan, PlanExtras)> {
     let mut planner = Planner::new(ctx.clone());
     let result = planner.plan_sql(sql).await;
 
-    if result.is_err() {
-        // Only log if there's an error
-        ctx.attach_query_str(QueryKind::Unknown, short_sql(sql.to_string()));
-        log_query_start(&ctx);
-        log_query_finished(&ctx, result.as_ref().err().cloned(), false);
+    match &result {
+        Ok((plan, _)) => SessionManager::instance().on_query_routed(&QueryRouting {
+            session_id: ctx.get_current_session_id(),
+            query_id: ctx.get_id(),
+            query_kind: ctx.get_query_kind(),
+            hint: plan.into(),
+        }),
+        Err(error) => {
+            // Only log if there's an error
+            ctx.attach_query_str(QueryKind::Unknown, short_sql(sql.to_string()));
+            log_query_start(&ctx);
+            log_query_finished(&ctx, Some(error.clone()), false);
+        }
     }
 
     result
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DropCatalogPlan;
 use databend_common_storages_fuse::TableContext;
@@ -51,6 +52,13 @@ impl Interpreter for DropCatalogInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_catalog_execute");
 
+        // The default catalog is built-in and can't be dropped, even with `IF EXISTS`.
+        if let Ok(catalog) = self.ctx.get_catalog(&self.plan.catalog).await {
+            if catalog.info().is_default() {
+                return Err(ErrorCode::BadArguments("cannot drop the default catalog"));
+            }
+        }
+
         let mgr = CatalogManager::instance();
         mgr.drop_catalog(self.plan.clone().into()).await?;
 
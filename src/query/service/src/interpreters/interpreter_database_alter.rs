@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
+use databend_common_meta_app::schema::parse_bool_option;
+use databend_common_meta_app::schema::UpsertDatabaseOptionReq;
+use databend_common_meta_app::schema::ALTERABLE_DATABASE_OPTIONS;
+use databend_common_meta_app::schema::OPT_KEY_DISABLE_TIME_TRAVEL;
+use databend_common_sql::plans::AlterDatabaseAction;
+use databend_common_sql::plans::AlterDatabasePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AlterDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterDatabasePlan,
+}
+
+impl AlterDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterDatabasePlan) -> Result<Self> {
+        Ok(AlterDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "AlterDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let options = match &self.plan.action {
+            AlterDatabaseAction::SetOptions(set_options) => {
+                for (key, value) in set_options {
+                    check_alterable_option(key)?;
+                    if key == OPT_KEY_DISABLE_TIME_TRAVEL {
+                        parse_bool_option(OPT_KEY_DISABLE_TIME_TRAVEL, value)?;
+                    }
+                }
+                set_options
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Some(value.clone())))
+                    .collect::<BTreeMap<_, _>>()
+            }
+            AlterDatabaseAction::UnsetOptions(unset_options) => {
+                for key in unset_options {
+                    check_alterable_option(key)?;
+                }
+                unset_options
+                    .iter()
+                    .map(|key| (key.clone(), None))
+                    .collect::<BTreeMap<_, _>>()
+            }
+        };
+
+        let tenant = &self.plan.tenant;
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let db = match catalog.get_database(tenant, &self.plan.database).await {
+            Ok(db) => db,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_DATABASE && self.plan.if_exists => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+
+        // Check the options as they will be after the change, e.g. a database
+        // created from a share does not accept the owner only options.
+        let mut db_meta = db.get_db_info().meta.clone();
+        for (key, value) in &options {
+            match value {
+                Some(value) => db_meta.options.insert(key.clone(), value.clone()),
+                None => db_meta.options.remove(key),
+            };
+        }
+        db_meta.check_options()?;
+
+        let req = UpsertDatabaseOptionReq {
+            name_ident: DatabaseNameIdent::new(tenant, &self.plan.database),
+            options,
+        };
+        catalog.upsert_database_option(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+fn check_alterable_option(key: &str) -> Result<()> {
+    if !ALTERABLE_DATABASE_OPTIONS.contains(&key) {
+        return Err(ErrorCode::BadArguments(format!(
+            "database option {key} is invalid for alter database statement"
+        )));
+    }
+    Ok(())
+}
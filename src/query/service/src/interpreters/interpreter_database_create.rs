@@ -18,7 +18,10 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::parse_bool_option;
 use databend_common_meta_app::schema::CreateDatabaseReq;
+use databend_common_meta_app::schema::OPT_KEY_DISABLE_TIME_TRAVEL;
+use databend_common_meta_app::schema::OPT_KEY_TRANSIENT;
 use databend_common_meta_app::share::share_name_ident::ShareNameIdent;
 use databend_common_meta_app::share::ShareGrantObjectPrivilege;
 use databend_common_meta_app::tenant::Tenant;
@@ -31,6 +34,7 @@ use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
+use crate::interpreters::interpreter_table_create::is_valid_data_retention_time;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -124,6 +128,20 @@ impl Interpreter for CreateDatabaseInterpreter {
                 quota.max_databases
             )));
         };
+        if let Some(value) = self
+            .plan
+            .meta
+            .options
+            .get(&OPT_KEY_TRANSIENT.to_uppercase())
+        {
+            parse_bool_option(OPT_KEY_TRANSIENT, value)?;
+        }
+        if let Some(value) = self.plan.meta.options.get(OPT_KEY_DISABLE_TIME_TRAVEL) {
+            parse_bool_option(OPT_KEY_DISABLE_TIME_TRAVEL, value)?;
+        }
+        is_valid_data_retention_time(&self.plan.meta.options)?;
+        self.plan.meta.check_options()?;
+
         // if create from other tenant, check from share endpoint
         if let Some(ref share_name) = self.plan.meta.from_share {
             let share_name_ident = share_name.clone().to_tident(());
@@ -55,7 +55,11 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
         let db = catalog.get_database(&tenant, &self.plan.database).await?;
         let name = db.name();
-        let mut info = format!("CREATE DATABASE `{}`", name);
+        let mut info = if db.options().contains_key("TRANSIENT") {
+            format!("CREATE TRANSIENT DATABASE `{}`", name)
+        } else {
+            format!("CREATE DATABASE `{}`", name)
+        };
         if !db.engine().is_empty() {
             let engine = format!(" ENGINE={}", db.engine().to_uppercase());
             let engine_options = db
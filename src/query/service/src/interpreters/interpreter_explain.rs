@@ -28,6 +28,7 @@ use databend_common_expression::FromData;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_sql::binder::ExplainConfig;
+use databend_common_sql::binder::ExplainVerbosity;
 use databend_common_sql::optimizer::ColumnSet;
 use databend_common_sql::plans::FunctionCall;
 use databend_common_sql::plans::UpdatePlan;
@@ -246,7 +247,15 @@ impl ExplainInterpreter {
     }
 
     pub fn explain_plan(&self, plan: &Plan) -> Result<Vec<DataBlock>> {
-        let result = plan.format_indent(self.config.verbose)?;
+        let result = match plan {
+            Plan::Query {
+                s_expr, metadata, ..
+            } if self.config.verbosity == ExplainVerbosity::Terse => {
+                let metadata = &*metadata.read();
+                strip_decorations(s_expr.to_format_tree(metadata, false)?).format_pretty()?
+            }
+            _ => plan.format_indent(self.config.verbose)?,
+        };
         let line_split_result: Vec<&str> = result.lines().collect();
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
@@ -287,9 +296,11 @@ impl ExplainInterpreter {
             }
         }
 
-        let result = plan
-            .format(metadata.clone(), Default::default())?
-            .format_pretty()?;
+        let mut format_tree = plan.format(metadata.clone(), Default::default())?;
+        if self.config.verbosity == ExplainVerbosity::Terse {
+            format_tree = strip_decorations(format_tree);
+        }
+        let result = format_tree.format_pretty()?;
         let line_split_result: Vec<&str> = result.lines().collect();
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
@@ -442,8 +453,23 @@ impl ExplainInterpreter {
         // It's because we need to get the same partitions as the original selecting plan.
         let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, formatted_ast.is_none());
         let plan = builder.build(s_expr, bind_context.column_set()).await?;
-        self.explain_physical_plan(&plan, metadata, formatted_ast)
-            .await
+        let mut blocks = self
+            .explain_physical_plan(&plan, metadata, formatted_ast)
+            .await?;
+
+        // The physical plan has no more details to show, so the verbose level also shows
+        // the logical plan it is built from, with the column ids and statistics.
+        if self.config.verbosity == ExplainVerbosity::Verbose {
+            let result = s_expr
+                .to_format_tree(&metadata.read(), true)?
+                .format_pretty()?;
+            let mut lines = vec!["Logical plan:"];
+            lines.extend(result.lines());
+            blocks.push(DataBlock::new_from_columns(vec![StringType::from_data(
+                lines,
+            )]));
+        }
+        Ok(blocks)
     }
 
     #[async_backtrace::framed]
@@ -553,3 +579,18 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::concat(&result)?])
     }
 }
+
+/// Keep only the operator nodes of a plan format tree.
+///
+/// Operators are named in upper camel case, e.g. `TableScan` or `Filter(Build)`, while
+/// decorations such as `estimated rows: 1.00` or `statistics` start in lower case, they
+/// are dropped together with their own children.
+fn strip_decorations(node: FormatTreeNode) -> FormatTreeNode {
+    let children = node
+        .children
+        .into_iter()
+        .filter(|child| !child.payload.starts_with(|c: char| c.is_ascii_lowercase()))
+        .map(strip_decorations)
+        .collect();
+    FormatTreeNode::with_children(node.payload, children)
+}
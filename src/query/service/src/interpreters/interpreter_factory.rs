@@ -65,6 +65,7 @@ use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CreateShareEndpointInterpreter;
 use crate::interpreters::CreateShareInterpreter;
 use crate::interpreters::CreateStreamInterpreter;
+use crate::interpreters::DescribeStreamInterpreter;
 use crate::interpreters::DropShareInterpreter;
 use crate::interpreters::DropStreamInterpreter;
 use crate::interpreters::DropUserInterpreter;
@@ -81,18 +82,41 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
-        // Check the access permission.
-        let access_checker = Accessor::create(ctx.clone());
-        access_checker
-            .check(plan)
-            .await
-            .map_err(|e| match e.code() {
-                ErrorCode::PERMISSION_DENIED => {
-                    error!("Access.denied(v2): {:?}", e);
-                    e
-                }
-                _ => e,
-            })?;
+        Self::get_with_access(ctx, plan, true).await
+    }
+
+    /// Same as `get`, but the access check can be skipped by passing `check_access = false`.
+    ///
+    /// Skipping is only allowed for internal (non-user) sessions, e.g. statements re-issued
+    /// by background jobs which have already been authorized.
+    #[async_backtrace::framed]
+    pub async fn get_with_access(
+        ctx: Arc<QueryContext>,
+        plan: &Plan,
+        check_access: bool,
+    ) -> Result<InterpreterPtr> {
+        if check_access {
+            // Check the access permission.
+            let access_checker = Accessor::create(ctx.clone());
+            access_checker
+                .check(plan)
+                .await
+                .map_err(|e| match e.code() {
+                    ErrorCode::PERMISSION_DENIED => {
+                        error!("Access.denied(v2): {:?}", e);
+                        e
+                    }
+                    _ => e,
+                })?;
+        } else {
+            let session_type = ctx.get_current_session().get_type();
+            if session_type.is_user_session() {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Access check can only be skipped by internal sessions, current session type: {}",
+                    session_type
+                )));
+            }
+        }
         Self::get_inner(ctx, plan)
     }
 
@@ -177,6 +201,10 @@ impl InterpreterFactory {
                 RenameDatabaseInterpreter::try_create(ctx, *rename_database.clone())?,
             )),
 
+            Plan::AlterDatabase(alter_database) => Ok(Arc::new(
+                AlterDatabaseInterpreter::try_create(ctx, *alter_database.clone())?,
+            )),
+
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(Arc::new(
                 ShowCreateTableInterpreter::try_create(ctx, *show_create_table.clone())?,
@@ -231,6 +259,9 @@ impl InterpreterFactory {
             Plan::TruncateTable(truncate_table) => Ok(Arc::new(
                 TruncateTableInterpreter::try_create(ctx, *truncate_table.clone())?,
             )),
+            Plan::TruncateTables(truncate_tables) => Ok(Arc::new(
+                TruncateTablesInterpreter::try_create(ctx, *truncate_tables.clone())?,
+            )),
             Plan::OptimizeTable(optimize_table) => Ok(Arc::new(
                 OptimizeTableInterpreter::try_create(ctx, *optimize_table.clone())?,
             )),
@@ -280,6 +311,9 @@ impl InterpreterFactory {
                 ctx,
                 *drop_stream.clone(),
             )?)),
+            Plan::DescribeStream(describe_stream) => Ok(Arc::new(
+                DescribeStreamInterpreter::try_create(ctx, *describe_stream.clone())?,
+            )),
 
             // dynamic tables
             Plan::CreateDynamicTable(_) => Err(ErrorCode::Unimplemented("todo")),
@@ -439,6 +473,10 @@ impl InterpreterFactory {
                 ctx,
                 *unset_variable.clone(),
             )?)),
+            Plan::ShowSettings(show_settings) => Ok(Arc::new(ShowSettingsInterpreter::try_create(
+                ctx,
+                *show_settings.clone(),
+            )?)),
             Plan::UseDatabase(p) => Ok(Arc::new(UseDatabaseInterpreter::try_create(
                 ctx,
                 *p.clone(),
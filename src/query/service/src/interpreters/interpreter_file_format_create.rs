@@ -14,11 +14,18 @@
 
 use std::sync::Arc;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::UserDefinedFileFormat;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_sql::plans::CreateFileFormatPlan;
 use databend_common_users::UserApiProvider;
 use log::debug;
+use log::info;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -61,10 +68,59 @@ impl Interpreter for CreateFileFormatInterpreter {
         );
 
         let tenant = self.ctx.get_tenant();
-        let _create_file_format = user_mgr
+        let old_file_format = match user_mgr.get_file_format(&tenant, &plan.name).await {
+            Ok(file_format) => Some(file_format),
+            Err(e) if e.code() == ErrorCode::UNKNOWN_FILE_FORMAT => None,
+            Err(e) => return Err(e),
+        };
+
+        user_mgr
             .add_file_format(&tenant, user_defined_file_format, &plan.create_option)
             .await?;
 
-        Ok(PipelineBuildResult::create())
+        let (status, changes) = match old_file_format {
+            None => ("created", vec![]),
+            Some(_) if plan.create_option == CreateOption::CreateIfNotExists => {
+                ("unchanged", vec![])
+            }
+            Some(old) => {
+                let changes =
+                    diff_file_format_params(&old.file_format_params, &plan.file_format_params);
+                info!(
+                    "replace file format {}, changed options: [{}]",
+                    plan.name,
+                    changes.join(", ")
+                );
+                ("replaced", changes)
+            }
+        };
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![status.to_string()]),
+            StringType::from_data(vec![changes.join(", ")]),
+        ])])
+    }
+}
+
+/// Compare two file formats by their options, returns `KEY: old -> new` for each
+/// option that differs.
+fn diff_file_format_params(old: &FileFormatParams, new: &FileFormatParams) -> Vec<String> {
+    let old_options = old.to_options_map();
+    let new_options = new.to_options_map();
+
+    let mut changes = vec![];
+    for (key, new_value) in new_options.iter() {
+        let key_upper = key.to_uppercase();
+        match old_options.get(key) {
+            Some(old_value) if old_value == new_value => {}
+            Some(old_value) => changes.push(format!("{key_upper}: '{old_value}' -> '{new_value}'")),
+            None => changes.push(format!("{key_upper}: (none) -> '{new_value}'")),
+        }
+    }
+    for (key, old_value) in old_options.iter() {
+        if !new_options.contains_key(key) {
+            changes.push(format!("{}: '{old_value}' -> (none)", key.to_uppercase()));
+        }
     }
+    changes
 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+use std::sync::LazyLock;
 use std::time::Duration;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
@@ -19,6 +21,7 @@ use std::time::UNIX_EPOCH;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_metrics::interpreter::*;
+use parking_lot::Mutex;
 
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -30,6 +33,17 @@ const LABEL_KIND: &str = "kind";
 const LABEL_TENANT: &str = "tenant";
 const LABEL_CLUSTER: &str = "cluster";
 const LABEL_CODE: &str = "code";
+const LABEL_APPLICATION_NAME: &str = "application_name";
+
+// The application name is passed by the client, keep the cardinality of its label
+// bounded: names are normalized, and the names beyond the first
+// `MAX_APPLICATION_NAME_LABELS` ones are reported as `other`.
+const MAX_APPLICATION_NAME_LABELS: usize = 64;
+const MAX_APPLICATION_NAME_LEN: usize = 64;
+const OTHER_APPLICATION_NAME: &str = "other";
+
+static APPLICATION_NAME_LABELS: LazyLock<Mutex<HashSet<String>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
 
 impl InterpreterMetrics {
     fn common_labels(ctx: &QueryContext) -> Vec<(&'static str, String)> {
@@ -37,12 +51,14 @@ impl InterpreterMetrics {
         let query_kind = ctx.get_query_kind().to_string();
         let tenant_id = ctx.get_tenant();
         let cluster_id = GlobalConfig::instance().query.cluster_id.clone();
+        let application_name = application_name_label(ctx.get_application_name());
 
         vec![
             (LABEL_HANDLER, handler_type),
             (LABEL_KIND, query_kind),
             (LABEL_TENANT, tenant_id.tenant_name().to_string()),
             (LABEL_CLUSTER, cluster_id),
+            (LABEL_APPLICATION_NAME, application_name),
         ]
     }
 
@@ -135,3 +151,26 @@ fn convert_query_timestamp(time: SystemTime) -> u128 {
         .unwrap_or(Duration::new(0, 0))
         .as_micros()
 }
+
+fn application_name_label(name: Option<String>) -> String {
+    let Some(name) = name else {
+        return String::new();
+    };
+
+    let name = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .take(MAX_APPLICATION_NAME_LEN)
+        .collect::<String>();
+    if name.is_empty() {
+        return OTHER_APPLICATION_NAME.to_string();
+    }
+
+    let mut labels = APPLICATION_NAME_LABELS.lock();
+    if labels.contains(&name) || labels.len() < MAX_APPLICATION_NAME_LABELS {
+        labels.insert(name.clone());
+        name
+    } else {
+        OTHER_APPLICATION_NAME.to_string()
+    }
+}
@@ -25,6 +25,7 @@ use databend_common_meta_app::tenant::Tenant;
 use databend_common_sql::plans::GrantPrivilegePlan;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use log::debug;
 use log::error;
 use log::info;
@@ -101,12 +102,57 @@ impl GrantPrivilegeInterpreter {
             GrantObject::UDF(name) => Ok(OwnershipObject::UDF {
                 name: name.to_string(),
             }),
+            GrantObject::Sequence(name) => Ok(OwnershipObject::Sequence {
+                name: name.to_string(),
+            }),
             GrantObject::Global => Err(ErrorCode::IllegalGrant(
                 "Illegal GRANT/REVOKE command; please consult the manual to see which privileges can be used",
             )),
         }
     }
 
+    /// Check the grantor holds every granted privilege on the object, so that a grant can not
+    /// give away more than the grantor has. `account_admin` and the owner of the object can grant
+    /// any privilege on it, ownership is checked separately in `grant_ownership`.
+    #[async_backtrace::framed]
+    async fn check_grantor_privileges(
+        &self,
+        tenant: &Tenant,
+        object: &GrantObject,
+        privileges: UserPrivilegeSet,
+    ) -> Result<()> {
+        let session = self.ctx.get_current_session();
+        let effective_roles = session.get_all_effective_roles().await?;
+        if effective_roles
+            .iter()
+            .any(|r| r.name == BUILTIN_ROLE_ACCOUNT_ADMIN)
+        {
+            return Ok(());
+        }
+
+        if !matches!(object, GrantObject::Global) {
+            let owner_object = self
+                .convert_to_ownerobject(tenant, object, object.catalog())
+                .await?;
+            if session.has_ownership(&owner_object).await? {
+                return Ok(());
+            }
+        }
+
+        for privilege in privileges.iter() {
+            if privilege == Ownership {
+                continue;
+            }
+            if session.validate_privilege(object, privilege).await.is_err() {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: cannot grant {} on {}, the current role does not have it",
+                    privilege, object
+                )));
+            }
+        }
+        Ok(())
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn grant_ownership(
@@ -183,10 +229,11 @@ impl Interpreter for GrantPrivilegeInterpreter {
         validate_grant_object_exists(&self.ctx, &plan.on).await?;
 
         // TODO: check user existence
-        // TODO: check privilege on granting on the grant object
 
         let tenant = self.ctx.get_tenant();
         let user_mgr = UserApiProvider::instance();
+        self.check_grantor_privileges(&tenant, &plan.on, plan.priv_types)
+            .await?;
 
         match plan.principal {
             PrincipalIdentity::User(user) => {
@@ -66,8 +66,15 @@ impl Interpreter for DropRoleInterpreter {
             ));
         }
         let tenant = self.ctx.get_tenant();
-        UserApiProvider::instance()
-            .drop_role(&tenant, plan.role_name, plan.if_exists)
+        let user_mgr = UserApiProvider::instance();
+        if !plan.if_exists {
+            // Fails with `UnknownRole` before any grant is touched.
+            user_mgr.get_role(&tenant, role_name.clone()).await?;
+        }
+        // Also revoke the role from the users and roles it was granted to, so that they
+        // do not keep dangling grants.
+        user_mgr
+            .drop_role_cascade(&tenant, role_name.clone())
             .await?;
 
         let session = self.ctx.get_current_session();
@@ -20,13 +20,18 @@ use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal::RoleInfo;
 use databend_common_storages_fuse::TableContext;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use log::debug;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 
+const SHOW_ROLES_PAGE_SIZE: usize = 1000;
+
 #[derive(Debug)]
 pub struct ShowRolesInterpreter {
     ctx: Arc<QueryContext>,
@@ -54,8 +59,8 @@ impl Interpreter for ShowRolesInterpreter {
         debug!("ctx.id" = self.ctx.get_id().as_str(); "show_roles_execute");
 
         let session = self.ctx.get_current_session();
-        let mut roles = session.get_all_available_roles().await?;
-        roles.sort_by(|a, b| a.name.cmp(&b.name));
+        let tenant = self.ctx.get_tenant();
+        let user_api = UserApiProvider::instance();
 
         let current_role_name = session
             .get_current_role()
@@ -68,19 +73,51 @@ impl Interpreter for ShowRolesInterpreter {
             .cloned()
             .unwrap_or_default();
 
-        let names = roles.iter().map(|x| x.name.clone()).collect::<Vec<_>>();
-        let inherited_roles: Vec<u64> = roles
-            .iter()
-            .map(|x| x.grants.roles().len() as u64)
-            .collect();
-        let is_currents: Vec<bool> = roles.iter().map(|r| r.name == current_role_name).collect();
-        let is_defaults: Vec<bool> = roles.iter().map(|r| r.name == default_role_name).collect();
-
-        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
-            StringType::from_data(names),
-            UInt64Type::from_data(inherited_roles),
-            BooleanType::from_data(is_currents),
-            BooleanType::from_data(is_defaults),
-        ])])
+        let ownerships = user_api.get_ownerships_by_role(&tenant).await?;
+        let to_block = |roles: &[RoleInfo]| {
+            let names = roles.iter().map(|x| x.name.clone()).collect::<Vec<_>>();
+            let inherited_roles: Vec<u64> = roles
+                .iter()
+                .map(|x| x.grants.roles().len() as u64)
+                .collect();
+            let is_currents: Vec<bool> =
+                roles.iter().map(|r| r.name == current_role_name).collect();
+            let is_defaults: Vec<bool> =
+                roles.iter().map(|r| r.name == default_role_name).collect();
+            let owned_objects: Vec<u64> = roles
+                .iter()
+                .map(|r| ownerships.get(&r.name).map_or(0, |v| v.len() as u64))
+                .collect();
+
+            DataBlock::new_from_columns(vec![
+                StringType::from_data(names),
+                UInt64Type::from_data(inherited_roles),
+                BooleanType::from_data(is_currents),
+                BooleanType::from_data(is_defaults),
+                UInt64Type::from_data(owned_objects),
+            ])
+        };
+
+        // account_admin contains every role, list them page by page instead of at once.
+        if current_role_name == BUILTIN_ROLE_ACCOUNT_ADMIN {
+            let mut blocks = vec![];
+            let mut start_after = None;
+            loop {
+                let roles = user_api
+                    .get_roles_page(&tenant, start_after.as_deref(), SHOW_ROLES_PAGE_SIZE)
+                    .await?;
+                let Some(last) = roles.last() else {
+                    break;
+                };
+                start_after = Some(last.name.clone());
+                blocks.push(to_block(&roles));
+            }
+            return PipelineBuildResult::from_blocks(blocks);
+        }
+
+        let mut roles = session.get_all_available_roles().await?;
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        PipelineBuildResult::from_blocks(vec![to_block(&roles)])
     }
 }
@@ -16,9 +16,13 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_sql::plans::CreateSequencePlan;
 use databend_common_storages_fuse::TableContext;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -54,7 +58,26 @@ impl Interpreter for CreateSequenceInterpreter {
             create_on: Utc::now(),
         };
         let catalog = self.ctx.get_default_catalog()?;
-        let _reply = catalog.create_sequence(req).await?;
+        let reply = catalog.create_sequence(req).await?;
+
+        // grant the ownership of the sequence to the current role, unless
+        // an existing sequence was kept by `IF NOT EXISTS`.
+        if !reply.new_sequence {
+            return Ok(PipelineBuildResult::create());
+        }
+        if let Some(current_role) = self.ctx.get_current_role() {
+            let tenant = self.ctx.get_tenant();
+            let role_api = UserApiProvider::instance().role_api(&tenant);
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::Sequence {
+                        name: self.plan.ident.name().to_string(),
+                    },
+                    &current_role.name,
+                )
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
 
         Ok(PipelineBuildResult::create())
     }
@@ -16,9 +16,13 @@ use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::DropSequenceReq;
 use databend_common_sql::plans::DropSequencePlan;
 use databend_common_storages_fuse::TableContext;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -60,6 +64,18 @@ impl Interpreter for DropSequenceInterpreter {
             )));
         }
 
+        // drop the ownership of the dropped sequence.
+        if reply.prev.is_some() {
+            let tenant = self.ctx.get_tenant();
+            let role_api = UserApiProvider::instance().role_api(&tenant);
+            role_api
+                .revoke_ownership(&OwnershipObject::Sequence {
+                    name: self.plan.ident.name().to_string(),
+                })
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
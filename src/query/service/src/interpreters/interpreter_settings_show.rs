@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::LikePattern;
+use databend_common_meta_app::principal::UserSettingValue;
+use databend_common_sql::plans::ShowSettingsPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct ShowSettingsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowSettingsPlan,
+}
+
+impl ShowSettingsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowSettingsPlan) -> Result<Self> {
+        Ok(ShowSettingsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowSettingsInterpreter {
+    fn name(&self) -> &str {
+        "ShowSettingsInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let limit = self.plan.limit.map_or(usize::MAX, |limit| limit as usize);
+        let items = self
+            .ctx
+            .get_settings()
+            .list_all()
+            .into_iter()
+            .filter(|item| item.level == self.plan.level)
+            .filter(|item| match &self.plan.like {
+                Some(pattern) => {
+                    LikePattern::complex_pattern(item.name.as_bytes(), pattern.as_bytes())
+                }
+                None => true,
+            })
+            .take(limit);
+
+        let mut names = vec![];
+        let mut values = vec![];
+        let mut defaults = vec![];
+        let mut ranges = vec![];
+        let mut levels = vec![];
+        let mut descs = vec![];
+        let mut types = vec![];
+        for item in items {
+            names.push(item.name);
+            values.push(format!("{:?}", item.user_value));
+            defaults.push(format!("{:?}", item.default_value));
+            ranges.push(match item.range {
+                Some(range) => format!("{}", range),
+                None => "None".to_string(),
+            });
+            levels.push(format!("{:?}", item.level));
+            descs.push(item.desc.to_string());
+            let typename = match item.user_value {
+                UserSettingValue::UInt64(_) => "UInt64",
+                UserSettingValue::String(_) => "String",
+            };
+            types.push(typename.to_string());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(values),
+            StringType::from_data(defaults),
+            StringType::from_data(ranges),
+            StringType::from_data(levels),
+            StringType::from_data(descs),
+            StringType::from_data(types),
+        ])])
+    }
+}
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use chrono::Utc;
 use databend_common_exception::Result;
 use databend_common_meta_api::ShareApi;
+use databend_common_meta_app::share::dedup_and_validate_share_tenants;
 use databend_common_meta_app::share::share_name_ident::ShareNameIdent;
 use databend_common_meta_app::share::AddShareAccountsReq;
 use databend_common_meta_app::share::RemoveShareAccountsReq;
@@ -55,10 +56,12 @@ impl Interpreter for AlterShareTenantsInterpreter {
         let tenant = self.ctx.get_tenant();
         let meta_api = UserApiProvider::instance().get_meta_store_client();
         if self.plan.is_add {
+            let mut accounts = self.plan.accounts.clone();
+            dedup_and_validate_share_tenants(&mut accounts, tenant.tenant_name())?;
             let req = AddShareAccountsReq {
                 share_name: ShareNameIdent::new(&tenant, &self.plan.share),
                 if_exists: self.plan.if_exists,
-                accounts: self.plan.accounts.clone(),
+                accounts,
                 share_on: Utc::now(),
             };
             let resp = meta_api.add_share_tenants(req).await?;
@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_sql::plans::DescribeStreamPlan;
+use databend_common_storages_fuse::io::SnapshotsIO;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_stream::stream_table::StreamTable;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DescribeStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescribeStreamPlan,
+}
+
+impl DescribeStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescribeStreamPlan) -> Result<Self> {
+        Ok(DescribeStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescribeStreamInterpreter {
+    fn name(&self) -> &str {
+        "DescribeStreamInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.plan.catalog.as_str();
+        let database = self.plan.database.as_str();
+        let name = self.plan.stream_name.as_str();
+        let table = self.ctx.get_table(catalog, database, name).await?;
+        if table.engine() != STREAM_ENGINE {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{database}.{name} is not STREAM, please use `DESC TABLE {database}.{name}`"
+            )));
+        }
+        let stream = StreamTable::try_from_table(table.as_ref())?;
+        let stream_info = table.get_table_info();
+
+        let tenant = self.ctx.get_tenant();
+        let db_id = self
+            .ctx
+            .get_catalog(catalog)
+            .await?
+            .get_database(&tenant, database)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+        let owner = UserApiProvider::instance()
+            .get_ownership(&tenant, &OwnershipObject::Table {
+                catalog_name: catalog.to_string(),
+                db_id,
+                table_id: stream_info.ident.table_id,
+            })
+            .await
+            .ok()
+            .and_then(|ownership| ownership.map(|o| o.role.clone()));
+
+        let mut invalid_reason = "".to_string();
+        match stream.source_table(self.ctx.clone()).await {
+            Ok(source) => {
+                let fuse_table = FuseTable::try_from_table(source.as_ref())?;
+                if let Some(location) = stream.snapshot_loc() {
+                    invalid_reason =
+                        SnapshotsIO::read_snapshot(location, fuse_table.get_operator())
+                            .await
+                            .err()
+                            .map_or("".to_string(), |e| e.display_text());
+                }
+            }
+            Err(e) => {
+                invalid_reason = e.display_text();
+            }
+        }
+
+        let table_on = format!(
+            "{}.{}",
+            stream.source_table_database(),
+            stream.source_table_name()
+        );
+        let columns = vec![
+            TimestampType::from_data(vec![stream_info.meta.created_on.timestamp_micros()]),
+            StringType::from_data(vec![name]),
+            StringType::from_data(vec![database]),
+            StringType::from_data(vec![catalog]),
+            StringType::from_data(vec![table_on]),
+            StringType::from_opt_data(vec![owner]),
+            StringType::from_data(vec![stream_info.meta.comment.clone()]),
+            StringType::from_data(vec![stream.mode().to_string()]),
+            StringType::from_data(vec![invalid_reason]),
+            UInt64Type::from_data(vec![stream.offset()]),
+            StringType::from_opt_data(vec![stream.snapshot_loc()]),
+        ];
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(columns)])
+    }
+}
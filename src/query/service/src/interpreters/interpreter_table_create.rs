@@ -17,7 +17,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
-use chrono::Utc;
+use databend_common_ast::ast::Engine;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
@@ -30,25 +30,26 @@ use databend_common_license::license::Feature::ComputedColumn;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::parse_bool_option;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableReq;
-use databend_common_meta_app::schema::TableIdent;
-use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::UndropTableByIdReq;
+use databend_common_meta_app::schema::OPT_KEY_TRANSIENT;
+use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_types::MatchSeq;
+use databend_common_settings::SettingRange;
 use databend_common_sql::field_default_value;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storage::DataOperator;
 use databend_common_storages_fuse::io::MetaReaders;
-use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
-use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
+use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
-use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use databend_common_storages_fuse::FUSE_TABLE_OPTIONS;
 use databend_common_storages_fuse::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use databend_common_storages_share::save_share_spec;
 use databend_common_users::RoleCacheManager;
@@ -66,13 +67,13 @@ use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
-use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
-use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use log::error;
 use log::info;
 
+use crate::interpreters::hook::analyze_hook::hook_analyze;
+use crate::interpreters::hook::analyze_hook::AnalyzeDesc;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -177,21 +178,19 @@ impl CreateTableInterpreter {
 
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
 
-        let mut req = self.build_request(None)?;
+        let mut req = self.build_request(None).await?;
 
         // create a dropped table first.
         req.as_dropped = true;
-        req.table_meta.drop_on = Some(Utc::now());
-        let table_meta = req.table_meta.clone();
-        let reply = catalog.create_table(req).await?;
+        req.table_meta.drop_on = Some(self.ctx.get_statement_timestamp());
+        let (reply, table_info) = catalog.create_table_with_info(req).await?;
         if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
             return Ok(PipelineBuildResult::create());
         }
+        self.add_table_location(reply.table_id).await?;
 
         let table_id = reply.table_id;
-        let table_id_seq = reply
-            .table_id_seq
-            .expect("internal error: table_id_seq must have been set. CTAS(replace) of table");
+        let table_id_seq = table_info.ident.seq;
         let db_id = reply.db_id;
 
         // grant the ownership of the table to the current role.
@@ -221,13 +220,6 @@ impl CreateTableInterpreter {
         // For the situation above, we implicitly cast the data type when inserting data.
         // The casting and schema checking is in interpreter_insert.rs, function check_schema_cast.
 
-        let table_info = TableInfo::new(
-            &self.plan.database,
-            &self.plan.table,
-            TableIdent::new(table_id, table_id_seq),
-            table_meta,
-        );
-
         let insert_plan = Insert {
             catalog: self.plan.catalog.clone(),
             database: self.plan.database.clone(),
@@ -293,6 +285,20 @@ impl CreateTableInterpreter {
                 Ok(())
             });
 
+        // Collect the column statistics of the new table, as the data is already at hand.
+        if self
+            .ctx
+            .get_settings()
+            .get_enable_analyze_after_create_table_as_select()?
+        {
+            let analyze_desc = AnalyzeDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            };
+            hook_analyze(self.ctx.clone(), &mut pipeline.main_pipeline, analyze_desc).await;
+        }
+
         Ok(pipeline)
     }
 
@@ -326,10 +332,13 @@ impl CreateTableInterpreter {
         let req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
             self.build_attach_request(storage_prefix).await
         } else {
-            self.build_request(stat)
+            self.build_request(stat).await
         }?;
 
         let reply = catalog.create_table(req.clone()).await?;
+        if reply.new_table || self.plan.create_option == CreateOption::CreateOrReplace {
+            self.add_table_location(reply.table_id).await?;
+        }
 
         // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
         if let Some(current_role) = self.ctx.get_current_role() {
@@ -368,8 +377,10 @@ impl CreateTableInterpreter {
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
+    /// - Copy the options of the `LIKE ... INCLUDING OPTIONS` table.
     /// - Update cluster key of table meta.
-    fn build_request(&self, statistics: Option<TableStatistics>) -> Result<CreateTableReq> {
+    #[async_backtrace::framed]
+    async fn build_request(&self, statistics: Option<TableStatistics>) -> Result<CreateTableReq> {
         let fields = self.plan.schema.fields().clone();
         for field in fields.iter() {
             if field.default_expr().is_some() {
@@ -384,8 +395,34 @@ impl CreateTableInterpreter {
         };
         let schema = TableSchemaRefExt::create(fields);
         let mut options = self.plan.options.clone();
+        if let Some((catalog, database, table)) = &self.plan.like_source {
+            if self.plan.like_including_options {
+                let table = self.ctx.get_table(catalog, database, table).await?;
+                // Options given in the statement take precedence over the inherited ones.
+                for (key, value) in table.options() {
+                    if FUSE_TABLE_OPTIONS.contains(&key.as_str()) {
+                        options.entry(key.clone()).or_insert_with(|| value.clone());
+                    }
+                }
+            }
+        }
         let comment = options.remove(OPT_KEY_COMMENT);
 
+        // An explicit `transient` option overrides the default of the database.
+        let transient_key = OPT_KEY_TRANSIENT.to_uppercase();
+        let transient = match options.remove(OPT_KEY_TRANSIENT) {
+            Some(value) => parse_bool_option(OPT_KEY_TRANSIENT, &value)?,
+            None => {
+                options.contains_key(&transient_key)
+                    || (self.plan.engine == Engine::Fuse && self.in_transient_database().await?)
+            }
+        };
+        if transient {
+            options.insert(transient_key, "T".to_string());
+        } else {
+            options.remove(&transient_key);
+        }
+
         let mut table_meta = TableMeta {
             schema: schema.clone(),
             engine: self.plan.engine.to_string(),
@@ -398,11 +435,13 @@ impl CreateTableInterpreter {
             drop_on: None,
             statistics: statistics.unwrap_or_default(),
             comment: comment.unwrap_or_default(),
+            created_on: self.ctx.get_statement_timestamp(),
+            updated_on: self.ctx.get_statement_timestamp(),
             ..Default::default()
         };
 
-        is_valid_block_per_segment(&table_meta.options)?;
-        is_valid_row_per_block(&table_meta.options)?;
+        is_valid_numeric_options(&table_meta.options)?;
+        is_valid_data_retention_time(&table_meta.options)?;
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
@@ -437,9 +476,93 @@ impl CreateTableInterpreter {
         Ok(req)
     }
 
+    #[async_backtrace::framed]
+    async fn in_transient_database(&self) -> Result<bool> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let db = catalog
+            .get_database(&self.plan.tenant, &self.plan.database)
+            .await?;
+        match db.options().get(&OPT_KEY_TRANSIENT.to_uppercase()) {
+            Some(value) => parse_bool_option(OPT_KEY_TRANSIENT, value),
+            None => Ok(false),
+        }
+    }
+
+    /// Record the location of a table having its own storage, so that an attach over
+    /// it can be found without listing all the tables.
+    #[async_backtrace::framed]
+    async fn add_table_location(&self, table_id: u64) -> Result<()> {
+        if let Some(params) = &self.plan.storage_params {
+            UserApiProvider::instance()
+                .table_location_api(&self.plan.tenant)?
+                .add_table(params, table_id)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// A writable attach is only permitted when the storage is exclusively owned by the
+    /// attaching table. Returns why it is not, if the location is inside the storage of
+    /// this deployment or contains it, or overlaps the location of another table having
+    /// its own storage, i.e. an attached table or a table with an external location.
+    #[async_backtrace::framed]
+    async fn shared_attach_location(
+        &self,
+        attach_params: &StorageParams,
+    ) -> Result<Option<String>> {
+        let own_params = self.ctx.get_data_operator()?.params();
+        if is_overlapping_location(attach_params, &own_params) {
+            return Ok(Some(
+                "it is shared with the storage of this deployment".to_string(),
+            ));
+        }
+
+        let location_api = UserApiProvider::instance().table_location_api(&self.plan.tenant)?;
+        let table_ids = location_api.list_overlapping_tables(attach_params).await?;
+        if table_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table_metas = catalog.mget_table_metas_by_ids(&table_ids).await?;
+        for (table_id, table_meta) in table_ids.iter().zip(table_metas) {
+            let Some((_, meta)) = table_meta else {
+                // The table is gone, the record is stale.
+                location_api.remove_table(attach_params, *table_id).await?;
+                continue;
+            };
+            let used = meta
+                .storage_params
+                .as_ref()
+                .is_some_and(|params| is_overlapping_location(attach_params, params));
+            if used && meta.drop_on.is_none() {
+                let table_name = catalog
+                    .mget_table_names_by_ids(&self.plan.tenant, &[*table_id])
+                    .await?
+                    .pop()
+                    .flatten()
+                    .unwrap_or_else(|| table_id.to_string());
+                return Ok(Some(format!("it is used by table {}", table_name)));
+            }
+        }
+        Ok(None)
+    }
+
     async fn build_attach_request(&self, storage_prefix: &str) -> Result<CreateTableReq> {
         // Safe to unwrap in this function, as attach table must have storage params.
         let sp = self.plan.storage_params.as_ref().unwrap();
+        let mut read_only = self.plan.read_only_attach;
+        if !read_only {
+            if let Some(reason) = self.shared_attach_location(sp).await? {
+                let warning = format!(
+                    "table {}.{} is attached READ_ONLY, as the location {} is not exclusively owned: {}",
+                    self.plan.database, self.plan.table, sp, reason
+                );
+                info!("{}", warning);
+                self.ctx.push_warning(warning);
+                read_only = true;
+            }
+        }
         let operator = DataOperator::try_create(sp).await?;
         let operator = operator.operator();
         let reader = MetaReaders::table_snapshot_reader(operator.clone());
@@ -452,7 +575,7 @@ impl CreateTableInterpreter {
         let mut options = self.plan.options.clone();
         options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc.clone());
 
-        if self.plan.read_only_attach {
+        if read_only {
             // mark table as read_only attached
             options.insert(
                 OPT_KEY_TABLE_ATTACHED_READ_ONLY.to_string(),
@@ -488,6 +611,8 @@ impl CreateTableInterpreter {
             field_comments,
             drop_on: None,
             statistics: stat,
+            created_on: self.ctx.get_statement_timestamp(),
+            updated_on: self.ctx.get_statement_timestamp(),
             ..Default::default()
         };
         let req = CreateTableReq {
@@ -508,18 +633,9 @@ impl CreateTableInterpreter {
 /// Table option keys that can occur in 'create table statement'.
 pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     let mut r = HashSet::new();
-    r.insert(FUSE_OPT_KEY_ROW_PER_PAGE);
-    r.insert(FUSE_OPT_KEY_BLOCK_PER_SEGMENT);
-    r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
-    r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
-    r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
-
-    r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
-    r.insert(OPT_KEY_TABLE_COMPRESSION);
-    r.insert(OPT_KEY_STORAGE_FORMAT);
+    r.extend(FUSE_TABLE_OPTIONS);
+
     r.insert(OPT_KEY_DATABASE_ID);
-    r.insert(OPT_KEY_COMMENT);
-    r.insert(OPT_KEY_CHANGE_TRACKING);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -530,7 +646,7 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
 
     r.insert(OPT_KEY_RANDOM_SEED);
 
-    r.insert("transient");
+    r.insert(OPT_KEY_TRANSIENT);
     r
 });
 
@@ -548,27 +664,48 @@ pub fn is_valid_column(name: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn is_valid_block_per_segment(options: &BTreeMap<String, String>) -> Result<()> {
-    // check block_per_segment is not over 1000.
-    if let Some(value) = options.get(FUSE_OPT_KEY_BLOCK_PER_SEGMENT) {
-        let blocks_per_segment = value.parse::<u64>()?;
-        let error_str = "invalid block_per_segment option, can't be over 1000";
-        if blocks_per_segment > 1000 {
-            error!("{}", &error_str);
-            return Err(ErrorCode::TableOptionInvalid(error_str));
+/// Numeric table options and the range of values they accept.
+pub static NUMERIC_TABLE_OPTION_RANGES: LazyLock<BTreeMap<&'static str, SettingRange>> =
+    LazyLock::new(|| {
+        let mut r = BTreeMap::new();
+        r.insert(
+            FUSE_OPT_KEY_BLOCK_PER_SEGMENT,
+            SettingRange::Numeric(1..=1000),
+        );
+        r.insert(
+            FUSE_OPT_KEY_ROW_PER_BLOCK,
+            SettingRange::Numeric(1..=DEFAULT_BLOCK_MAX_ROWS as u64),
+        );
+        r
+    });
+
+pub fn is_valid_numeric_options(options: &BTreeMap<String, String>) -> Result<()> {
+    for (key, range) in NUMERIC_TABLE_OPTION_RANGES.iter() {
+        if let Some(value) = options.get(*key) {
+            let value = value.parse::<u64>()?;
+            if let Err(e) = range.is_within_numeric_range(value) {
+                let error_str = format!("invalid {} option, {}", key, e.message());
+                error!("{}", error_str);
+                return Err(ErrorCode::TableOptionInvalid(error_str));
+            }
         }
     }
 
     Ok(())
 }
 
-pub fn is_valid_row_per_block(options: &BTreeMap<String, String>) -> Result<()> {
-    // check row_per_block can not be over 1000000.
-    if let Some(value) = options.get(FUSE_OPT_KEY_ROW_PER_BLOCK) {
-        let row_per_block = value.parse::<u64>()?;
-        let error_str = "invalid row_per_block option, can't be over 1000000";
-
-        if row_per_block > DEFAULT_BLOCK_MAX_ROWS as u64 {
+pub fn is_valid_data_retention_time(options: &BTreeMap<String, String>) -> Result<()> {
+    // check data_retention_time_in_days is not over the configured maximum.
+    if let Some(value) = options.get(FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS) {
+        let days = value.parse::<u64>()?;
+        let max_days = GlobalConfig::instance()
+            .query
+            .data_retention_time_in_days_max;
+        if days > max_days {
+            let error_str = format!(
+                "invalid data_retention_time_in_days option, can't be over {}",
+                max_days
+            );
             error!("{}", error_str);
             return Err(ErrorCode::TableOptionInvalid(error_str));
         }
@@ -599,3 +736,21 @@ pub fn is_valid_random_seed(options: &BTreeMap<String, String>) -> Result<()> {
     }
     Ok(())
 }
+
+/// Returns true if the two locations are the same, or one of them is inside the other.
+pub fn is_overlapping_location(a: &StorageParams, b: &StorageParams) -> bool {
+    // `Display` of storage params only contains the backend, bucket, root and
+    // endpoint, so different credentials of the same location compare equal.
+    let without_root =
+        |params: &StorageParams| params.clone().map_root(|_| String::new()).to_string();
+    if without_root(a) != without_root(b) {
+        return false;
+    }
+
+    let contains = |outer: &str, inner: &str| {
+        outer.is_empty() || inner == outer || inner.starts_with(&format!("{outer}/"))
+    };
+    let a = a.root().trim_matches('/');
+    let b = b.root().trim_matches('/');
+    contains(a, b) || contains(b, a)
+}
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::DataType;
@@ -52,10 +53,16 @@ impl Interpreter for ExistsTableInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let catalog = self.plan.catalog.as_str();
+        let tenant = self.ctx.get_tenant();
         let database = self.plan.database.as_str();
         let table = self.plan.table.as_str();
-        let exists = self.ctx.get_table(catalog, database, table).await.is_ok();
+        // resolve the catalog the plan is qualified with, rather than the current one.
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let exists = match catalog.exists_table(&tenant, database, table).await {
+            Ok(exists) => exists,
+            Err(err) if err.code() == ErrorCode::UNKNOWN_DATABASE => false,
+            Err(err) => return Err(err),
+        };
         let result = match exists {
             true => 1u8,
             false => 0u8,
@@ -17,7 +17,12 @@ use std::sync::Arc;
 use databend_common_catalog::table::NavigationDescriptor;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
 use databend_common_sql::plans::RevertTablePlan;
+use databend_common_storages_fuse::FuseTable;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -57,6 +62,18 @@ impl Interpreter for RevertTableInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        if self.plan.dry_run {
+            let preview = FuseTable::try_from_table(table.as_ref())?
+                .do_revert_preview(self.ctx.clone(), &self.plan.point)
+                .await?;
+            return PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+                StringType::from_opt_data(vec![preview.current_snapshot_id]),
+                StringType::from_opt_data(vec![preview.target_snapshot_id]),
+                UInt64Type::from_data(vec![preview.rows_added]),
+                UInt64Type::from_data(vec![preview.rows_removed]),
+            ])]);
+        }
+
         let navigation_descriptor = NavigationDescriptor {
             database_name: self.plan.database.clone(),
             point: self.plan.point.clone(),
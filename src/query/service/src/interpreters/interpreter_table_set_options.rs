@@ -28,10 +28,10 @@ use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use log::error;
 
-use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_create_opt;
-use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_data_retention_time;
+use super::interpreter_table_create::is_valid_numeric_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -61,10 +61,10 @@ impl Interpreter for SetOptionsInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         // valid_options_check and do request to meta_srv
         let mut options_map = HashMap::new();
-        // check block_per_segment
-        is_valid_block_per_segment(&self.plan.set_options)?;
-        // check row_per_block
-        is_valid_row_per_block(&self.plan.set_options)?;
+        // check block_per_segment, row_per_block
+        is_valid_numeric_options(&self.plan.set_options)?;
+        // check data_retention_time_in_days
+        is_valid_data_retention_time(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
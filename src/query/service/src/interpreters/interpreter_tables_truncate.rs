@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::TruncateTablePlan;
+use databend_common_sql::plans::TruncateTablesPlan;
+use futures::TryStreamExt;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::TruncateTableInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct TruncateTablesInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: TruncateTablesPlan,
+}
+
+impl TruncateTablesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: TruncateTablesPlan) -> Result<Self> {
+        Ok(TruncateTablesInterpreter { ctx, plan })
+    }
+
+    // Each table is truncated by its own query context, so that the
+    // truncate pipelines do not share the executor of this query.
+    #[async_backtrace::framed]
+    async fn truncate_table(&self, plan: &TruncateTablePlan) -> Result<()> {
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        let interpreter = TruncateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+        let stream = interpreter.execute(ctx).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for TruncateTablesInterpreter {
+    fn name(&self) -> &str {
+        "TruncateTablesInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let mut tables = Vec::with_capacity(self.plan.tables.len());
+        let mut errors = Vec::with_capacity(self.plan.tables.len());
+        for plan in &self.plan.tables {
+            let table = format!("{}.{}.{}", plan.catalog, plan.database, plan.table);
+            match self.truncate_table(plan).await {
+                Ok(_) => errors.push(None),
+                Err(e) if self.plan.if_exists => {
+                    warn!("truncate table {} failed, skip it: {}", table, e);
+                    errors.push(Some(e.message()));
+                }
+                Err(e) => return Err(e),
+            }
+            tables.push(table);
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(tables),
+            StringType::from_opt_data(errors),
+        ])])
+    }
+}
@@ -20,7 +20,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_license::license::Feature::Vacuum;
 use databend_common_license::license_manager::get_license_manager;
-use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_core::query_spill_prefix_with_path;
 use databend_common_sql::plans::VacuumTemporaryFilesPlan;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
 
@@ -59,7 +59,9 @@ impl Interpreter for VacuumTemporaryFilesInterpreter {
 
         let handler = get_vacuum_handler();
 
-        let temporary_files_prefix = query_spill_prefix(self.ctx.get_tenant().tenant_name(), "");
+        let spill_path = self.ctx.get_settings().get_spill_path()?;
+        let temporary_files_prefix =
+            query_spill_prefix_with_path(&spill_path, self.ctx.get_tenant().tenant_name(), "");
         let removed_files = handler
             .do_vacuum_temporary_files(
                 temporary_files_prefix,
@@ -21,6 +21,8 @@ use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::AlterViewPlan;
 use databend_common_sql::Planner;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -51,48 +53,62 @@ impl Interpreter for AlterViewInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
-        if let Ok(tbl) = catalog
+        let tbl = match catalog
             .get_table(&self.plan.tenant, &self.plan.database, &self.plan.view_name)
             .await
         {
-            let mut options = HashMap::new();
-            let subquery = if self.plan.column_names.is_empty() {
-                self.plan.subquery.clone()
-            } else {
-                let mut planner = Planner::new(self.ctx.clone());
-                let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
-                if plan.schema().fields().len() != self.plan.column_names.len() {
-                    return Err(ErrorCode::BadDataArrayLength(format!(
-                        "column name length mismatch, expect {}, got {}",
-                        plan.schema().fields().len(),
-                        self.plan.column_names.len(),
-                    )));
-                }
-                format!(
-                    "select * from ({}) {}({})",
-                    self.plan.subquery,
-                    self.plan.view_name,
-                    self.plan.column_names.join(", ")
-                )
-            };
-            options.insert("query".to_string(), Some(subquery));
+            Ok(tbl) if tbl.engine() == VIEW_ENGINE => tbl,
+            Ok(_) => {
+                return Err(ErrorCode::UnknownView(format!(
+                    "'{}'.'{}' is not a view",
+                    self.plan.database, self.plan.view_name
+                )));
+            }
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => {
+                return Err(ErrorCode::UnknownView(format!(
+                    "Unknown view '{}'.'{}'",
+                    self.plan.database, self.plan.view_name
+                )));
+            }
+            Err(e) => return Err(e),
+        };
 
-            let req = UpsertTableOptionReq {
-                table_id: tbl.get_id(),
-                seq: MatchSeq::Exact(tbl.get_table_info().ident.seq),
-                options,
-            };
+        let mut options = HashMap::new();
+        let subquery = if self.plan.column_names.is_empty() {
+            self.plan.subquery.clone()
+        } else {
+            let mut planner = Planner::new(self.ctx.clone());
+            let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
+            if plan.schema().fields().len() != self.plan.column_names.len() {
+                return Err(ErrorCode::BadDataArrayLength(format!(
+                    "column name length mismatch, expect {}, got {}",
+                    plan.schema().fields().len(),
+                    self.plan.column_names.len(),
+                )));
+            }
+            format!(
+                "select * from ({}) {}({})",
+                self.plan.subquery,
+                self.plan.view_name,
+                self.plan.column_names.join(", ")
+            )
+        };
+        options.insert(QUERY.to_string(), Some(subquery));
 
-            catalog
-                .upsert_table_option(&self.plan.tenant, &self.plan.database, req)
-                .await?;
+        // The definition is swapped in place by a single meta transaction: the view
+        // keeps its table id, so readers never see it missing and its ownership is kept.
+        // The seq match makes a concurrent alter of the same view fail instead of
+        // silently overwriting it.
+        let req = UpsertTableOptionReq {
+            table_id: tbl.get_id(),
+            seq: MatchSeq::Exact(tbl.get_table_info().ident.seq),
+            options,
+        };
 
-            Ok(PipelineBuildResult::create())
-        } else {
-            return Err(ErrorCode::UnknownView(format!(
-                "Unknown view '{}'.'{}'",
-                self.plan.database, self.plan.view_name
-            )));
-        }
+        catalog
+            .upsert_table_option(&self.plan.tenant, &self.plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
     }
 }
@@ -40,24 +40,13 @@ impl CreateViewInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: CreateViewPlan) -> Result<Self> {
         Ok(CreateViewInterpreter { ctx, plan })
     }
-}
-
-#[async_trait::async_trait]
-impl Interpreter for CreateViewInterpreter {
-    fn name(&self) -> &str {
-        "CreateViewInterpreter"
-    }
 
-    fn is_ddl(&self) -> bool {
-        true
-    }
-
-    #[async_backtrace::framed]
-    async fn execute2(&self) -> Result<PipelineBuildResult> {
+    /// Bind the view query against the current schemas, so that a view
+    /// referencing missing tables or columns is rejected at create time.
+    async fn check_query_binding(&self) -> Result<()> {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
         let tenant = self.ctx.get_tenant();
         let table_function = catalog.list_table_functions();
-        let mut options = BTreeMap::new();
         let mut planner = Planner::new(self.ctx.clone());
         let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
         match plan.clone() {
@@ -87,16 +76,43 @@ impl Interpreter for CreateViewInterpreter {
             }
         }
 
+        if !self.plan.column_names.is_empty()
+            && plan.schema().fields().len() != self.plan.column_names.len()
+        {
+            return Err(ErrorCode::BadDataArrayLength(format!(
+                "column name length mismatch, expect {}, got {}",
+                plan.schema().fields().len(),
+                self.plan.column_names.len(),
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let mut options = BTreeMap::new();
+
+        // With `WITH NO SCHEMA BINDING`, the query is only bound when the view is used.
+        if !self.plan.no_schema_binding {
+            self.check_query_binding().await?;
+        }
+
         let subquery = if self.plan.column_names.is_empty() {
             self.plan.subquery.clone()
         } else {
-            if plan.schema().fields().len() != self.plan.column_names.len() {
-                return Err(ErrorCode::BadDataArrayLength(format!(
-                    "column name length mismatch, expect {}, got {}",
-                    plan.schema().fields().len(),
-                    self.plan.column_names.len(),
-                )));
-            }
             format!(
                 "select * from ({}) {}({})",
                 self.plan.subquery,
@@ -21,6 +21,9 @@ use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_sql::plans::DescribeViewPlan;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::BaseTableColumn;
+use databend_common_sql::ColumnEntry;
 use databend_common_storages_fuse::TableContext;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::QUERY;
@@ -61,11 +64,16 @@ impl Interpreter for DescribeViewInterpreter {
         let table = self.ctx.get_table(catalog, database, view).await?;
         let tbl_info = table.get_table_info();
         let engine = table.get_table_info().engine();
-        let schema = if engine == VIEW_ENGINE {
+        let (schema, sources) = if engine == VIEW_ENGINE {
             if let Some(query) = tbl_info.options().get(QUERY) {
                 let mut planner = Planner::new(self.ctx.clone());
                 let (plan, _) = planner.plan_sql(query).await?;
-                infer_table_schema(&plan.schema())
+                let sources = if self.plan.lineage {
+                    Some(column_sources(&plan))
+                } else {
+                    None
+                };
+                Ok((infer_table_schema(&plan.schema())?, sources))
             } else {
                 return Err(ErrorCode::Internal(
                     "Logical error, View Table must have a SelectQuery inside.",
@@ -88,12 +96,48 @@ impl Interpreter for DescribeViewInterpreter {
 
         let (names, types, nulls, default_exprs, extras) = generate_desc_schema(schema);
 
-        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+        let mut columns = vec![
             StringType::from_data(names),
             StringType::from_data(types),
             StringType::from_data(nulls),
             StringType::from_data(default_exprs),
             StringType::from_data(extras),
-        ])])
+        ];
+        if let Some(sources) = sources {
+            columns.push(StringType::from_data(sources));
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(columns)])
     }
 }
+
+/// Resolve each output column of the view query to the base table column it
+/// is read from, as `database.table.column`. Columns computed from
+/// expressions have no single source and are left empty.
+fn column_sources(plan: &Plan) -> Vec<String> {
+    let Plan::Query {
+        metadata,
+        bind_context,
+        ..
+    } = plan
+    else {
+        return vec![String::new(); plan.schema().fields().len()];
+    };
+
+    let metadata = metadata.read();
+    bind_context
+        .columns
+        .iter()
+        .map(|column| match metadata.column(column.index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index,
+                column_name,
+                ..
+            }) => {
+                let table = metadata.table(*table_index);
+                format!("{}.{}.{}", table.database(), table.name(), column_name)
+            }
+            _ => String::new(),
+        })
+        .collect()
+}
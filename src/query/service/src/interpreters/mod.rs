@@ -31,6 +31,7 @@ mod interpreter_copy_into_table;
 mod interpreter_data_mask_create;
 mod interpreter_data_mask_desc;
 mod interpreter_data_mask_drop;
+mod interpreter_database_alter;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
@@ -79,6 +80,7 @@ mod interpreter_select;
 mod interpreter_sequence_create;
 mod interpreter_sequence_drop;
 mod interpreter_setting;
+mod interpreter_settings_show;
 mod interpreter_share_alter_tenants;
 mod interpreter_share_create;
 mod interpreter_share_desc;
@@ -93,6 +95,7 @@ mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
 mod interpreter_stream_create;
+mod interpreter_stream_describe;
 mod interpreter_stream_drop;
 mod interpreter_table_add_column;
 mod interpreter_table_analyze;
@@ -116,6 +119,7 @@ mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_vacuum;
+mod interpreter_tables_truncate;
 mod interpreter_task_alter;
 mod interpreter_task_create;
 mod interpreter_task_describe;
@@ -161,6 +165,7 @@ pub use interpreter_clustering_history::InterpreterClusteringHistory;
 pub use interpreter_data_mask_create::CreateDataMaskInterpreter;
 pub use interpreter_data_mask_desc::DescDataMaskInterpreter;
 pub use interpreter_data_mask_drop::DropDataMaskInterpreter;
+pub use interpreter_database_alter::AlterDatabaseInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
@@ -197,6 +202,7 @@ pub use interpreter_select::SelectInterpreter;
 pub use interpreter_sequence_create::CreateSequenceInterpreter;
 pub use interpreter_sequence_drop::DropSequenceInterpreter;
 pub use interpreter_setting::SettingInterpreter;
+pub use interpreter_settings_show::ShowSettingsInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
 pub use interpreter_share_create::CreateShareInterpreter;
 pub use interpreter_share_drop::DropShareInterpreter;
@@ -210,6 +216,7 @@ pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
 pub use interpreter_stream_create::CreateStreamInterpreter;
+pub use interpreter_stream_describe::DescribeStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
@@ -231,6 +238,7 @@ pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_tables_truncate::TruncateTablesInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
@@ -25,7 +25,7 @@ use databend_common_expression::HashMethodKind;
 use databend_common_expression::HashTableConfig;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_core::query_spill_prefix_with_path;
 use databend_common_sql::executor::physical_plans::AggregateExpand;
 use databend_common_sql::executor::physical_plans::AggregateFinal;
 use databend_common_sql::executor::physical_plans::AggregateFunctionDesc;
@@ -171,8 +171,12 @@ impl PipelineBuilder {
         // If cluster mode, spill write will be completed in exchange serialize, because we need scatter the block data first
         if self.ctx.get_cluster().is_empty() {
             let operator = DataOperator::instance().operator();
-            let location_prefix =
-                query_spill_prefix(self.ctx.get_tenant().tenant_name(), &self.ctx.get_id());
+            let spill_path = self.ctx.get_settings().get_spill_path()?;
+            let location_prefix = query_spill_prefix_with_path(
+                &spill_path,
+                self.ctx.get_tenant().tenant_name(),
+                &self.ctx.get_id(),
+            );
             self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(
                     match params.aggregate_functions.is_empty() {
@@ -28,6 +28,7 @@ impl PipelineBuilder {
             let runtimes = TransformUdfScript::init_runtime(&udf.udf_funcs)?;
             self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(TransformUdfScript::try_create(
+                    self.ctx.clone(),
                     self.func_ctx.clone(),
                     udf.udf_funcs.clone(),
                     runtimes.clone(),
@@ -25,4 +25,5 @@ pub use transforms::TransformCreateSets;
 pub use transforms::TransformLimit;
 pub use transforms::TransformResortAddOn;
 pub use transforms::TransformResortAddOnWithoutSourceSchema;
+pub use transforms::TransformUdfScript;
 pub use transforms::TransformWindow;
@@ -29,7 +29,7 @@ use databend_common_hashtable::HashtableEntryMutRefLike;
 use databend_common_hashtable::HashtableEntryRefLike;
 use databend_common_hashtable::HashtableLike;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_core::query_spill_prefix_with_path;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_settings::FlightCompression;
 use databend_common_storage::DataOperator;
@@ -369,7 +369,9 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
         let params = self.aggregator_params.clone();
 
         let operator = DataOperator::instance().operator();
-        let location_prefix = query_spill_prefix(&self.tenant, &self.ctx.get_id());
+        let spill_path = self.ctx.get_settings().get_spill_path()?;
+        let location_prefix =
+            query_spill_prefix_with_path(&spill_path, &self.tenant, &self.ctx.get_id());
 
         pipeline.add_transform(|input, output| {
             Ok(ProcessorPtr::create(
@@ -417,7 +419,9 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
         let method = &self.method;
         let params = self.aggregator_params.clone();
         let operator = DataOperator::instance().operator();
-        let location_prefix = query_spill_prefix(&self.tenant, &self.ctx.get_id());
+        let spill_path = self.ctx.get_settings().get_spill_path()?;
+        let location_prefix =
+            query_spill_prefix_with_path(&spill_path, &self.tenant, &self.ctx.get_id());
 
         let schema = shuffle_params.schema.clone();
         let local_id = &shuffle_params.executor_id;
@@ -17,7 +17,7 @@ use std::sync::Arc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
-use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_core::query_spill_prefix_with_path;
 use databend_common_sql::plans::JoinType;
 use databend_common_storage::DataOperator;
 
@@ -40,8 +40,12 @@ pub struct ProbeSpillState {
 impl ProbeSpillState {
     pub fn create(ctx: Arc<QueryContext>, probe_state: Arc<HashJoinProbeState>) -> Result<Self> {
         let tenant = ctx.get_tenant();
-        let spill_config =
-            SpillerConfig::create(query_spill_prefix(tenant.tenant_name(), &ctx.get_id()));
+        let spill_path = ctx.get_settings().get_spill_path()?;
+        let spill_config = SpillerConfig::create(query_spill_prefix_with_path(
+            &spill_path,
+            tenant.tenant_name(),
+            &ctx.get_id(),
+        ));
         let operator = DataOperator::instance().operator();
         let spiller = Spiller::create(ctx, operator, spill_config, SpillerType::HashJoinProbe)?;
         Ok(Self {
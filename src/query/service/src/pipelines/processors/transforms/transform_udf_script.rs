@@ -14,10 +14,20 @@
 
 use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::ops::Range;
 use std::sync::Arc;
 
+use arrow_array::new_null_array;
+use arrow_array::Array;
 use arrow_array::RecordBatch;
 use arrow_schema::Schema;
+use arrow_select::concat::concat;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_compress::CompressAlgorithm;
+use databend_common_compress::DecompressDecoder;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::variant_transform::contains_variant;
@@ -27,15 +37,22 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::Value;
+use databend_common_meta_app::principal::UDFOnError;
+use databend_common_metrics::udf::metrics_inc_udf_runtime_created;
+use databend_common_metrics::udf::metrics_inc_udf_runtime_reused;
+use databend_common_metrics::udf::observe_udf_wasm_module_load_time;
 use databend_common_pipeline_transforms::processors::Transform;
 use databend_common_pipeline_transforms::processors::Transformer;
 use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
 use databend_common_sql::plans::UDFType;
+use databend_common_sql::plans::WASM_INLINE_BASE64_PREFIX;
 use parking_lot::RwLock;
 
 use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::OutputPort;
 use crate::pipelines::processors::Processor;
+use crate::sessions::QueryContext;
 
 pub enum ScriptRuntime {
     JavaScript(Arc<RwLock<arrow_udf_js::Runtime>>),
@@ -59,16 +76,58 @@ impl ScriptRuntime {
     }
 
     fn create_wasm_runtime(code_blob: Option<Vec<u8>>) -> Result<Self, ErrorCode> {
-        let decoded_code_blob = code_blob
+        let code_blob = code_blob
             .ok_or_else(|| ErrorCode::UDFDataError("WASM module not provided".to_string()))?;
+        let decoded_code_blob = match code_blob.strip_prefix(WASM_INLINE_BASE64_PREFIX.as_bytes()) {
+            Some(encoded) => Self::decode_inline_wasm_module(encoded)?,
+            None => code_blob,
+        };
 
+        let start = std::time::Instant::now();
         let runtime = arrow_udf_wasm::Runtime::new(&decoded_code_blob).map_err(|err| {
             ErrorCode::UDFDataError(format!("Failed to create WASM runtime for module: {}", err))
         })?;
+        observe_udf_wasm_module_load_time(start.elapsed());
 
         Ok(ScriptRuntime::WebAssembly(Arc::new(RwLock::new(runtime))))
     }
 
+    /// Decode a base64 WASM module embedded in the function body,
+    /// the decoded module may be compressed, detected by its magic bytes.
+    pub fn decode_inline_wasm_module(encoded: &[u8]) -> Result<Vec<u8>, ErrorCode> {
+        let encoded = std::str::from_utf8(encoded).map_err(|err| {
+            ErrorCode::UDFDataError(format!("Invalid inline WASM module: {}", err))
+        })?;
+        let code_blob = BASE64_STANDARD.decode(encoded.trim()).map_err(|err| {
+            ErrorCode::UDFDataError(format!("Failed to decode inline WASM module: {}", err))
+        })?;
+
+        let code_blob = match CompressAlgorithm::from_magic_bytes(&code_blob) {
+            Some(algo) => {
+                log::trace!(
+                    "Decompressing inline WASM module using {:?} algorithm",
+                    algo
+                );
+                DecompressDecoder::new(algo)
+                    .decompress_all(&code_blob)
+                    .map_err(|err| {
+                        ErrorCode::UDFDataError(format!(
+                            "Failed to decompress inline WASM module: {}",
+                            err
+                        ))
+                    })?
+            }
+            None => code_blob,
+        };
+
+        if !code_blob.starts_with(b"\0asm") {
+            return Err(ErrorCode::UDFDataError(
+                "Invalid inline WASM module: missing WASM magic bytes".to_string(),
+            ));
+        }
+        Ok(code_blob)
+    }
+
     pub fn add_function_with_handler(
         &self,
         func: &UdfFunctionDesc,
@@ -125,25 +184,71 @@ impl ScriptRuntime {
     }
 }
 
+/// Returns an error once the query is aborted, it is checked between the calls of a function.
+type CheckAborting = Arc<dyn Fn() -> Result<()> + Send + Sync>;
+
+/// Max number of rows passed to one call of a script function, so that a killed query
+/// stops before the rest of a large block is evaluated.
+const SCRIPT_BATCH_ROWS: usize = 4096;
+
 pub struct TransformUdfScript {
     funcs: Vec<UdfFunctionDesc>,
+    // Name of the column appended by each function, in the same order as `funcs`.
+    output_names: Vec<String>,
     script_runtimes: BTreeMap<String, Arc<ScriptRuntime>>,
+    check_aborting: CheckAborting,
 }
 
 unsafe impl Send for TransformUdfScript {}
 
 impl TransformUdfScript {
     pub fn try_create(
+        ctx: Arc<QueryContext>,
         _func_ctx: FunctionContext,
         funcs: Vec<UdfFunctionDesc>,
         script_runtimes: BTreeMap<String, Arc<ScriptRuntime>>,
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
     ) -> Result<Box<dyn Processor>> {
-        Ok(Transformer::create(input, output, Self {
+        let check_aborting = Arc::new(move || ctx.check_aborting());
+        Ok(Transformer::create(
+            input,
+            output,
+            Self::new(funcs, script_runtimes, check_aborting)?,
+        ))
+    }
+
+    fn new(
+        funcs: Vec<UdfFunctionDesc>,
+        script_runtimes: BTreeMap<String, Arc<ScriptRuntime>>,
+        check_aborting: CheckAborting,
+    ) -> Result<Self> {
+        let output_names = Self::output_names(&funcs)?;
+        Ok(Self {
             funcs,
+            output_names,
             script_runtimes,
-        }))
+            check_aborting,
+        })
+    }
+
+    /// Name the column appended by each function after the function and its output column,
+    /// the columns are appended in this order, after the input columns.
+    pub fn output_names(funcs: &[UdfFunctionDesc]) -> Result<Vec<String>> {
+        let mut names = HashSet::with_capacity(funcs.len());
+        funcs
+            .iter()
+            .map(|func| {
+                let name = format!("{}_{}", func.name, func.output_column);
+                if !names.insert(name.clone()) {
+                    return Err(ErrorCode::UDFDataError(format!(
+                        "Duplicate output column name '{}' of function '{}'",
+                        name, func.name
+                    )));
+                }
+                Ok(name)
+            })
+            .collect()
     }
 }
 
@@ -151,15 +256,22 @@ impl Transform for TransformUdfScript {
     const NAME: &'static str = "UDFScriptTransform";
 
     fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
-        for func in &self.funcs {
+        for (func, output_name) in self.funcs.iter().zip(self.output_names.iter()) {
             let num_rows = data_block.num_rows();
             let block_entries = self.prepare_block_entries(func, &data_block)?;
-            let input_batch = self.create_input_batch(block_entries, num_rows)?;
             let runtime_key = Self::get_runtime_key(func)?;
 
             if let Some(runtime) = self.script_runtimes.get(&runtime_key) {
-                let result_batch = runtime.handle_execution(func, &input_batch)?;
-                self.update_datablock(func, result_batch, &mut data_block)?;
+                let result_batch = Self::execute_in_batches(
+                    runtime,
+                    func,
+                    output_name,
+                    &block_entries,
+                    num_rows,
+                    SCRIPT_BATCH_ROWS,
+                    self.check_aborting.as_ref(),
+                )?;
+                self.update_datablock(func, output_name, result_batch, &mut data_block)?;
             } else {
                 return Err(ErrorCode::UDFDataError(format!(
                     "Failed to find runtime for function '{}' with key: {}",
@@ -203,10 +315,14 @@ impl TransformUdfScript {
             };
 
             let runtime_key = Self::get_runtime_key(func)?;
+            let lang = lang.trim();
             let runtime = match script_runtimes.entry(runtime_key.clone()) {
-                Entry::Occupied(entry) => entry.into_mut().clone(),
+                Entry::Occupied(entry) => {
+                    metrics_inc_udf_runtime_reused(lang);
+                    entry.into_mut().clone()
+                }
                 Entry::Vacant(entry) => {
-                    let new_runtime = ScriptRuntime::try_create(lang.trim(), code_opt)
+                    let new_runtime = ScriptRuntime::try_create(lang, code_opt)
                         .map(Arc::new)
                         .map_err(|err| {
                             ErrorCode::UDFDataError(format!(
@@ -214,6 +330,7 @@ impl TransformUdfScript {
                                 lang, err
                             ))
                         })?;
+                    metrics_inc_udf_runtime_created(lang);
                     entry.insert(new_runtime).clone()
                 }
             };
@@ -252,11 +369,14 @@ impl TransformUdfScript {
         Ok(block_entries)
     }
 
+    /// Build the input record batch of rows `[start, start + num_rows)` of the arguments,
+    /// so that a large block can be fed to the runtime in row windows.
     fn create_input_batch(
-        &self,
-        block_entries: Vec<BlockEntry>,
+        block_entries: &[BlockEntry],
+        start: usize,
         num_rows: usize,
     ) -> Result<RecordBatch> {
+        let block_entries = slice_block_entries(block_entries, start, num_rows);
         let fields = block_entries
             .iter()
             .enumerate()
@@ -277,9 +397,122 @@ impl TransformUdfScript {
         Ok(input_batch)
     }
 
+    /// Evaluate the function on windows of at most `batch_rows` rows, the query is checked
+    /// for cancellation before every call.
+    ///
+    /// With `ON_ERROR = NULL`, a window that fails to evaluate is evaluated again row by row,
+    /// so only the failed rows are NULL.
+    fn execute_in_batches(
+        runtime: &ScriptRuntime,
+        func: &UdfFunctionDesc,
+        output_name: &str,
+        block_entries: &[BlockEntry],
+        num_rows: usize,
+        batch_rows: usize,
+        check_aborting: &dyn Fn() -> Result<()>,
+    ) -> Result<RecordBatch> {
+        let mut batches = Vec::with_capacity(num_rows.div_ceil(batch_rows));
+        // An empty block is still evaluated once, for the schema of the result.
+        for start in (0..num_rows.max(1)).step_by(batch_rows) {
+            check_aborting()?;
+            let len = batch_rows.min(num_rows - start);
+            let input_batch = Self::create_input_batch(block_entries, start, len)?;
+            let batch = match func.on_error {
+                UDFOnError::Abort => runtime.handle_execution(func, &input_batch)?,
+                UDFOnError::Null => match runtime.handle_execution(func, &input_batch) {
+                    Ok(batch) => batch,
+                    Err(_) => Self::execute_rows_or_null(
+                        runtime,
+                        func,
+                        output_name,
+                        block_entries,
+                        start..start + len,
+                        check_aborting,
+                    )?,
+                },
+            };
+            batches.push(batch);
+        }
+
+        if batches.len() == 1 {
+            return Ok(batches.pop().unwrap());
+        }
+        let columns = batches
+            .iter()
+            .map(|b| b.column(0).as_ref())
+            .collect::<Vec<&dyn Array>>();
+        let column = concat(&columns).map_err(|err| {
+            ErrorCode::UDFDataError(format!(
+                "Failed to concat batch results of function '{}': {}",
+                func.name, err
+            ))
+        })?;
+        RecordBatch::try_new(batches[0].schema(), vec![column]).map_err(|err| {
+            ErrorCode::UDFDataError(format!(
+                "Failed to create result batch of function '{}': {}",
+                func.name, err
+            ))
+        })
+    }
+
+    /// Evaluate the function on the `rows` one call each, a row that fails to evaluate
+    /// produces a NULL.
+    fn execute_rows_or_null(
+        runtime: &ScriptRuntime,
+        func: &UdfFunctionDesc,
+        output_name: &str,
+        block_entries: &[BlockEntry],
+        rows: Range<usize>,
+        check_aborting: &dyn Fn() -> Result<()>,
+    ) -> Result<RecordBatch> {
+        let data_schema = DataSchema::new(vec![DataField::new(
+            output_name,
+            func.data_type.as_ref().clone(),
+        )]);
+        let arrow_schema = Arc::new(Schema::from(&data_schema));
+        let arrow_type = arrow_schema.field(0).data_type().clone();
+
+        let mut columns = Vec::with_capacity(rows.len());
+        for row in rows {
+            check_aborting()?;
+            let input_batch = Self::create_input_batch(block_entries, row, 1)?;
+            match runtime.handle_execution(func, &input_batch) {
+                Ok(result_batch) => columns.push(result_batch.column(0).clone()),
+                Err(err) if func.data_type.is_nullable_or_null() => {
+                    log::warn!(
+                        "UDF '{}' failed on row {}, returns NULL instead: {}",
+                        func.name,
+                        row,
+                        err
+                    );
+                    columns.push(new_null_array(&arrow_type, 1));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        let columns = columns
+            .iter()
+            .map(|c| c.as_ref())
+            .collect::<Vec<&dyn Array>>();
+        let column = concat(&columns).map_err(|err| {
+            ErrorCode::UDFDataError(format!(
+                "Failed to concat row results of function '{}': {}",
+                func.name, err
+            ))
+        })?;
+        RecordBatch::try_new(arrow_schema, vec![column]).map_err(|err| {
+            ErrorCode::UDFDataError(format!(
+                "Failed to create result batch of function '{}': {}",
+                func.name, err
+            ))
+        })
+    }
+
     fn update_datablock(
         &self,
         func: &UdfFunctionDesc,
+        output_name: &str,
         result_batch: RecordBatch,
         data_block: &mut DataBlock,
     ) -> Result<()> {
@@ -317,11 +550,351 @@ impl TransformUdfScript {
 
         if col.data_type != func.data_type.as_ref().clone() {
             return Err(ErrorCode::UDFDataError(format!(
-                "Function '{}' returned column with data type {:?} but expected {:?}",
-                func.name, col.data_type, func.data_type
+                "Function '{}' returned column '{}' with data type {:?} but expected {:?}",
+                func.name, output_name, col.data_type, func.data_type
             )));
         }
         data_block.add_column(col);
         Ok(())
     }
 }
+
+/// Take rows `[start, start + len)` of the entries, scalar entries are kept as they are.
+pub fn slice_block_entries(entries: &[BlockEntry], start: usize, len: usize) -> Vec<BlockEntry> {
+    entries
+        .iter()
+        .map(|entry| match &entry.value {
+            Value::Scalar(s) => BlockEntry::new(entry.data_type.clone(), Value::Scalar(s.clone())),
+            Value::Column(c) => BlockEntry::new(
+                entry.data_type.clone(),
+                Value::Column(c.slice(start..start + len)),
+            ),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use base64::prelude::BASE64_STANDARD;
+    use base64::Engine;
+    use databend_common_compress::CompressAlgorithm;
+    use databend_common_compress::CompressCodec;
+    use databend_common_exception::ErrorCode;
+    use databend_common_exception::Result;
+    use databend_common_expression::types::DataType;
+    use databend_common_expression::types::Int32Type;
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::types::NumberScalar;
+    use databend_common_expression::types::StringType;
+    use databend_common_expression::BlockEntry;
+    use databend_common_expression::DataBlock;
+    use databend_common_expression::FromData;
+    use databend_common_expression::Scalar;
+    use databend_common_expression::Value;
+    use databend_common_meta_app::principal::UDFOnError;
+    use databend_common_pipeline_transforms::processors::Transform;
+    use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
+    use databend_common_sql::plans::UDFType;
+    use databend_common_sql::plans::WASM_INLINE_BASE64_PREFIX;
+
+    use super::slice_block_entries;
+    use super::CheckAborting;
+    use super::ScriptRuntime;
+    use super::TransformUdfScript;
+
+    fn entries(num_rows: usize) -> Vec<BlockEntry> {
+        let ints = (0..num_rows as i32).collect::<Vec<_>>();
+        let strings = (0..num_rows).map(|i| format!("s{i}")).collect::<Vec<_>>();
+        vec![
+            BlockEntry::new(
+                DataType::Number(NumberDataType::Int32),
+                Value::Column(Int32Type::from_data(ints)),
+            ),
+            BlockEntry::new(
+                DataType::String,
+                Value::Column(StringType::from_data(strings)),
+            ),
+            BlockEntry::new(
+                DataType::Number(NumberDataType::Int32),
+                Value::Scalar(Scalar::Number(NumberScalar::Int32(7))),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_slice_block_entries() {
+        let sliced = slice_block_entries(&entries(10), 3, 4);
+        assert_eq!(sliced.len(), 3);
+        assert_eq!(
+            sliced[0].value,
+            Value::Column(Int32Type::from_data(vec![3, 4, 5, 6]))
+        );
+        assert_eq!(
+            sliced[1].value,
+            Value::Column(StringType::from_data(vec!["s3", "s4", "s5", "s6"]))
+        );
+        assert_eq!(
+            sliced[2].value,
+            Value::Scalar(Scalar::Number(NumberScalar::Int32(7)))
+        );
+    }
+
+    #[test]
+    fn test_create_input_batch_window() -> Result<()> {
+        let entries = entries(10);
+        let full = TransformUdfScript::create_input_batch(&entries, 0, 10)?;
+        assert_eq!(full.num_rows(), 10);
+
+        // Every window matches the same rows of the whole batch.
+        let mut rows = 0;
+        for start in (0..10).step_by(4) {
+            let len = 4.min(10 - start);
+            let window = TransformUdfScript::create_input_batch(&entries, start, len)?;
+            assert_eq!(window.num_rows(), len);
+            assert_eq!(window, full.slice(start, len));
+            rows += window.num_rows();
+        }
+        assert_eq!(rows, full.num_rows());
+
+        Ok(())
+    }
+
+    fn fail_on_two(on_error: UDFOnError) -> UdfFunctionDesc {
+        let code = r#"
+export function fail_on_two(x) {
+    if (x === 2) {
+        throw new Error("two");
+    }
+    return x;
+}
+"#;
+        UdfFunctionDesc {
+            name: "fail_on_two".to_string(),
+            func_name: "fail_on_two".to_string(),
+            output_column: 1,
+            arg_indices: vec![0],
+            arg_exprs: vec!["x".to_string()],
+            data_type: Box::new(DataType::Nullable(Box::new(DataType::Number(
+                NumberDataType::Int32,
+            )))),
+            udf_type: UDFType::Script(("javascript".to_string(), "".to_string(), code.to_string())),
+            on_error,
+        }
+    }
+
+    fn never_aborted() -> CheckAborting {
+        Arc::new(|| Ok(()))
+    }
+
+    /// Aborts the query once it has been checked `checks` times.
+    fn aborted_after(checks: usize) -> (Arc<AtomicUsize>, CheckAborting) {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let check_counter = counter.clone();
+        let check_aborting: CheckAborting = Arc::new(move || {
+            if check_counter.fetch_add(1, Ordering::Relaxed) >= checks {
+                return Err(ErrorCode::AbortedQuery("killed"));
+            }
+            Ok(())
+        });
+        (counter, check_aborting)
+    }
+
+    fn transform_with(func: UdfFunctionDesc) -> Result<DataBlock> {
+        let funcs = vec![func];
+        let script_runtimes = TransformUdfScript::init_runtime(&funcs)?;
+        let mut transform = TransformUdfScript::new(funcs, script_runtimes, never_aborted())?;
+        let block = DataBlock::new_from_columns(vec![Int32Type::from_data(vec![0, 1, 2, 3])]);
+        transform.transform(block)
+    }
+
+    #[test]
+    fn test_on_error_null() -> Result<()> {
+        let block = transform_with(fail_on_two(UDFOnError::Null))?;
+        assert_eq!(block.num_columns(), 2);
+        assert_eq!(
+            block.get_by_offset(1).value,
+            Value::Column(Int32Type::from_opt_data(vec![
+                Some(0),
+                Some(1),
+                None,
+                Some(3)
+            ]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_on_error_abort() {
+        assert!(transform_with(fail_on_two(UDFOnError::Abort)).is_err());
+    }
+
+    fn int_entries(values: Vec<i32>) -> Vec<BlockEntry> {
+        vec![BlockEntry::new(
+            DataType::Number(NumberDataType::Int32),
+            Value::Column(Int32Type::from_data(values)),
+        )]
+    }
+
+    #[test]
+    fn test_execute_in_batches() -> Result<()> {
+        // A failed window falls back to row by row, only the failed row is NULL.
+        let func = fail_on_two(UDFOnError::Null);
+        let script_runtimes = TransformUdfScript::init_runtime(std::slice::from_ref(&func))?;
+        let runtime = script_runtimes.values().next().unwrap();
+        let entries = int_entries((0..10).collect());
+        let (checks, check_aborting) = aborted_after(usize::MAX);
+        let batch = TransformUdfScript::execute_in_batches(
+            runtime,
+            &func,
+            "r",
+            &entries,
+            10,
+            4,
+            check_aborting.as_ref(),
+        )?;
+        assert_eq!(batch.num_rows(), 10);
+        assert_eq!(batch.column(0).null_count(), 1);
+        assert!(batch.column(0).is_null(2));
+        // Checked before the 3 windows and before the 4 rows of the failed window.
+        assert_eq!(checks.load(Ordering::Relaxed), 3 + 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_aborting() -> Result<()> {
+        let func = plus("plus_one", 1, 1);
+        let script_runtimes = TransformUdfScript::init_runtime(std::slice::from_ref(&func))?;
+        let runtime = script_runtimes.values().next().unwrap();
+        let entries = int_entries((0..10).collect());
+
+        // The query is killed while the block is evaluated, after 2 of its 3 windows.
+        let (checks, check_aborting) = aborted_after(2);
+        let err = TransformUdfScript::execute_in_batches(
+            runtime,
+            &func,
+            "r",
+            &entries,
+            10,
+            4,
+            check_aborting.as_ref(),
+        )
+        .unwrap_err();
+        assert_eq!(ErrorCode::ABORTED_QUERY, err.code());
+        assert_eq!(checks.load(Ordering::Relaxed), 3);
+
+        // The row by row fallback stops at the next row once the query is killed.
+        let func = fail_on_two(UDFOnError::Null);
+        let script_runtimes = TransformUdfScript::init_runtime(std::slice::from_ref(&func))?;
+        let runtime = script_runtimes.values().next().unwrap();
+        let (checks, check_aborting) = aborted_after(2);
+        let err = TransformUdfScript::execute_rows_or_null(
+            runtime,
+            &func,
+            "r",
+            &entries,
+            0..4,
+            check_aborting.as_ref(),
+        )
+        .unwrap_err();
+        assert_eq!(ErrorCode::ABORTED_QUERY, err.code());
+        assert_eq!(checks.load(Ordering::Relaxed), 3);
+        Ok(())
+    }
+
+    fn plus(name: &str, output_column: usize, delta: i32) -> UdfFunctionDesc {
+        let code = format!("export function {name}(x) {{ return x + {delta}; }}");
+        UdfFunctionDesc {
+            name: name.to_string(),
+            func_name: name.to_string(),
+            output_column,
+            arg_indices: vec![0],
+            arg_exprs: vec!["x".to_string()],
+            data_type: Box::new(DataType::Nullable(Box::new(DataType::Number(
+                NumberDataType::Int32,
+            )))),
+            udf_type: UDFType::Script(("javascript".to_string(), "".to_string(), code)),
+            on_error: UDFOnError::Abort,
+        }
+    }
+
+    #[test]
+    fn test_output_names() -> Result<()> {
+        let funcs = vec![plus("plus_one", 1, 1), plus("plus_ten", 2, 10)];
+        let script_runtimes = TransformUdfScript::init_runtime(&funcs)?;
+        let mut transform = TransformUdfScript::new(funcs, script_runtimes, never_aborted())?;
+        assert_eq!(transform.output_names, vec!["plus_one_1", "plus_ten_2"]);
+
+        let block = DataBlock::new_from_columns(vec![Int32Type::from_data(vec![0, 1])]);
+        let block = transform.transform(block)?;
+        assert_eq!(block.num_columns(), 3);
+        assert_eq!(
+            block.get_by_offset(1).value,
+            Value::Column(Int32Type::from_opt_data(vec![Some(1), Some(2)]))
+        );
+        assert_eq!(
+            block.get_by_offset(2).value,
+            Value::Column(Int32Type::from_opt_data(vec![Some(10), Some(11)]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_output_names() {
+        let funcs = vec![plus("plus_one", 1, 1), plus("plus_one", 1, 1)];
+        let err = TransformUdfScript::output_names(&funcs).unwrap_err();
+        assert!(
+            err.message()
+                .contains("Duplicate output column name 'plus_one_1'")
+        );
+    }
+
+    // Header of an empty WASM module: magic bytes and version 1.
+    const EMPTY_WASM_MODULE: &[u8] = b"\0asm\x01\0\0\0";
+
+    #[test]
+    fn test_decode_inline_wasm_module() -> Result<()> {
+        let encoded = BASE64_STANDARD.encode(EMPTY_WASM_MODULE);
+        let decoded = ScriptRuntime::decode_inline_wasm_module(encoded.as_bytes())?;
+        assert_eq!(decoded, EMPTY_WASM_MODULE);
+
+        // Compressed module is detected by the magic bytes of the decoded content.
+        let compressed =
+            CompressCodec::from(CompressAlgorithm::Zstd).compress_all(EMPTY_WASM_MODULE)?;
+        let encoded = BASE64_STANDARD.encode(compressed);
+        let decoded = ScriptRuntime::decode_inline_wasm_module(encoded.as_bytes())?;
+        assert_eq!(decoded, EMPTY_WASM_MODULE);
+
+        assert!(ScriptRuntime::decode_inline_wasm_module(b"not base64!").is_err());
+        let encoded = BASE64_STANDARD.encode(b"not a wasm module");
+        assert!(ScriptRuntime::decode_inline_wasm_module(encoded.as_bytes()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_inline_wasm_module_runtime() {
+        // The inline module goes straight to the WASM runtime, a module without
+        // the UDF exports is rejected by the runtime rather than by decoding.
+        let code = format!(
+            "{}{}",
+            WASM_INLINE_BASE64_PREFIX,
+            BASE64_STANDARD.encode(EMPTY_WASM_MODULE)
+        );
+        let err = ScriptRuntime::try_create("wasm", Some(code.into_bytes()))
+            .err()
+            .unwrap();
+        assert!(err.message().contains("Failed to create WASM runtime"));
+
+        let code = format!("{}not base64!", WASM_INLINE_BASE64_PREFIX);
+        let err = ScriptRuntime::try_create("wasm", Some(code.into_bytes()))
+            .err()
+            .unwrap();
+        assert!(
+            err.message()
+                .contains("Failed to decode inline WASM module")
+        );
+    }
+}
@@ -157,6 +157,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .map_err(|_| Status::internal("authorization not parsable"))?;
         resp.metadata_mut().insert("authorization", metadata);
 
+        session.set_application_name(FlightSqlServiceImpl::get_header_value(
+            request.metadata(),
+            "application_name",
+        ));
+
         session.get_status().write().is_native_client =
             FlightSqlServiceImpl::get_header_value(request.metadata(), "bendsql").is_some();
 
@@ -60,6 +60,7 @@ use crate::sessions::SessionType;
 const DEDUPLICATE_LABEL: &str = "X-DATABEND-DEDUPLICATE-LABEL";
 const USER_AGENT: &str = "User-Agent";
 const QUERY_ID: &str = "X-DATABEND-QUERY-ID";
+const APPLICATION_NAME: &str = "X-DATABEND-APPLICATION-NAME";
 
 const TRACE_PARENT: &str = "traceparent";
 
@@ -229,6 +230,10 @@ impl<E> HTTPSessionEndpoint<E> {
             let tenant = Tenant::new_or_err(tenant_id.clone(), func_name!())?;
             session.set_current_tenant(tenant);
         }
+        if let Some(name) = req.headers().get(APPLICATION_NAME) {
+            // Ignore a name that is not visible ASCII instead of failing the request.
+            session.set_application_name(name.to_str().ok().map(|name| name.to_string()));
+        }
         let node_id = ctx.get_cluster().local_id.clone();
 
         self.auth_manager
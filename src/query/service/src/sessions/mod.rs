@@ -39,6 +39,11 @@ pub use queue_mgr::QueueManager;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
+pub use session_mgr::QueryCompletion;
+pub use session_mgr::QueryCompletionHook;
+pub use session_mgr::QueryRouting;
+pub use session_mgr::QueryRoutingHook;
+pub use session_mgr::RoutingHint;
 pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
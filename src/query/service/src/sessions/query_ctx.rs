@@ -28,6 +28,8 @@ use std::time::Instant;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
+use chrono::DateTime;
+use chrono::Utc;
 use chrono_tz::Tz;
 use dashmap::mapref::multiple::RefMulti;
 use dashmap::DashMap;
@@ -60,10 +62,12 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_io::prelude::FormatSettings;
+use databend_common_meta_app::principal::FileFormatOptionsAst;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::COPY_MAX_FILES_COMMIT_MSG;
@@ -76,6 +80,7 @@ use databend_common_metrics::storage::*;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::InputError;
 use databend_common_settings::Settings;
+use databend_common_sql::binder::resolve_stage_location;
 use databend_common_sql::IndexType;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
@@ -255,6 +260,16 @@ impl QueryContext {
         self.shared.session.session_ctx.get_client_host()
     }
 
+    /// The application name set with the `application_name` setting, or else the one
+    /// passed by the client at connect.
+    pub fn get_application_name(&self) -> Option<String> {
+        self.get_settings()
+            .get_application_name()
+            .ok()
+            .filter(|name| !name.is_empty())
+            .or_else(|| self.shared.session.session_ctx.get_application_name())
+    }
+
     pub fn get_affect(self: &Arc<Self>) -> Option<QueryAffect> {
         self.shared.get_affect()
     }
@@ -539,6 +554,10 @@ impl TableContext for QueryContext {
         self.shared.init_query_id.as_ref().read().clone()
     }
 
+    fn get_statement_timestamp(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from(self.shared.created_time)
+    }
+
     fn get_current_catalog(&self) -> String {
         self.shared.get_current_catalog()
     }
@@ -647,6 +666,8 @@ impl TableContext for QueryContext {
             external_server_request_batch_rows,
             geometry_output_format,
             parse_datetime_ignore_remainder,
+
+            statement_timestamp: Some(self.get_statement_timestamp()),
         })
     }
 
@@ -774,6 +795,45 @@ impl TableContext for QueryContext {
             }
         }
     }
+
+    /// Resolve a stage location (`[@]<stage>[/<path>]`) together with the file format to read
+    /// it with.
+    ///
+    /// The format comes from, in order:
+    /// - the named file format given by `format_name`, which can not be combined with `type`;
+    /// - the inline options, e.g. `type = csv field_delimiter = '|'`;
+    /// - the file format of the stage itself, if no option is given.
+    #[async_backtrace::framed]
+    async fn resolve_stage_and_format(
+        &self,
+        location: &str,
+        format_ref: &FileFormatOptionsAst,
+    ) -> Result<(StageInfo, FileFormatParams)> {
+        let location = location.strip_prefix('@').unwrap_or(location);
+        let (stage_info, _) = resolve_stage_location(self, location).await?;
+
+        let mut options = format_ref.options.clone();
+        let params = match options.remove("format_name") {
+            Some(_) if options.contains_key("type") || options.contains_key("format") => {
+                return Err(ErrorCode::IllegalFileFormat(
+                    "Invalid FILE_FORMAT options: both NAME and TYPE option are present. \
+                    Please only use one of them to specify the file format.",
+                ));
+            }
+            Some(name) if options.is_empty() => self.get_file_format(&name).await?,
+            Some(_) => {
+                return Err(ErrorCode::IllegalFileFormat(format!(
+                    "Invalid FILE_FORMAT options: options {:?} can not be used together with NAME.",
+                    options.keys().collect::<Vec<_>>()
+                )));
+            }
+            None if options.is_empty() => stage_info.file_format_params.clone(),
+            None => FileFormatParams::try_from(FileFormatOptionsAst::new(options))?,
+        };
+
+        Ok((stage_info, params))
+    }
+
     async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection> {
         self.shared.get_connection(name).await
     }
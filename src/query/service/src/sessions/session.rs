@@ -184,6 +184,14 @@ impl Session {
         self.session_ctx.set_client_host(host);
     }
 
+    pub fn set_application_name(self: &Arc<Self>, name: Option<String>) {
+        self.session_ctx.set_application_name(name);
+    }
+
+    pub fn get_application_name(self: &Arc<Self>) -> Option<String> {
+        self.session_ctx.get_application_name()
+    }
+
     pub fn set_current_database(self: &Arc<Self>, database_name: String) {
         self.session_ctx.set_current_database(database_name);
     }
@@ -192,6 +200,10 @@ impl Session {
         self.session_ctx.get_current_database()
     }
 
+    pub fn set_current_catalog(self: &Arc<Self>, catalog_name: String) -> Result<()> {
+        self.session_ctx.set_current_catalog(catalog_name)
+    }
+
     pub fn get_current_catalog(self: &Arc<Self>) -> String {
         self.session_ctx.get_current_catalog()
     }
@@ -18,6 +18,7 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::RoleInfo;
@@ -35,7 +36,6 @@ use crate::sessions::QueryContextShared;
 pub struct SessionContext {
     abort: AtomicBool,
     settings: Arc<Settings>,
-    current_catalog: RwLock<String>,
     current_database: RwLock<String>,
     // The current tenant can be determined by databend-query's config file, or by X-DATABEND-TENANT
     // if it's in management mode. If databend-query is not in management mode, the current tenant
@@ -60,6 +60,8 @@ pub struct SessionContext {
     secondary_roles: RwLock<Option<Vec<String>>>,
     // The client IP from the client.
     client_host: RwLock<Option<String>>,
+    // The application name reported by the client driver.
+    application_name: RwLock<Option<String>>,
     io_shutdown_tx: RwLock<Option<Box<dyn FnOnce() + Send + Sync + 'static>>>,
     query_context_shared: RwLock<Weak<QueryContextShared>>,
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
@@ -80,7 +82,7 @@ impl SessionContext {
             secondary_roles: Default::default(),
             current_tenant: Default::default(),
             client_host: Default::default(),
-            current_catalog: RwLock::new("default".to_string()),
+            application_name: Default::default(),
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
@@ -104,16 +106,16 @@ impl SessionContext {
         self.settings.clone()
     }
 
-    // Get current catalog name.
+    // Get current catalog name, which is kept in the `default_catalog` setting.
     pub fn get_current_catalog(&self) -> String {
-        let lock = self.current_catalog.read();
-        lock.clone()
+        self.settings
+            .get_default_catalog()
+            .unwrap_or_else(|_| CATALOG_DEFAULT.to_string())
     }
 
     // Set current catalog.
-    pub fn set_current_catalog(&self, catalog_name: String) {
-        let mut lock = self.current_catalog.write();
-        *lock = catalog_name
+    pub fn set_current_catalog(&self, catalog_name: String) -> Result<()> {
+        self.settings.set_default_catalog(catalog_name)
     }
 
     // Get current database.
@@ -209,6 +211,16 @@ impl SessionContext {
         *lock = sock
     }
 
+    pub fn get_application_name(&self) -> Option<String> {
+        let lock = self.application_name.read();
+        lock.clone()
+    }
+
+    pub fn set_application_name(&self, name: Option<String>) {
+        let mut lock = self.application_name.write();
+        *lock = name
+    }
+
     pub fn set_io_shutdown_tx<F: FnOnce() + Send + Sync + 'static>(&self, f: F) {
         let mut lock = self.io_shutdown_tx.write();
 
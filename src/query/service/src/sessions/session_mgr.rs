@@ -26,13 +26,17 @@ use databend_common_base::base::tokio;
 use databend_common_base::base::GlobalInstance;
 use databend_common_base::base::SignalStream;
 use databend_common_base::runtime::profile::Profile;
+use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::ProcessInfoState;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_metrics::session::*;
 use databend_common_settings::Settings;
+use databend_common_sql::plans::Plan;
 use futures::future::Either;
 use futures::StreamExt;
 use log::info;
@@ -44,6 +48,50 @@ use crate::sessions::SessionContext;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
 
+/// The outcome of a finished statement, passed to every hook registered with
+/// [`SessionManager::register_query_completion_hook`].
+#[derive(Clone, Debug)]
+pub struct QueryCompletion {
+    pub query_id: String,
+    pub query_kind: QueryKind,
+    pub duration_ms: i64,
+    /// `None` if the statement succeeded, otherwise the code of the error it failed with.
+    pub error_code: Option<u16>,
+}
+
+pub type QueryCompletionHook = Arc<dyn Fn(&QueryCompletion) + Send + Sync>;
+
+/// Where a statement may be served in a primary/replica deployment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoutingHint {
+    /// The statement only reads, a replica can serve it.
+    ReadOnly,
+    /// The statement writes, it must go to the primary.
+    Write,
+}
+
+impl From<&Plan> for RoutingHint {
+    fn from(plan: &Plan) -> Self {
+        if plan.is_read_only() {
+            RoutingHint::ReadOnly
+        } else {
+            RoutingHint::Write
+        }
+    }
+}
+
+/// The routing hint of a statement once it is planned, passed to every hook registered
+/// with [`SessionManager::register_query_routing_hook`].
+#[derive(Clone, Debug)]
+pub struct QueryRouting {
+    pub session_id: String,
+    pub query_id: String,
+    pub query_kind: QueryKind,
+    pub hint: RoutingHint,
+}
+
+pub type QueryRoutingHook = Arc<dyn Fn(&QueryRouting) + Send + Sync>;
+
 pub struct SessionManager {
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Weak<Session>>>>,
@@ -52,6 +100,8 @@ pub struct SessionManager {
     // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+    pub(in crate::sessions) query_completion_hooks: Arc<RwLock<Vec<QueryCompletionHook>>>,
+    pub(in crate::sessions) query_routing_hooks: Arc<RwLock<Vec<QueryRoutingHook>>>,
 }
 
 impl SessionManager {
@@ -69,6 +119,8 @@ impl SessionManager {
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
+            query_completion_hooks: Arc::new(RwLock::new(vec![])),
+            query_routing_hooks: Arc::new(RwLock::new(vec![])),
         })
     }
 
@@ -76,6 +128,33 @@ impl SessionManager {
         GlobalInstance::get()
     }
 
+    /// Register a hook that is called once for every statement that finishes,
+    /// successfully or not, e.g. to feed an audit or history table.
+    pub fn register_query_completion_hook(&self, hook: QueryCompletionHook) {
+        self.query_completion_hooks.write().push(hook);
+    }
+
+    pub fn on_query_completed(&self, completion: &QueryCompletion) {
+        // Clone the hooks so that a hook may register another one without dead lock.
+        let hooks = self.query_completion_hooks.read().clone();
+        for hook in hooks.iter() {
+            hook(completion);
+        }
+    }
+
+    /// Register a hook that is called with the routing hint of every statement
+    /// once it is planned and before it runs, e.g. to forward read-only statements to a replica.
+    pub fn register_query_routing_hook(&self, hook: QueryRoutingHook) {
+        self.query_routing_hooks.write().push(hook);
+    }
+
+    pub fn on_query_routed(&self, routing: &QueryRouting) {
+        let hooks = self.query_routing_hooks.read().clone();
+        for hook in hooks.iter() {
+            hook(routing);
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn create_session(&self, typ: SessionType) -> Result<Arc<Session>> {
         if !matches!(typ, SessionType::Dummy | SessionType::FlightRPC) {
@@ -158,6 +237,37 @@ impl SessionManager {
         Ok(session)
     }
 
+    /// Force a setting onto all the live sessions, e.g. lowering the memory limit in an emergency.
+    /// The caller session must have the global SUPER privilege.
+    #[async_backtrace::framed]
+    pub async fn apply_to_all(
+        &self,
+        session: &Arc<Session>,
+        setting: &str,
+        value: &str,
+    ) -> Result<()> {
+        session
+            .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+            .await?;
+
+        // Same as `processes_info`, drop the read lock before upgrading the sessions.
+        let active_sessions = {
+            let active_sessions_guard = self.active_sessions.read();
+            active_sessions_guard.values().cloned().collect::<Vec<_>>()
+        };
+
+        for weak_ptr in active_sessions {
+            if let Some(active_session) = weak_ptr.upgrade() {
+                active_session
+                    .get_settings()
+                    .set_setting(setting.to_string(), value.to_string())?;
+            }
+        }
+
+        info!("Applied setting {} = {} to all sessions", setting, value);
+        Ok(())
+    }
+
     pub fn get_session_by_id(&self, id: &str) -> Option<Arc<Session>> {
         let sessions = self.active_sessions.read();
         sessions.get(id).and_then(|weak_ptr| weak_ptr.upgrade())
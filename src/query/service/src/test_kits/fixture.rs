@@ -321,6 +321,8 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            like_source: None,
+            like_including_options: false,
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -346,6 +348,8 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            like_source: None,
+            like_including_options: false,
             cluster_key: None,
         }
     }
@@ -382,6 +386,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            like_source: None,
+            like_including_options: false,
             cluster_key: None,
         }
     }
@@ -418,6 +424,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            like_source: None,
+            like_including_options: false,
             cluster_key: None,
         }
     }
@@ -463,6 +471,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            like_source: None,
+            like_including_options: false,
             cluster_key: None,
         }
     }
@@ -0,0 +1,51 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CatalogType;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_catalog_manager_default_catalog_fast_path() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let catalog_manager = CatalogManager::instance();
+
+    // An empty tenant can never form a metasrv key, so resolving the default
+    // catalog with it only succeeds if no metasrv read is involved.
+    let catalog = catalog_manager
+        .get_catalog("", CATALOG_DEFAULT, ctx.txn_mgr())
+        .await?;
+    assert_eq!(catalog.info().catalog_type(), CatalogType::Default);
+
+    let catalog = catalog_manager.get_default_catalog(ctx.txn_mgr())?;
+    assert_eq!(catalog.info().catalog_type(), CatalogType::Default);
+
+    // A named catalog is looked up in metasrv.
+    let tenant = ctx.get_tenant();
+    let res = catalog_manager
+        .get_catalog(tenant.tenant_name(), "not_exists_catalog", ctx.txn_mgr())
+        .await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::UnknownCatalog("").code())
+    );
+
+    Ok(())
+}
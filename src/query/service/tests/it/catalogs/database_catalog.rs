@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use databend_common_base::base::tokio;
+use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::TableDataType;
@@ -31,8 +32,11 @@ use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableStatistics;
+use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::tenant::Tenant;
 use databend_query::catalogs::Catalog;
+use futures_util::TryStreamExt;
 
 use crate::tests::create_catalog;
 
@@ -200,3 +204,306 @@ async fn test_catalogs_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_catalogs_create_table_with_info() -> Result<()> {
+    let tenant_name = "test";
+    let tenant = Tenant::new_literal(tenant_name);
+    let catalog = create_catalog().await?;
+
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "number",
+        TableDataType::Number(NumberDataType::UInt64),
+    )]));
+
+    let req = CreateTableReq {
+        create_option: CreateOption::Create,
+        name_ident: TableNameIdent {
+            tenant: tenant.clone(),
+            db_name: "default".to_string(),
+            table_name: "test_table_info".to_string(),
+        },
+        table_meta: TableMeta {
+            schema,
+            engine: "MEMORY".to_string(),
+            options: maplit::btreemap! {"opt‐1".into() => "val-1".into()},
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        },
+        as_dropped: false,
+    };
+    let (reply, table_info) = catalog.create_table_with_info(req).await?;
+    assert!(reply.new_table);
+    assert_eq!(table_info.ident.table_id, reply.table_id);
+
+    // The returned table info must be the same as the one fetched afterwards.
+    let table = catalog
+        .get_table(&tenant, "default", "test_table_info")
+        .await?;
+    assert_eq!(&table_info, table.get_table_info());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_catalogs_truncate_table_reply() -> Result<()> {
+    let tenant_name = "test";
+    let tenant = Tenant::new_literal(tenant_name);
+    let catalog = create_catalog().await?;
+
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "number",
+        TableDataType::Number(NumberDataType::UInt64),
+    )]));
+
+    let req = CreateTableReq {
+        create_option: CreateOption::Create,
+        name_ident: TableNameIdent {
+            tenant: tenant.clone(),
+            db_name: "default".to_string(),
+            table_name: "test_truncate_table".to_string(),
+        },
+        table_meta: TableMeta {
+            schema,
+            engine: "MEMORY".to_string(),
+            created_on: Utc::now(),
+            statistics: TableStatistics {
+                number_of_rows: 100,
+                data_bytes: 4000,
+                compressed_data_bytes: 1000,
+                index_data_bytes: 24,
+                number_of_segments: Some(1),
+                number_of_blocks: Some(3),
+            },
+            ..TableMeta::default()
+        },
+        as_dropped: false,
+    };
+    catalog.create_table(req).await?;
+
+    let table = catalog
+        .get_table(&tenant, "default", "test_truncate_table")
+        .await?;
+    let table_info = table.get_table_info();
+    let reply = catalog
+        .truncate_table(table_info, TruncateTableReq {
+            table_id: table_info.ident.table_id,
+            batch_size: None,
+        })
+        .await?;
+    assert_eq!(1024, reply.removed_bytes);
+    assert_eq!(3, reply.removed_blocks);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_catalogs_mget_table_metas_by_ids() -> Result<()> {
+    let tenant_name = "test";
+    let tenant = Tenant::new_literal(tenant_name);
+    let catalog = create_catalog().await?;
+
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "number",
+        TableDataType::Number(NumberDataType::UInt64),
+    )]));
+
+    let mut table_ids = vec![];
+    for table_name in ["t1", "t2"] {
+        let req = CreateTableReq {
+            create_option: CreateOption::Create,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: "default".to_string(),
+                table_name: table_name.to_string(),
+            },
+            table_meta: TableMeta {
+                schema: schema.clone(),
+                engine: "MEMORY".to_string(),
+                created_on: Utc::now(),
+                ..TableMeta::default()
+            },
+            as_dropped: false,
+        };
+        let reply = catalog.create_table(req).await?;
+        table_ids.push(reply.table_id);
+    }
+    let sys_table = catalog.get_table(&tenant, "system", "one").await?;
+    let sys_table_id = sys_table.get_id();
+    let missing_table_id = u64::MAX;
+
+    let ids = [table_ids[0], missing_table_id, sys_table_id, table_ids[1]];
+    let table_metas = catalog.mget_table_metas_by_ids(&ids).await?;
+    assert_eq!(table_metas.len(), 4);
+
+    // The order of the input ids is kept, and missing ids yield None.
+    for (i, table_meta) in table_metas.iter().enumerate() {
+        match table_meta {
+            Some((ident, _)) => assert_eq!(ident.table_id, ids[i]),
+            None => assert_eq!(ids[i], missing_table_id),
+        }
+    }
+    assert!(table_metas[0].is_some());
+    assert!(table_metas[1].is_none());
+    assert!(table_metas[2].is_some());
+    assert!(table_metas[3].is_some());
+
+    let (_, sys_meta) = table_metas[2].as_ref().unwrap();
+    assert_eq!(sys_meta.as_ref(), &sys_table.get_table_info().meta);
+    let (_, meta) = table_metas[3].as_ref().unwrap();
+    assert_eq!(meta.engine, "MEMORY");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_catalogs_list_tables_history_paged() -> Result<()> {
+    let tenant_name = "test";
+    let tenant = Tenant::new_literal(tenant_name);
+    let catalog = create_catalog().await?;
+
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "number",
+        TableDataType::Number(NumberDataType::UInt64),
+    )]));
+    let create_table = |table_name: &str| CreateTableReq {
+        create_option: CreateOption::Create,
+        name_ident: TableNameIdent {
+            tenant: tenant.clone(),
+            db_name: "default".to_string(),
+            table_name: table_name.to_string(),
+        },
+        table_meta: TableMeta {
+            schema: schema.clone(),
+            engine: "MEMORY".to_string(),
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        },
+        as_dropped: false,
+    };
+
+    for table_name in ["e", "a", "c", "b", "d"] {
+        catalog.create_table(create_table(table_name)).await?;
+    }
+    // Drop and create `b` again, the history has two tables named `b`.
+    let db = catalog.get_database(&tenant, "default").await?;
+    let tbl = catalog.get_table(&tenant, "default", "b").await?;
+    catalog
+        .drop_table_by_id(DropTableByIdReq {
+            if_exists: false,
+            tenant: tenant.clone(),
+            table_name: "b".to_string(),
+            tb_id: tbl.get_table_info().ident.table_id,
+            db_id: db.get_db_info().ident.db_id,
+        })
+        .await?;
+    catalog.create_table(create_table("b")).await?;
+
+    let names = |tables: &[Arc<dyn Table>]| {
+        tables
+            .iter()
+            .map(|t| t.name().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    // Unbounded, the same as list_tables_history.
+    let (tables, next) = catalog
+        .list_tables_history_paged(&tenant, "default", None, None)
+        .await?;
+    assert_eq!(names(&tables), vec!["a", "b", "b", "c", "d", "e"]);
+    assert_eq!(next, None);
+    let history = catalog.list_tables_history(&tenant, "default").await?;
+    assert_eq!(history.len(), tables.len());
+
+    // Page boundaries and continuation, tables named `b` are kept in one page.
+    let (tables, next) = catalog
+        .list_tables_history_paged(&tenant, "default", Some(2), None)
+        .await?;
+    assert_eq!(names(&tables), vec!["a", "b", "b"]);
+    assert_eq!(next, Some("b".to_string()));
+
+    let (tables, next) = catalog
+        .list_tables_history_paged(&tenant, "default", Some(2), next)
+        .await?;
+    assert_eq!(names(&tables), vec!["c", "d"]);
+    assert_eq!(next, Some("d".to_string()));
+
+    let (tables, next) = catalog
+        .list_tables_history_paged(&tenant, "default", Some(2), next)
+        .await?;
+    assert_eq!(names(&tables), vec!["e"]);
+    assert_eq!(next, None);
+
+    // A page that ends exactly at the last table has no continuation.
+    let (tables, next) = catalog
+        .list_tables_history_paged(&tenant, "default", Some(2), Some("c".to_string()))
+        .await?;
+    assert_eq!(names(&tables), vec!["d", "e"]);
+    assert_eq!(next, None);
+
+    assert!(
+        catalog
+            .list_tables_history_paged(&tenant, "default", Some(0), None)
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_catalogs_list_tables_stream() -> Result<()> {
+    let tenant_name = "test";
+    let tenant = Tenant::new_literal(tenant_name);
+    let catalog = create_catalog().await?;
+
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "number",
+        TableDataType::Number(NumberDataType::UInt64),
+    )]));
+    for table_name in ["c", "a", "b"] {
+        let req = CreateTableReq {
+            create_option: CreateOption::Create,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: "default".to_string(),
+                table_name: table_name.to_string(),
+            },
+            table_meta: TableMeta {
+                schema: schema.clone(),
+                engine: "MEMORY".to_string(),
+                created_on: Utc::now(),
+                ..TableMeta::default()
+            },
+            as_dropped: false,
+        };
+        catalog.create_table(req).await?;
+    }
+
+    for db_name in ["default", "system"] {
+        let mut want = catalog
+            .list_tables(&tenant, db_name)
+            .await?
+            .iter()
+            .map(|t| t.get_id())
+            .collect::<Vec<_>>();
+        let mut got = catalog
+            .list_tables_stream(&tenant, db_name)
+            .map_ok(|t| t.get_id())
+            .try_collect::<Vec<_>>()
+            .await?;
+        want.sort();
+        got.sort();
+        assert!(!got.is_empty());
+        assert_eq!(want, got);
+    }
+
+    // Errors of listing are yielded by the stream.
+    let res = catalog
+        .list_tables_stream(&tenant, "not_exists")
+        .try_collect::<Vec<_>>()
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_database_summary() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!("create table {db}.t1(a int)"))
+        .await?;
+    fixture
+        .execute_command(&format!("create table {db}.t2(a int, b string)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t1 values (1), (2), (3)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t1 values (4)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t2 values (1, 'a'), (2, 'b')"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let catalog = ctx.get_catalog(&fixture.default_catalog_name()).await?;
+    let database = catalog.get_database(&fixture.default_tenant(), &db).await?;
+    let summary = database.summary(ctx.clone()).await?;
+
+    assert_eq!(summary.num_rows, Some(6));
+    assert_eq!(summary.number_of_blocks, Some(3));
+
+    let mut data_size = 0;
+    for table in database.list_tables().await? {
+        let stats = table.table_statistics(ctx.clone(), None).await?.unwrap();
+        data_size += stats.data_size.unwrap();
+    }
+    assert_eq!(summary.data_size, Some(data_size));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_alter_database_options() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.execute_command("create database db_alter").await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let catalog = ctx.get_catalog(&fixture.default_catalog_name()).await?;
+    let tenant = fixture.default_tenant();
+
+    fixture
+        .execute_command("alter database db_alter set options (disable_time_travel = true)")
+        .await?;
+    let db = catalog.get_database(&tenant, "db_alter").await?;
+    assert!(db.get_db_info().meta.time_travel_disabled());
+
+    fixture
+        .execute_command("alter database db_alter unset options (disable_time_travel)")
+        .await?;
+    let db = catalog.get_database(&tenant, "db_alter").await?;
+    assert!(!db.get_db_info().meta.time_travel_disabled());
+    assert!(db.options().is_empty());
+
+    // Unknown option keys are rejected.
+    let err = fixture
+        .execute_command("alter database db_alter set options (unknown_option = 1)")
+        .await
+        .unwrap_err();
+    assert_eq!(ErrorCode::BAD_ARGUMENTS, err.code());
+
+    // So are invalid values of the known ones.
+    let err = fixture
+        .execute_command("alter database db_alter set options (disable_time_travel = 'maybe')")
+        .await
+        .unwrap_err();
+    assert_eq!(ErrorCode::BAD_ARGUMENTS, err.code());
+
+    fixture
+        .execute_command(
+            "alter database if exists db_missing set options (disable_time_travel = true)",
+        )
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storages_fuse::FuseTable;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_attach_table_over_shared_location() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture
+        .execute_command(&format!("insert into {db}.{tbl} values(1, (2, 3))"))
+        .await?;
+
+    let table = fixture.latest_default_table().await?;
+    let table_info = table.get_table_info();
+    let storage_prefix = FuseTable::parse_storage_prefix(table_info)?;
+    let uri = format!(
+        "fs://{}/{}/",
+        fixture.storage_root().trim_end_matches('/'),
+        storage_prefix
+    );
+
+    // The source table still lives in the storage of this deployment, the writable
+    // attach falls back to read-only.
+    fixture
+        .execute_command(&format!("attach table {db}.t_writable '{uri}'"))
+        .await?;
+    assert!(attached_table_is_read_only(&fixture, "t_writable").await?);
+
+    fixture
+        .execute_command(&format!("attach table {db}.t_read_only '{uri}' READ_ONLY"))
+        .await?;
+    assert!(attached_table_is_read_only(&fixture, "t_read_only").await?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_attach_table_over_used_location() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    // A table with its own storage outside of the storage of this deployment.
+    let external_root = format!("{}_external", fixture.storage_root().trim_end_matches('/'));
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t_external(a int) 'fs://{external_root}/'"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t_external values(1)"))
+        .await?;
+
+    let catalog = fixture
+        .new_query_ctx()
+        .await?
+        .get_catalog(&fixture.default_catalog_name())
+        .await?;
+    let table = catalog
+        .get_table(&fixture.default_tenant(), &db, "t_external")
+        .await?;
+    let storage_prefix = FuseTable::parse_storage_prefix(table.get_table_info())?;
+    let uri = format!("fs://{external_root}/{storage_prefix}/");
+
+    // The location is already used by `t_external`, the writable attach falls back
+    // to read-only, and mutations are rejected.
+    fixture
+        .execute_command(&format!("attach table {db}.t_writable '{uri}'"))
+        .await?;
+    assert!(attached_table_is_read_only(&fixture, "t_writable").await?);
+    let err = fixture
+        .execute_command(&format!("delete from {db}.t_writable where a = 1"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::INVALID_OPERATION);
+
+    fixture
+        .execute_command(&format!("attach table {db}.t_read_only '{uri}' READ_ONLY"))
+        .await?;
+    assert!(attached_table_is_read_only(&fixture, "t_read_only").await?);
+
+    Ok(())
+}
+
+async fn attached_table_is_read_only(fixture: &TestFixture, table: &str) -> Result<bool> {
+    let catalog = fixture
+        .new_query_ctx()
+        .await?
+        .get_catalog(&fixture.default_catalog_name())
+        .await?;
+    let table = catalog
+        .get_table(&fixture.default_tenant(), &fixture.default_db_name(), table)
+        .await?;
+    Ok(table.is_read_only())
+}
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::DatabaseMeta;
+use databend_common_sql::plans::CreateDatabasePlan;
+use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
+use databend_query::interpreters::CreateDatabaseInterpreter;
+use databend_query::interpreters::Interpreter;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_database_records_created_by() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create database db_created_by")
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let db = ctx
+        .get_catalog(&fixture.default_catalog_name())
+        .await?
+        .get_database(&fixture.default_tenant(), "db_created_by")
+        .await?;
+
+    let user = ctx.get_current_user()?;
+    assert_eq!(
+        Some(user.identity().display().to_string()),
+        db.get_db_info().meta.created_by
+    );
+    assert_eq!(
+        Some("'root'@'%'".to_string()),
+        db.get_db_info().meta.created_by
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_table_in_transient_database() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create transient database db_transient")
+        .await?;
+    fixture
+        .execute_command("create table db_transient.t1(a int)")
+        .await?;
+    // An explicit option overrides the default of the database.
+    fixture
+        .execute_command("create table db_transient.t2(a int) transient = 'false'")
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let catalog = ctx.get_catalog(&fixture.default_catalog_name()).await?;
+    let tenant = fixture.default_tenant();
+
+    let db = catalog.get_database(&tenant, "db_transient").await?;
+    assert_eq!(Some(&"T".to_string()), db.options().get("TRANSIENT"));
+
+    let t1 = catalog.get_table(&tenant, "db_transient", "t1").await?;
+    assert!(t1.options().contains_key("TRANSIENT"));
+
+    let t2 = catalog.get_table(&tenant, "db_transient", "t2").await?;
+    assert!(!t2.options().contains_key("TRANSIENT"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_database_data_retention_over_max() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let max_days = GlobalConfig::instance()
+        .query
+        .data_retention_time_in_days_max;
+    let plan = CreateDatabasePlan {
+        create_option: CreateOption::Create,
+        tenant: fixture.default_tenant(),
+        catalog: fixture.default_catalog_name(),
+        database: "db_retention".to_string(),
+        meta: DatabaseMeta {
+            engine: "".to_string(),
+            options: BTreeMap::from([(
+                FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS.to_string(),
+                (max_days + 1).to_string(),
+            )]),
+            ..Default::default()
+        },
+    };
+    let interpreter = CreateDatabaseInterpreter::try_create(ctx.clone(), plan)?;
+    let err = interpreter
+        .execute(ctx.clone())
+        .await
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(ErrorCode::TABLE_OPTION_INVALID, err.code());
+
+    Ok(())
+}
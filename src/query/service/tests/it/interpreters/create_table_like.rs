@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_sql::plans::Plan;
+use databend_query::sql::Planner;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_table_like() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    let tenant = fixture.default_tenant();
+
+    fixture
+        .execute_command(&format!(
+            "create table {db}.src(a int comment 'col a', b string comment 'col b') \
+             cluster by (a) block_per_segment = 500"
+        ))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let catalog = ctx.get_catalog(&fixture.default_catalog_name()).await?;
+    let src = catalog.get_table(&tenant, &db, "src").await?;
+
+    // Schema only, the options of the source table are not copied.
+    fixture
+        .execute_command(&format!("create table {db}.t1 like {db}.src"))
+        .await?;
+    let t1 = catalog.get_table(&tenant, &db, "t1").await?;
+    assert_eq!(t1.schema().fields(), src.schema().fields());
+    assert_eq!(t1.field_comments(), &vec!["col a", "col b"]);
+    assert_eq!(
+        t1.get_table_info().meta.default_cluster_key,
+        src.get_table_info().meta.default_cluster_key
+    );
+    assert!(!t1.options().contains_key("block_per_segment"));
+    assert_eq!(t1.get_table_info().meta.statistics.number_of_rows, 0);
+
+    // Options included, explicit options take precedence over the inherited ones.
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t2 like {db}.src including options row_per_block = 1000"
+        ))
+        .await?;
+    let t2 = catalog.get_table(&tenant, &db, "t2").await?;
+    assert_eq!(t2.schema().fields(), src.schema().fields());
+    assert_eq!(t2.options().get("block_per_segment").unwrap(), "500");
+    assert_eq!(t2.options().get("row_per_block").unwrap(), "1000");
+    assert_eq!(
+        t2.options().get("storage_format"),
+        src.options().get("storage_format")
+    );
+
+    // The schema is resolved from the source table by the binder, so the plan
+    // carries the columns the interpreter checks, e.g. for computed columns.
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner
+        .plan_sql(&format!("create table {db}.t3 like {db}.src"))
+        .await?;
+    match plan {
+        Plan::CreateTable(plan) => {
+            assert_eq!(plan.schema.fields(), src.schema().fields());
+            assert_eq!(plan.field_comments, vec!["col a", "col b"]);
+            assert_eq!(
+                plan.cluster_key,
+                src.get_table_info().meta.default_cluster_key
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_catalog() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    for sql in ["drop catalog default", "drop catalog if exists default"] {
+        let err = fixture.execute_command(sql).await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS, "{sql}");
+        assert_eq!(err.message(), "cannot drop the default catalog", "{sql}");
+    }
+
+    let root = fixture.storage_root();
+    fixture
+        .execute_command(&format!(
+            "create catalog ctl type = iceberg connection = (url = 'fs://{root}/ctl/')"
+        ))
+        .await?;
+    fixture.execute_command("drop catalog ctl").await?;
+
+    Ok(())
+}
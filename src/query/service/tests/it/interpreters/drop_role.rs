@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_users::UserApiProvider;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_drop_role_revokes_grants() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    fixture.execute_command("create role r1").await?;
+    fixture.execute_command("create role r2").await?;
+    fixture
+        .execute_command("create user u1 identified by '123'")
+        .await?;
+    fixture.execute_command("grant role r1 to u1").await?;
+    fixture.execute_command("grant role r1 to role r2").await?;
+
+    fixture.execute_command("drop role r1").await?;
+
+    let tenant = fixture.new_query_ctx().await?.get_tenant();
+    let user_mgr = UserApiProvider::instance();
+    let user = user_mgr
+        .get_user(&tenant, UserIdentity::new("u1", "%"))
+        .await?;
+    assert!(!user.grants.roles().contains(&"r1".to_string()));
+    let role = user_mgr.get_role(&tenant, "r2".to_string()).await?;
+    assert!(!role.grants.roles().contains(&"r1".to_string()));
+
+    let res = fixture.execute_command("drop role r1").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_ROLE);
+    fixture.execute_command("drop role if exists r1").await?;
+
+    Ok(())
+}
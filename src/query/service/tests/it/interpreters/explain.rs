@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_sql::binder::ExplainConfig;
+use databend_common_sql::binder::ExplainVerbosity;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use databend_query::interpreters::ExplainInterpreter;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::SessionType;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+async fn explain_lines(
+    fixture: &TestFixture,
+    sql: &str,
+    verbosity: ExplainVerbosity,
+) -> Result<usize> {
+    let ctx = fixture.new_query_ctx().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+
+    let Plan::Explain { kind, plan, .. } = plan else {
+        unreachable!()
+    };
+    let config = ExplainConfig {
+        verbosity,
+        ..Default::default()
+    };
+    let interpreter = ExplainInterpreter::try_create(ctx.clone(), *plan, kind, config)?;
+    let stream = interpreter.execute(ctx).await?;
+    let blocks: Vec<DataBlock> = stream.try_collect().await?;
+    Ok(blocks.iter().map(|b| b.num_rows()).sum())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_explain_verbosity() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    for sql in [
+        "explain select number from numbers(10) where number > 1",
+        "explain(logical) select number from numbers(10) where number > 1",
+    ] {
+        let terse = explain_lines(&fixture, sql, ExplainVerbosity::Terse).await?;
+        let normal = explain_lines(&fixture, sql, ExplainVerbosity::Normal).await?;
+        assert!(terse > 0);
+        assert!(terse < normal, "{sql}: terse: {terse}, normal: {normal}");
+    }
+
+    // The verbose level adds the logical plan to the physical plan.
+    let sql = "explain select number from numbers(10) where number > 1";
+    let normal = explain_lines(&fixture, sql, ExplainVerbosity::Normal).await?;
+    let verbose = explain_lines(&fixture, sql, ExplainVerbosity::Verbose).await?;
+    assert!(
+        normal < verbose,
+        "{sql}: normal: {normal}, verbose: {verbose}"
+    );
+
+    // Operators without children, e.g. DummyTableScan, are kept in the terse output.
+    let terse = explain_lines(&fixture, "explain select 1", ExplainVerbosity::Terse).await?;
+    assert_eq!(terse, 2);
+    let verbose = explain_lines(&fixture, "explain select 1", ExplainVerbosity::Verbose).await?;
+    assert!(verbose > 4);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_explain_write_requires_select_only() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture
+        .execute_command("create user u1 identified by '123'")
+        .await?;
+    fixture
+        .execute_command(&format!("grant select on {db}.{tbl} to u1"))
+        .await?;
+
+    let tenant = fixture.new_query_ctx().await?.get_tenant();
+    let user = UserApiProvider::instance()
+        .get_user(&tenant, UserIdentity::new("u1", "%"))
+        .await?;
+    let session = fixture.new_session_with_type(SessionType::MySQL).await?;
+    session.set_authed_user(user, None).await?;
+
+    let insert = format!("insert into {db}.{tbl} values(1, (2, 3))");
+
+    // Explaining the insert only needs to see the table.
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&format!("explain {insert}")).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    let blocks: Vec<DataBlock> = stream.try_collect().await?;
+    assert!(blocks.iter().map(|b| b.num_rows()).sum::<usize>() > 0);
+
+    // Running it needs the INSERT privilege.
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&insert).await?;
+    let res = InterpreterFactory::get(ctx.clone(), &plan).await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::PERMISSION_DENIED)
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_explain_copy_requires_stage_read() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture
+        .execute_command("create stage s1 file_format = (type = csv)")
+        .await?;
+    fixture
+        .execute_command("create user u1 identified by '123'")
+        .await?;
+    fixture
+        .execute_command(&format!("grant select on {db}.{tbl} to u1"))
+        .await?;
+
+    let tenant = fixture.new_query_ctx().await?.get_tenant();
+    let user = UserApiProvider::instance()
+        .get_user(&tenant, UserIdentity::new("u1", "%"))
+        .await?;
+    let session = fixture.new_session_with_type(SessionType::MySQL).await?;
+    session.set_authed_user(user, None).await?;
+    session.get_settings().set_setting(
+        "enable_experimental_rbac_check".to_string(),
+        "1".to_string(),
+    )?;
+
+    // The stage is still read by the explained COPY, so READ on it is required.
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner
+        .plan_sql(&format!("explain copy into {db}.{tbl} from @s1"))
+        .await?;
+    let res = InterpreterFactory::get(ctx.clone(), &plan).await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::PERMISSION_DENIED)
+    );
+
+    Ok(())
+}
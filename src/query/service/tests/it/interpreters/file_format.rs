@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+async fn create_file_format(fixture: &TestFixture, sql: &str) -> Result<(String, String)> {
+    let blocks = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), 1);
+
+    let value = |i: usize| {
+        let column = block.get_by_offset(i).value.as_ref();
+        column.index(0).unwrap().as_string().unwrap().to_string()
+    };
+    Ok((value(0), value(1)))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_or_replace_file_format() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let (status, changes) = create_file_format(
+        &fixture,
+        "create file format f1 type = CSV field_delimiter = ','",
+    )
+    .await?;
+    assert_eq!(status, "created");
+    assert_eq!(changes, "");
+
+    let (status, changes) = create_file_format(
+        &fixture,
+        "create or replace file format f1 type = CSV field_delimiter = '|'",
+    )
+    .await?;
+    assert_eq!(status, "replaced");
+    assert_eq!(changes, "FIELD_DELIMITER: ',' -> '|'");
+
+    let (status, changes) = create_file_format(
+        &fixture,
+        "create file format if not exists f1 type = CSV field_delimiter = ';'",
+    )
+    .await?;
+    assert_eq!(status, "unchanged");
+    assert_eq!(changes, "");
+
+    Ok(())
+}
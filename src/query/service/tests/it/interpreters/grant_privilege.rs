@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::SessionType;
+use databend_query::test_kits::TestFixture;
+
+async fn execute_as(fixture: &TestFixture, user: &str, sql: &str) -> Result<()> {
+    let user_info = UserApiProvider::instance()
+        .get_user(&fixture.default_tenant(), UserIdentity::new(user, "%"))
+        .await?;
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    session.set_authed_user(user_info, None).await?;
+    let ctx = session.create_query_context().await?;
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let _ = interpreter.execute(ctx).await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_grant_privilege_beyond_grantor() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    fixture.execute_command("create role grantor_role").await?;
+    fixture.execute_command("create role grantee_role").await?;
+    fixture
+        .execute_command("grant grant on *.* to role grantor_role")
+        .await?;
+    fixture
+        .execute_command("grant select on default.* to role grantor_role")
+        .await?;
+    fixture
+        .execute_command("create user grantor identified by '123' with default_role='grantor_role'")
+        .await?;
+    fixture
+        .execute_command("grant role grantor_role to grantor")
+        .await?;
+    fixture
+        .execute_command("create user admin identified by '123' with default_role='account_admin'")
+        .await?;
+    fixture
+        .execute_command("grant role account_admin to admin")
+        .await?;
+
+    // A privilege the grantor has can be granted.
+    execute_as(
+        &fixture,
+        "grantor",
+        "grant select on default.* to role grantee_role",
+    )
+    .await?;
+
+    // A privilege the grantor lacks can not be granted.
+    let err = execute_as(
+        &fixture,
+        "grantor",
+        "grant insert on default.* to role grantee_role",
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(ErrorCode::PERMISSION_DENIED, err.code());
+
+    // account_admin can grant any privilege.
+    execute_as(
+        &fixture,
+        "admin",
+        "grant insert on default.* to role grantee_role",
+    )
+    .await?;
+
+    Ok(())
+}
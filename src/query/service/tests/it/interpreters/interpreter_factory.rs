@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_sql::Planner;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::SessionType;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_factory_skip_access_check() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // An internal session whose user has no privileges at all.
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    session
+        .set_authed_user(UserInfo::new_no_auth("internal", "%"), None)
+        .await?;
+
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql("create database db_skip_access").await?;
+
+    // The accessor rejects the plan.
+    let res = InterpreterFactory::get(ctx.clone(), &plan).await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::PERMISSION_DENIED)
+    );
+
+    // The accessor is not invoked, so the plan can be executed.
+    let interpreter = InterpreterFactory::get_with_access(ctx.clone(), &plan, false).await?;
+    let _ = interpreter.execute(ctx.clone()).await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_factory_skip_access_check_user_session() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let session = fixture.new_session_with_type(SessionType::MySQL).await?;
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql("create database db_user_session").await?;
+
+    // User sessions are not allowed to skip the access check.
+    let res = InterpreterFactory::get_with_access(ctx.clone(), &plan, false).await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::PERMISSION_DENIED)
+    );
+
+    Ok(())
+}
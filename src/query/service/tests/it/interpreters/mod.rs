@@ -12,4 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod alter_database;
+mod attach_table;
+mod create_database;
+mod create_table_like;
+mod drop_catalog;
+mod drop_role;
+mod explain;
+mod file_format;
+mod grant_privilege;
+mod interpreter_factory;
+mod sequence;
+mod show_roles;
+mod show_settings;
+mod table_options;
+mod truncate_tables;
 mod union;
+mod view;
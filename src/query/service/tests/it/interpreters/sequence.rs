@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sequence_ownership() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let tenant = fixture.default_tenant();
+    let role_api = UserApiProvider::instance().role_api(&tenant);
+    let object = OwnershipObject::Sequence {
+        name: "seq_owned".to_string(),
+    };
+
+    fixture
+        .execute_command("create role seq_owner_role")
+        .await?;
+    fixture
+        .execute_command("grant role seq_owner_role to 'root'@'%'")
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_current_session()
+        .set_current_role_checked("seq_owner_role")
+        .await?;
+    let current_role = ctx.get_current_role().unwrap();
+    assert_eq!("seq_owner_role", current_role.name);
+
+    // Creating a sequence grants its ownership to the current role.
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql("create sequence seq_owned").await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let _ = interpreter.execute(ctx.clone()).await?;
+
+    let ownership = role_api.get_ownership(&object).await?.unwrap();
+    assert_eq!("seq_owner_role", ownership.role);
+
+    let role = UserApiProvider::instance()
+        .get_role(&tenant, "seq_owner_role".to_string())
+        .await?;
+    assert!(role.grants.verify_privilege(
+        &GrantObject::Sequence("seq_owned".to_string()),
+        UserPrivilegeType::Ownership
+    ));
+
+    // An existing sequence kept by IF NOT EXISTS does not change owner.
+    fixture
+        .execute_command("create sequence if not exists seq_owned")
+        .await?;
+    let ownership = role_api.get_ownership(&object).await?.unwrap();
+    assert_eq!("seq_owner_role", ownership.role);
+
+    // Dropping the sequence revokes the ownership.
+    fixture.execute_command("drop sequence seq_owned").await?;
+    assert!(role_api.get_ownership(&object).await?.is_none());
+
+    let role = UserApiProvider::instance()
+        .get_role(&tenant, "seq_owner_role".to_string())
+        .await?;
+    assert!(!role.grants.verify_privilege(
+        &GrantObject::Sequence("seq_owned".to_string()),
+        UserPrivilegeType::Ownership
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_users::RoleCacheManager;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_show_roles_owned_objects() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    fixture.execute_command("create role r1").await?;
+    fixture.execute_command("create role r2").await?;
+    fixture.execute_command("create database db1").await?;
+    fixture
+        .execute_command("create table db1.t1(a int)")
+        .await?;
+    fixture
+        .execute_command("grant ownership on db1.* to role r1")
+        .await?;
+    fixture
+        .execute_command("grant ownership on db1.t1 to role r1")
+        .await?;
+
+    let tenant = fixture.new_query_ctx().await?.get_tenant();
+    RoleCacheManager::instance().force_reload(&tenant).await?;
+
+    let blocks = fixture
+        .execute_query("show roles")
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+
+    let owned_objects = |role: &str| -> ScalarRef {
+        let names = block.get_by_offset(0).value.as_ref();
+        let owned = block.get_by_offset(4).value.as_ref();
+        let row = (0..block.num_rows())
+            .find(|i| names.index(*i).unwrap().as_string().unwrap() == role)
+            .unwrap();
+        owned.index(row).unwrap()
+    };
+    assert_eq!(
+        owned_objects("r1"),
+        ScalarRef::Number(NumberScalar::UInt64(2))
+    );
+    assert_eq!(
+        owned_objects("r2"),
+        ScalarRef::Number(NumberScalar::UInt64(0))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+async fn show_settings(fixture: &TestFixture, sql: &str) -> Result<Vec<(String, String)>> {
+    let blocks: Vec<DataBlock> = fixture.execute_query(sql).await?.try_collect().await?;
+    let block = DataBlock::concat(&blocks)?;
+    let mut rows = Vec::with_capacity(block.num_rows());
+    for i in 0..block.num_rows() {
+        let name = block.get_by_offset(0).value.index(i);
+        let level = block.get_by_offset(4).value.index(i);
+        match (name, level) {
+            (Some(ScalarRef::String(name)), Some(ScalarRef::String(level))) => {
+                rows.push((name.to_string(), level.to_string()))
+            }
+            v => unreachable!("{:?}", v),
+        }
+    }
+    Ok(rows)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_show_settings_scope() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    fixture
+        .execute_command("set global max_threads = 2")
+        .await?;
+    fixture.execute_command("set max_block_size = 1024").await?;
+
+    let rows = show_settings(&fixture, "show global settings").await?;
+    assert!(rows.iter().all(|(_, level)| level == "GLOBAL"));
+    assert!(rows.iter().any(|(name, _)| name == "max_threads"));
+    assert!(!rows.iter().any(|(name, _)| name == "max_block_size"));
+
+    let rows = show_settings(&fixture, "show session settings like 'max_%'").await?;
+    assert_eq!(
+        vec![("max_block_size".to_string(), "SESSION".to_string())],
+        rows
+    );
+
+    let rows = show_settings(&fixture, "show global settings like 'max_block%'").await?;
+    assert!(rows.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_numeric_table_option_range() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    // Below min.
+    let err = fixture
+        .execute_command(&format!(
+            "create table {db}.t1(a int) block_per_segment = 0"
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::TABLE_OPTION_INVALID);
+    assert_eq!(
+        err.message(),
+        "invalid block_per_segment option, Value 0 is not within the range [1, 1000]"
+    );
+
+    // Above max.
+    let err = fixture
+        .execute_command(&format!(
+            "create table {db}.t1(a int) row_per_block = 1000001"
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::TABLE_OPTION_INVALID);
+    assert_eq!(
+        err.message(),
+        "invalid row_per_block option, Value 1000001 is not within the range [1, 1000000]"
+    );
+
+    // In range.
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t1(a int) block_per_segment = 500 row_per_block = 1000"
+        ))
+        .await?;
+
+    // The same ranges apply to ALTER TABLE SET OPTIONS.
+    let err = fixture
+        .execute_command(&format!(
+            "alter table {db}.t1 set options(block_per_segment = 2000)"
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::TABLE_OPTION_INVALID);
+    fixture
+        .execute_command(&format!(
+            "alter table {db}.t1 set options(block_per_segment = 1000)"
+        ))
+        .await?;
+
+    Ok(())
+}
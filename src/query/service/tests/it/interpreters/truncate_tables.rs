@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+async fn query_block(fixture: &TestFixture, sql: &str) -> Result<DataBlock> {
+    let blocks: Vec<DataBlock> = fixture.execute_query(sql).await?.try_collect().await?;
+    DataBlock::concat(&blocks)
+}
+
+async fn count_rows(fixture: &TestFixture, table: &str) -> Result<u64> {
+    let block = query_block(fixture, &format!("select count(*) from {table}")).await?;
+    match block.get_by_offset(0).value.index(0) {
+        Some(ScalarRef::Number(n)) => Ok(n.as_u_int64().copied().unwrap()),
+        v => unreachable!("{:?}", v),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_truncate_tables() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    for table in ["t1", "t2"] {
+        fixture
+            .execute_command(&format!("create table default.{table}(a int)"))
+            .await?;
+        fixture
+            .execute_command(&format!("insert into default.{table} values(1),(2)"))
+            .await?;
+    }
+
+    // Without IF EXISTS, the first failure is returned.
+    let err = fixture
+        .execute_command("truncate table default.t1, default.missing")
+        .await
+        .unwrap_err();
+    assert_eq!(ErrorCode::UNKNOWN_TABLE, err.code());
+
+    // With IF EXISTS, the missing table is reported and the others are truncated.
+    fixture
+        .execute_command("insert into default.t1 values(3)")
+        .await?;
+    let block = query_block(
+        &fixture,
+        "truncate table if exists default.t1, default.missing, default.t2",
+    )
+    .await?;
+    assert_eq!(3, block.num_rows());
+    let errors = &block.get_by_offset(1).value;
+    assert_eq!(Some(ScalarRef::Null), errors.index(0));
+    assert!(matches!(errors.index(1), Some(ScalarRef::String(_))));
+    assert_eq!(Some(ScalarRef::Null), errors.index(2));
+
+    assert_eq!(0, count_rows(&fixture, "default.t1").await?);
+    assert_eq!(0, count_rows(&fixture, "default.t2").await?);
+
+    Ok(())
+}
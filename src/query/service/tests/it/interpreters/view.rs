@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::Planner;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_view_schema_binding() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t(a int)"))
+        .await?;
+
+    // The query references a column that does not exist, so the view is rejected.
+    let res = fixture
+        .execute_command(&format!("create view {db}.v1 as select b from {db}.t"))
+        .await;
+    assert!(res.is_err());
+
+    // Binding is deferred, so the view can be created ...
+    fixture
+        .execute_command(&format!(
+            "create view {db}.v2 with no schema binding as select b from {db}.t"
+        ))
+        .await?;
+
+    // ... but fails when it is used.
+    let res = fixture
+        .execute_command(&format!("select * from {db}.v2"))
+        .await;
+    assert!(res.is_err());
+
+    // Once the column exists, the view works.
+    fixture
+        .execute_command(&format!("alter table {db}.t add column b int"))
+        .await?;
+    fixture
+        .execute_command(&format!("select * from {db}.v2"))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_alter_view_concurrent_reads() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create view {db}.v as select 0 as a"))
+        .await?;
+
+    let alter = async {
+        for i in 1..=20 {
+            fixture
+                .execute_command(&format!("alter view {db}.v as select {i} as a"))
+                .await?;
+        }
+        Result::Ok(())
+    };
+
+    // The view is swapped in place, so the readers never see it missing.
+    let read = async {
+        for _ in 0..50 {
+            fixture
+                .execute_command(&format!("select * from {db}.v"))
+                .await?;
+        }
+        Result::Ok(())
+    };
+
+    let (altered, read) = futures::future::join(alter, read).await;
+    altered?;
+    read?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_describe_view_lineage() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t(a int, b string)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "create view {db}.v as select b as x, a, a + 1 as c from {db}.t"
+        ))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_enable_describe_view_lineage(true)?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&format!("desc view {db}.v")).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    let blocks: Vec<DataBlock> = stream.try_collect().await?;
+    let block = DataBlock::concat(&blocks)?;
+
+    assert_eq!(block.num_columns(), 6);
+    let sources = (0..block.num_rows())
+        .map(|row| {
+            let value = block.get_by_offset(5).value.index(row).unwrap();
+            value.as_string().unwrap().to_string()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sources, vec![
+        format!("{db}.t.b"),
+        format!("{db}.t.a"),
+        "".to_string(),
+    ]);
+
+    Ok(())
+}
@@ -16,8 +16,19 @@ use std::net::SocketAddr;
 
 use databend_common_base::base::tokio;
 use databend_common_base::runtime::metrics::register_counter;
+use databend_common_base::runtime::metrics::MetricValue;
+use databend_common_base::runtime::metrics::GLOBAL_METRICS_REGISTRY;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_meta_app::principal::UDFOnError;
+use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
+use databend_common_sql::plans::UDFType;
+use databend_query::pipelines::processors::TransformUdfScript;
 use databend_query::servers::metrics::MetricService;
 use databend_query::servers::Server;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_metric_server() -> databend_common_exception::Result<()> {
@@ -47,3 +58,85 @@ async fn test_metric_server() -> databend_common_exception::Result<()> {
 
     Ok(())
 }
+
+fn has_query_start_sample(application_name: &str) -> Result<bool> {
+    let samples = GLOBAL_METRICS_REGISTRY.dump_sample()?;
+    Ok(samples.iter().any(|sample| {
+        sample.name.contains("query_start")
+            && sample.labels.get("application_name").map(String::as_str) == Some(application_name)
+    }))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_metrics_application_name() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // The name passed by the client at connect is normalized for the label.
+    fixture
+        .default_session()
+        .set_application_name(Some("metrics test/app".to_string()));
+    let _ = fixture
+        .execute_query("select 1")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(has_query_start_sample("metricstestapp")?);
+
+    // The `application_name` setting overrides the name passed at connect.
+    fixture
+        .execute_command("set application_name = 'metrics_setting_app'")
+        .await?;
+    let _ = fixture
+        .execute_query("select 1")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(has_query_start_sample("metrics_setting_app")?);
+
+    Ok(())
+}
+
+fn udf_runtime_counter(name: &str, lang: &str) -> Result<f64> {
+    let samples = GLOBAL_METRICS_REGISTRY.dump_sample()?;
+    Ok(samples
+        .iter()
+        .find(|sample| {
+            sample.name == name && sample.labels.get("lang").map(String::as_str) == Some(lang)
+        })
+        .map(|sample| match sample.value {
+            MetricValue::Counter(v) => v,
+            _ => 0.0,
+        })
+        .unwrap_or_default())
+}
+
+#[test]
+fn test_udf_runtime_metrics() -> Result<()> {
+    // Two functions with the same language and handler share one runtime.
+    let code = "export function add_one(x) { return x + 1; }";
+    let func = |name: &str| UdfFunctionDesc {
+        name: name.to_string(),
+        func_name: "add_one".to_string(),
+        output_column: 1,
+        arg_indices: vec![0],
+        arg_exprs: vec!["x".to_string()],
+        data_type: Box::new(DataType::Nullable(Box::new(DataType::Number(
+            NumberDataType::Int32,
+        )))),
+        udf_type: UDFType::Script(("javascript".to_string(), "".to_string(), code.to_string())),
+        on_error: UDFOnError::Abort,
+    };
+
+    let created = udf_runtime_counter("udf_runtime_created_total", "javascript")?;
+    let reused = udf_runtime_counter("udf_runtime_reused_total", "javascript")?;
+
+    let runtimes = TransformUdfScript::init_runtime(&[func("add_one_a"), func("add_one_b")])?;
+    assert_eq!(runtimes.len(), 1);
+
+    // The counters are global and only labelled by language, other tests running in
+    // parallel may bump them as well.
+    assert!(udf_runtime_counter("udf_runtime_created_total", "javascript")? - created >= 1.0);
+    assert!(udf_runtime_counter("udf_runtime_reused_total", "javascript")? - reused >= 1.0);
+
+    Ok(())
+}
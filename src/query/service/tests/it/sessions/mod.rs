@@ -15,4 +15,5 @@ mod query_ctx;
 mod queue_mgr;
 mod session;
 mod session_context;
+mod session_mgr;
 mod session_setting;
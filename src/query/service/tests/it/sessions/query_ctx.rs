@@ -12,8 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::time::Duration;
+
 use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::FileFormatOptionsAst;
+use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::storage::StorageFsConfig;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::storage::StorageS3Config;
@@ -65,3 +71,72 @@ async fn test_get_storage_accessor_fs() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_statement_timestamp_is_pinned() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // Two `now()` reads within one statement return the same value.
+    let now1 = ctx.get_function_context()?.now();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    let now2 = ctx.get_function_context()?.now();
+    assert_eq!(now1, now2);
+    assert_eq!(now1, ctx.get_statement_timestamp());
+
+    // Another statement has its own timestamp.
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    let ctx2 = fixture.new_query_ctx().await?;
+    assert!(ctx2.get_statement_timestamp() > now1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resolve_stage_and_format() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create file format f1 type = tsv field_delimiter = '|'")
+        .await?;
+    fixture
+        .execute_command("create stage s1 file_format = (type = csv)")
+        .await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let options = |kvs: &[(&str, &str)]| {
+        FileFormatOptionsAst::new(
+            kvs.iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<_, _>>(),
+        )
+    };
+
+    // named format
+    let (stage, params) = ctx
+        .resolve_stage_and_format("@s1/a/b", &options(&[("format_name", "f1")]))
+        .await?;
+    assert_eq!(stage.stage_name, "s1");
+    match params {
+        FileFormatParams::Tsv(tsv) => assert_eq!(tsv.field_delimiter, "|"),
+        other => panic!("unexpected file format {other:?}"),
+    }
+
+    // inline options
+    let (_, params) = ctx
+        .resolve_stage_and_format("s1", &options(&[("type", "ndjson")]))
+        .await?;
+    assert!(matches!(params, FileFormatParams::NdJson(_)));
+
+    // no option, falls back to the file format of the stage
+    let (_, params) = ctx.resolve_stage_and_format("@s1", &options(&[])).await?;
+    assert!(matches!(params, FileFormatParams::Csv(_)));
+
+    // NAME and TYPE can not be used together
+    let err = ctx
+        .resolve_stage_and_format("@s1", &options(&[("format_name", "f1"), ("type", "csv")]))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_FILE_FORMAT);
+
+    Ok(())
+}
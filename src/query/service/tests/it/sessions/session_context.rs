@@ -32,6 +32,14 @@ async fn test_session_context() -> Result<()> {
         assert!(val);
     }
 
+    // Current catalog status.
+    {
+        assert_eq!("default", session_ctx.get_current_catalog());
+        session_ctx.set_current_catalog("hive".to_string())?;
+        assert_eq!("hive", session_ctx.get_current_catalog());
+        assert_eq!("hive", session_ctx.get_settings().get_default_catalog()?);
+    }
+
     // Current database status.
     {
         session_ctx.set_current_database("bend".to_string());
@@ -0,0 +1,142 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::Planner;
+use databend_query::interpreters::interpreter_plan_sql;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryCompletion;
+use databend_query::sessions::QueryRouting;
+use databend_query::sessions::RoutingHint;
+use databend_query::sessions::SessionManager;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+async fn run_and_get_completion(
+    fixture: &TestFixture,
+    completions: &Arc<Mutex<Vec<QueryCompletion>>>,
+    sql: &str,
+) -> Result<QueryCompletion> {
+    let ctx = fixture.new_query_ctx().await?;
+    let query_id = ctx.get_id();
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    if let Ok(stream) = interpreter.execute(ctx).await {
+        let _ = stream.try_collect::<Vec<DataBlock>>().await;
+    }
+
+    let completions = completions.lock().unwrap();
+    let recorded = completions
+        .iter()
+        .filter(|c| c.query_id == query_id)
+        .collect::<Vec<_>>();
+    assert_eq!(recorded.len(), 1, "statement: {sql}");
+    Ok(recorded[0].clone())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_completion_hook() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+
+    let completions = Arc::new(Mutex::new(vec![]));
+    {
+        let completions = completions.clone();
+        SessionManager::instance().register_query_completion_hook(Arc::new(
+            move |completion: &QueryCompletion| {
+                completions.lock().unwrap().push(completion.clone());
+            },
+        ));
+    }
+
+    // A succeeding statement records ok.
+    let completion = run_and_get_completion(&fixture, &completions, "select 1").await?;
+    assert_eq!(completion.query_kind, QueryKind::Query);
+    assert_eq!(completion.error_code, None);
+    assert!(completion.duration_ms >= 0);
+
+    // A failing statement records the code of its error.
+    let sql = format!("create database {}", fixture.default_db_name());
+    let completion = run_and_get_completion(&fixture, &completions, &sql).await?;
+    assert_eq!(
+        completion.error_code,
+        Some(ErrorCode::DatabaseAlreadyExists("").code())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_routing_hook() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    let routings: Arc<Mutex<Vec<QueryRouting>>> = Arc::new(Mutex::new(vec![]));
+    {
+        let routings = routings.clone();
+        SessionManager::instance().register_query_routing_hook(Arc::new(
+            move |routing: &QueryRouting| {
+                routings.lock().unwrap().push(routing.clone());
+            },
+        ));
+    }
+
+    let db = fixture.default_db_name();
+    let table = fixture.default_table_name();
+    let cases = [
+        ("select 1".to_string(), RoutingHint::ReadOnly),
+        (format!("show tables from {db}"), RoutingHint::ReadOnly),
+        (format!("desc {db}.{table}"), RoutingHint::ReadOnly),
+        (
+            format!("show create table {db}.{table}"),
+            RoutingHint::ReadOnly,
+        ),
+        (
+            format!("insert into {db}.{table} values(1, (2, 3))"),
+            RoutingHint::Write,
+        ),
+        (
+            format!("copy into @~/routing/ from {db}.{table}"),
+            RoutingHint::Write,
+        ),
+    ];
+    for (sql, expected) in cases {
+        let ctx = fixture.new_query_ctx().await?;
+        let query_id = ctx.get_id();
+
+        // The hint is passed to the hooks once the statement is planned.
+        interpreter_plan_sql(ctx.clone(), &sql).await?;
+
+        let routings = routings.lock().unwrap();
+        let recorded = routings
+            .iter()
+            .filter(|r| r.query_id == query_id)
+            .collect::<Vec<_>>();
+        assert_eq!(recorded.len(), 1, "statement: {sql}");
+        assert_eq!(recorded[0].hint, expected, "statement: {sql}");
+    }
+
+    Ok(())
+}
@@ -13,7 +13,12 @@
 // limitations under the License.
 
 use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_users::UserApiProvider;
+use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
@@ -69,3 +74,75 @@ async fn test_session_setting_override() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_setting_apply_to_all() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let session1 = fixture.new_session_with_type(SessionType::MySQL).await?;
+    let session2 = fixture.new_session_with_type(SessionType::MySQL).await?;
+
+    SessionManager::instance()
+        .apply_to_all(&fixture.default_session(), "max_threads", "3")
+        .await?;
+
+    assert_eq!(session1.get_settings().get_max_threads()?, 3);
+    assert_eq!(session2.get_settings().get_max_threads()?, 3);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_setting_default_catalog() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let qry = format!("select * from {db}.{tbl}");
+    fixture.execute_command(&qry).await?;
+
+    // Tables without a catalog now resolve in `iceberg_cat`, which does not exist.
+    fixture
+        .execute_command("set default_catalog = 'iceberg_cat'")
+        .await?;
+    let ctx = fixture.new_query_ctx().await?;
+    assert_eq!(ctx.get_current_catalog(), "iceberg_cat");
+
+    let err = fixture.execute_command(&qry).await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNKNOWN_CATALOG);
+    assert!(err.message().contains("iceberg_cat"));
+
+    // Qualifying the catalog still works.
+    let qry = format!("select * from default.{db}.{tbl}");
+    fixture.execute_command(&qry).await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_setting_apply_to_all_requires_super() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create user u1 identified by '123'")
+        .await?;
+
+    let user = UserApiProvider::instance()
+        .get_user(&fixture.default_tenant(), UserIdentity::new("u1", "%"))
+        .await?;
+    let caller = fixture.new_session_with_type(SessionType::MySQL).await?;
+    caller.set_authed_user(user, None).await?;
+    let other = fixture.new_session_with_type(SessionType::MySQL).await?;
+    let max_threads = other.get_settings().get_max_threads()?;
+
+    let res = SessionManager::instance()
+        .apply_to_all(&caller, "max_threads", "3")
+        .await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::PERMISSION_DENIED)
+    );
+    assert_eq!(other.get_settings().get_max_threads()?, max_threads);
+
+    Ok(())
+}
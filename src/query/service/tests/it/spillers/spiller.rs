@@ -23,6 +23,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::ScalarRef;
 use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_core::query_spill_prefix_with_path;
 use databend_common_storage::DataOperator;
 use databend_query::spillers::Spiller;
 use databend_query::spillers::SpillerConfig;
@@ -72,3 +73,38 @@ async fn test_spill_with_partition() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_spill_with_configured_path() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("spill_path".to_string(), "tenant_spill/".to_string())?;
+
+    let tenant = ctx.get_tenant();
+    let spill_path = ctx.get_settings().get_spill_path()?;
+    let location_prefix =
+        query_spill_prefix_with_path(&spill_path, tenant.tenant_name(), &ctx.get_id());
+    assert_eq!(
+        location_prefix,
+        format!("tenant_spill/{}/{}", tenant.tenant_name(), ctx.get_id())
+    );
+
+    let spiller_config = SpillerConfig::create(location_prefix.clone());
+    let operator = DataOperator::instance().operator();
+    let mut spiller = Spiller::create(ctx, operator, spiller_config, SpillerType::HashJoinBuild)?;
+
+    let data =
+        DataBlock::new_from_columns(vec![Int32Type::from_data((0..100).collect::<Vec<_>>())]);
+    spiller.spill_with_partition(0_u8, data).await?;
+
+    let location = &spiller.partition_location.get(&0).unwrap()[0];
+    assert!(location.starts_with(&location_prefix));
+    assert!(!location.starts_with("_query_spill"));
+
+    let block = DataBlock::concat(&spiller.read_spilled_partition(&(0_u8)).await?)?;
+    assert_eq!(block.num_rows(), 100);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_exception::Result;
+use databend_common_sql::plans::Plan;
 use databend_common_sql::Planner;
 use databend_common_storages_fuse::TableContext;
 use databend_query::sessions::SessionType;
@@ -26,3 +27,60 @@ async fn test_query_kind() -> Result<()> {
     assert_eq!(kind, QueryKind::CopyIntoTable);
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_explain_ast_without_binding() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    for sql in [
+        "EXPLAIN AST SELECT * FROM not_exists_db.not_exists_table",
+        "EXPLAIN SYNTAX SELECT * FROM not_exists_db.not_exists_table",
+    ] {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        assert!(matches!(
+            plan,
+            Plan::ExplainAst { .. } | Plan::ExplainSyntax { .. }
+        ));
+    }
+
+    // Other explain kinds still need to bind the inner statement.
+    let mut planner = Planner::new(ctx.clone());
+    let res = planner
+        .plan_sql("EXPLAIN SELECT * FROM not_exists_db.not_exists_table")
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_plan_extras_referenced_tables() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t1(a int, b int)"))
+        .await?;
+    fixture
+        .execute_command(&format!("create table {db}.t2(a int, c int)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let sql = format!(
+        "WITH cte AS (SELECT a FROM {db}.t2 WHERE c > 1) \
+         SELECT t1.b FROM {db}.t1 JOIN cte ON t1.a = cte.a \
+         WHERE t1.a IN (SELECT a FROM {db}.t2)"
+    );
+    let (_, extras) = planner.plan_sql(&sql).await?;
+
+    let mut tables = extras.referenced_tables;
+    tables.sort();
+    assert_eq!(tables, vec![
+        ("default".to_string(), db.clone(), "t1".to_string()),
+        ("default".to_string(), db.clone(), "t2".to_string()),
+    ]);
+    Ok(())
+}
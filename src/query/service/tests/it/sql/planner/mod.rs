@@ -14,4 +14,5 @@
 
 mod builders;
 mod optimizer;
+mod plan_sql;
 mod semantic;
@@ -84,6 +84,8 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         .into(),
         field_comments: vec![],
         as_select: None,
+        like_source: None,
+        like_including_options: false,
         cluster_key: None,
     }
 }
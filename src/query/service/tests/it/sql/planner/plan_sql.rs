@@ -0,0 +1,138 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::optimizer::SExpr;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::Planner;
+use databend_query::test_kits::TestFixture;
+
+async fn plan_with_hints(fixture: &TestFixture, sql: &str) -> Result<u64> {
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("enable_statement_hints".to_string(), "1".to_string())?;
+    let mut planner = Planner::new(ctx.clone());
+    planner.plan_sql(sql).await?;
+    ctx.get_settings().get_max_execute_time_in_seconds()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_plan_sql_max_execution_time_hint() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let timeout = plan_with_hints(&fixture, "/*+ max_execution_time(5000) */ select 1").await?;
+    assert_eq!(5, timeout);
+
+    // Milliseconds are rounded up to seconds.
+    let timeout = plan_with_hints(&fixture, "/*+ MAX_EXECUTION_TIME(1500) */ select 1").await?;
+    assert_eq!(2, timeout);
+
+    // SET_VAR hints of the statement take precedence.
+    let timeout = plan_with_hints(
+        &fixture,
+        "/*+ max_execution_time(5000) */ select /*+ SET_VAR(max_execute_time_in_seconds=7) */ 1",
+    )
+    .await?;
+    assert_eq!(7, timeout);
+
+    // The hint only applies to the query, not the session.
+    let ctx = fixture.new_query_ctx().await?;
+    assert_eq!(0, ctx.get_settings().get_max_execute_time_in_seconds()?);
+
+    // The hints are ignored unless enabled.
+    let mut planner = Planner::new(ctx.clone());
+    planner
+        .plan_sql("/*+ max_execution_time(5000) */ select 1")
+        .await?;
+    assert_eq!(0, ctx.get_settings().get_max_execute_time_in_seconds()?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_plan_sql_unknown_hint() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let timeout = plan_with_hints(
+        &fixture,
+        "/*+ unknown_hint(1) max_execution_time(abc) max_execution_time(')') */ select 1",
+    )
+    .await?;
+    assert_eq!(0, timeout);
+
+    // A known hint after unknown and malformed ones is still applied.
+    let timeout = plan_with_hints(
+        &fixture,
+        "/*+ unknown_hint('a)b') max_execution_time( max_execution_time(3000) */ select 1",
+    )
+    .await?;
+    assert_eq!(3, timeout);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_plan_sql_prql_dialect() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let prql = "from numbers(3) | select { number }";
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("sql_dialect".to_string(), "prql".to_string())?;
+
+    // PRQL is rejected unless enabled, `SET` is still allowed to switch back.
+    let mut planner = Planner::new(ctx.clone());
+    let err = planner.plan_sql(prql).await.unwrap_err();
+    assert_eq!(ErrorCode::BAD_ARGUMENTS, err.code());
+    assert!(err.message().contains("enable_prql"));
+    planner.plan_sql("set sql_dialect = 'PostgreSQL'").await?;
+
+    ctx.get_settings()
+        .set_setting("enable_prql".to_string(), "1".to_string())?;
+    let mut planner = Planner::new(ctx.clone());
+    planner.plan_sql(prql).await?;
+
+    Ok(())
+}
+
+fn has_materialized_cte(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::MaterializedCte(_))
+        || s_expr.children().any(has_materialized_cte)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_plan_sql_cte_materialized_hint() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    for (hint, materialized) in [
+        ("", false),
+        ("materialized", true),
+        ("not materialized", false),
+    ] {
+        let ctx = fixture.new_query_ctx().await?;
+        let mut planner = Planner::new(ctx.clone());
+        let sql = format!("with t as {hint} (select number from numbers(3)) select * from t");
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let Plan::Query { s_expr, .. } = plan else {
+            unreachable!()
+        };
+        assert_eq!(materialized, has_materialized_cte(&s_expr), "{sql}");
+    }
+
+    Ok(())
+}
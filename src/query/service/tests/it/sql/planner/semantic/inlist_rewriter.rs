@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_query::sql::InListRewriter;
+use databend_query::test_kits::TestFixture;
+use derive_visitor::DriveMut;
+use futures_util::TryStreamExt;
+
+fn in_list_sql(not: bool, n: usize) -> String {
+    let items = (0..n)
+        .map(|i| (i * 2).to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let not = if not { "NOT " } else { "" };
+    format!("number {not}IN ({items})")
+}
+
+#[test]
+fn test_inlist_rewriter() {
+    for not in [false, true] {
+        let sql = in_list_sql(not, 10_000);
+        let tokens = tokenize_sql(&sql).unwrap();
+        let mut expr = parse_expr(&tokens, Dialect::PostgreSQL).unwrap();
+        expr.drive_mut(&mut InListRewriter::new(1024));
+
+        let Expr::InSubquery { subquery, not: n, .. } = &expr else {
+            panic!("expect IN subquery, got {expr}");
+        };
+        assert_eq!(*n, not);
+        let SetExpr::Values { values, .. } = &subquery.body else {
+            panic!("expect VALUES, got {}", subquery.body);
+        };
+        assert_eq!(values.len(), 10_000);
+    }
+
+    // Short lists and lists with non-constant items are kept.
+    for sql in ["number IN (1, 2, 3)", "number IN (1, 2, number + 1)"] {
+        let tokens = tokenize_sql(sql).unwrap();
+        let mut expr = parse_expr(&tokens, Dialect::PostgreSQL).unwrap();
+        expr.drive_mut(&mut InListRewriter::new(3));
+        assert!(matches!(expr, Expr::InList { .. }), "{sql}");
+    }
+}
+
+async fn query_result(fixture: &TestFixture, sql: &str) -> Result<String> {
+    let blocks = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(pretty_format_blocks(&blocks)?)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_inlist_rewriter_results() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let queries = [
+        (
+            format!(
+                "SELECT count(*), sum(number) FROM numbers(30000) WHERE {}",
+                in_list_sql(false, 10_000)
+            ),
+            1024,
+        ),
+        (
+            format!(
+                "SELECT count(*), sum(number) FROM numbers(30000) WHERE {}",
+                in_list_sql(true, 10_000)
+            ),
+            1024,
+        ),
+        // A NULL in the list makes `NOT IN` never true. The naive form of a list
+        // with NULL is bound as a chain of `OR`, so keep it short.
+        (
+            format!(
+                "SELECT count(*) FROM numbers(300) WHERE {}",
+                in_list_sql(true, 100).replace("(0,", "(NULL,")
+            ),
+            10,
+        ),
+    ];
+
+    for (sql, threshold) in queries.iter() {
+        fixture
+            .execute_command(&format!("SET inlist_to_join_threshold = {threshold}"))
+            .await?;
+        let rewritten = query_result(&fixture, sql).await?;
+
+        // Disable the rewrite to get the result of the naive form.
+        fixture
+            .execute_command("SET inlist_to_join_threshold = 100000")
+            .await?;
+        let naive = query_result(&fixture, sql).await?;
+
+        assert_eq!(rewritten, naive, "{sql}");
+    }
+
+    let result = query_result(&fixture, &queries[2].0).await?;
+    assert!(result.contains("| 0 "), "{result}");
+
+    Ok(())
+}
@@ -12,11 +12,22 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use databend_common_base::runtime::metrics::MetricValue;
+use databend_common_base::runtime::metrics::GLOBAL_METRICS_REGISTRY;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_query::storages::fuse::io::BlockBuilder;
+use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use databend_query::storages::fuse::io::WriteSettings;
+use databend_query::storages::fuse::statistics::ClusterStatsGenerator;
+use databend_query::storages::fuse::FuseTable;
 use databend_query::test_kits::TestFixture;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use futures_util::TryStreamExt;
@@ -80,3 +91,172 @@ async fn test_array_cache_of_nested_column_iusse_14502() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_skip_bloom_index_for_small_blocks() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    // default bloom_index_min_block_rows is 1000
+    fixture
+        .execute_command(&format!("create table {db}.t_small(a int, b string)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "insert into {db}.t_small select number, to_string(number) from numbers(10)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "insert into {db}.t_small select number, to_string(number) from numbers(5000)"
+        ))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_catalog(&fixture.default_catalog_name())
+        .await?
+        .get_table(&fixture.default_tenant(), &db, "t_small")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+
+    let segment_reader =
+        MetaReaders::segment_info_reader(fuse_table.get_operator(), table.schema());
+    let mut checked = 0;
+    for (location, ver) in &snapshot.segments {
+        let params = LoadParams {
+            location: location.clone(),
+            len_hint: None,
+            ver: *ver,
+            put_cache: false,
+        };
+        let segment = SegmentInfo::try_from(segment_reader.read(&params).await?)?;
+        for block_meta in &segment.blocks {
+            match block_meta.row_count {
+                10 => assert!(block_meta.bloom_filter_index_location.is_none()),
+                5000 => assert!(block_meta.bloom_filter_index_location.is_some()),
+                n => unreachable!("unexpected block with {n} rows"),
+            }
+            checked += 1;
+        }
+    }
+    assert_eq!(checked, 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_serialization_column_sizes() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // id, t.a, t.b: three leaf columns
+    let (schema, blocks) = TestFixture::gen_sample_blocks_ex(1, 100, 0);
+    let block = blocks.into_iter().next().unwrap()?;
+
+    let block_builder = BlockBuilder {
+        ctx,
+        meta_locations: TableMetaLocationGenerator::with_prefix("test_prefix".to_owned()),
+        source_schema: schema,
+        write_settings: WriteSettings::default(),
+        cluster_stats_gen: ClusterStatsGenerator::default(),
+        bloom_columns_map: Default::default(),
+        inverted_index_builders: vec![],
+    };
+    let serialized = block_builder.build(block, |block, generator| {
+        generator.gen_stats_for_append(block)
+    })?;
+
+    let column_sizes = &serialized.column_sizes;
+    assert_eq!(column_sizes.len(), 3);
+    assert_eq!(column_sizes.len(), serialized.block_meta.col_metas.len());
+
+    // column chunks are laid out back to back, so the per-column sizes
+    // must add up to the whole data region of the block file.
+    let (start, end) = serialized
+        .block_meta
+        .col_metas
+        .values()
+        .map(|col_meta| col_meta.offset_length())
+        .fold((u64::MAX, 0), |(start, end), (offset, len)| {
+            (start.min(offset), end.max(offset + len))
+        });
+    let total: u64 = column_sizes.values().sum();
+    assert_eq!(total, end - start);
+    assert!(total <= serialized.size);
+
+    Ok(())
+}
+
+fn block_write_bytes_per_column(table_id: u64, column_id: u32) -> Result<f64> {
+    let samples = GLOBAL_METRICS_REGISTRY.dump_sample()?;
+    Ok(samples
+        .iter()
+        .find(|sample| {
+            sample.name == "fuse_block_write_bytes_per_column_total"
+                && sample.labels.get("table_id") == Some(&table_id.to_string())
+                && sample.labels.get("column_id") == Some(&column_id.to_string())
+        })
+        .map(|sample| match sample.value {
+            MetricValue::Counter(v) => v,
+            _ => 0.0,
+        })
+        .unwrap_or_default())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_write_bytes_per_column_metrics() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!("create table {db}.t_metrics(a int, b string)"))
+        .await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_catalog(&fixture.default_catalog_name())
+        .await?
+        .get_table(&fixture.default_tenant(), &db, "t_metrics")
+        .await?;
+    let table_id = table.get_id();
+    let before = [
+        block_write_bytes_per_column(table_id, 0)?,
+        block_write_bytes_per_column(table_id, 1)?,
+    ];
+
+    fixture
+        .execute_command(&format!(
+            "insert into {db}.t_metrics select number, to_string(number) from numbers(100)"
+        ))
+        .await?;
+
+    let table = table.refresh(ctx.as_ref()).await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let segment_reader =
+        MetaReaders::segment_info_reader(fuse_table.get_operator(), table.schema());
+    let (location, ver) = &snapshot.segments[0];
+    let params = LoadParams {
+        location: location.clone(),
+        len_hint: None,
+        ver: *ver,
+        put_cache: false,
+    };
+    let segment = SegmentInfo::try_from(segment_reader.read(&params).await?)?;
+    assert_eq!(segment.blocks.len(), 1);
+    let col_metas = &segment.blocks[0].col_metas;
+
+    // Other tests of the process may write to a table with the same id.
+    for column_id in 0..2 {
+        let column_size = col_metas[&column_id].offset_length().1 as f64;
+        let written =
+            block_write_bytes_per_column(table_id, column_id)? - before[column_id as usize];
+        assert!(column_size > 0.0);
+        assert!(written >= column_size, "{written} < {column_size}");
+    }
+
+    Ok(())
+}
@@ -57,6 +57,8 @@ async fn test_fuse_alter_table_cluster_key() -> databend_common_exception::Resul
         .into(),
         field_comments: vec![],
         as_select: None,
+        like_source: None,
+        like_including_options: false,
         cluster_key: None,
     };
 
@@ -17,6 +17,8 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::DateTime;
+use chrono::Utc;
 use dashmap::DashMap;
 use databend_common_base::base::tokio;
 use databend_common_base::base::Progress;
@@ -45,9 +47,11 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_io::prelude::FormatSettings;
+use databend_common_meta_app::principal::FileFormatOptionsAst;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::schema::CatalogInfo;
@@ -511,6 +515,10 @@ impl TableContext for CtxDelegation {
         self.ctx.get_id()
     }
 
+    fn get_statement_timestamp(&self) -> DateTime<Utc> {
+        self.ctx.get_statement_timestamp()
+    }
+
     fn get_current_catalog(&self) -> String {
         "default".to_owned()
     }
@@ -630,6 +638,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn resolve_stage_and_format(
+        &self,
+        _location: &str,
+        _format_ref: &FileFormatOptionsAst,
+    ) -> Result<(StageInfo, FileFormatParams)> {
+        todo!()
+    }
+
     async fn get_connection(&self, _name: &str) -> Result<UserDefinedConnection> {
         todo!()
     }
@@ -25,5 +25,8 @@ mod optimize;
 mod purge_drop;
 mod read_plan;
 mod replace_into;
+mod retention;
+mod revert;
+mod serialize_block;
 mod table_analyze;
 mod truncate;
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::Duration;
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
+use databend_common_meta_app::schema::CreateDatabaseReq;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::DatabaseMeta;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
+use databend_query::test_kits::*;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_effective_retention() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    // Without any option, the setting applies.
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_data_retention_time_in_days(3)?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert_eq!(
+        fuse_table.effective_retention(ctx.as_ref()).await?,
+        Duration::days(3)
+    );
+
+    // The database option overrides the setting.
+    let db_name = "db_retention";
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog("default").await?;
+    catalog
+        .create_database(CreateDatabaseReq {
+            create_option: CreateOption::Create,
+            name_ident: DatabaseNameIdent::new(&tenant, db_name),
+            meta: DatabaseMeta {
+                engine: "".to_string(),
+                options: BTreeMap::from([(
+                    FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS.to_string(),
+                    "5".to_string(),
+                )]),
+                ..Default::default()
+            },
+        })
+        .await?;
+    fixture
+        .execute_command(&format!("create table {db_name}.t(a int)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_data_retention_time_in_days(3)?;
+    let table = ctx.get_table("default", db_name, "t").await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert_eq!(
+        fuse_table.effective_retention(ctx.as_ref()).await?,
+        Duration::days(5)
+    );
+
+    // The table option overrides the database option.
+    fixture
+        .execute_command(&format!(
+            "alter table {db_name}.t set options({} = 7)",
+            FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS
+        ))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_data_retention_time_in_days(3)?;
+    let table = ctx.get_table("default", db_name, "t").await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert_eq!(
+        fuse_table.effective_retention(ctx.as_ref()).await?,
+        Duration::days(7)
+    );
+
+    Ok(())
+}
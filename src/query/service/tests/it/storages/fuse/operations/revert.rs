@@ -0,0 +1,76 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::test_kits::*;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_revert_dry_run() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    let qry = format!("insert into {db}.{tbl} values (1, (2, 3)), (2, (4, 6))");
+    fixture.execute_command(&qry).await?;
+    let table = fixture.latest_default_table().await?;
+    let first_snapshot = FuseTable::try_from_table(table.as_ref())?
+        .read_table_snapshot()
+        .await?
+        .unwrap();
+
+    let qry = format!("insert into {db}.{tbl} values (3, (6, 9))");
+    fixture.execute_command(&qry).await?;
+    let table = fixture.latest_default_table().await?;
+    let current_location = FuseTable::try_from_table(table.as_ref())?
+        .snapshot_loc()
+        .await?;
+
+    let qry = format!(
+        "alter table {db}.{tbl} flashback to (snapshot => '{}') dry run",
+        first_snapshot.snapshot_id.simple()
+    );
+    let blocks = fixture
+        .execute_query(&qry)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), 1);
+
+    let value = |idx: usize| block.get_by_offset(idx).value.index(0).unwrap();
+    assert_eq!(
+        value(1),
+        ScalarRef::String(&first_snapshot.snapshot_id.simple().to_string())
+    );
+    // the rows of the second insertion would be removed
+    assert_eq!(value(2), ScalarRef::Number(NumberScalar::UInt64(0)));
+    assert_eq!(value(3), ScalarRef::Number(NumberScalar::UInt64(1)));
+
+    // the current snapshot is kept
+    let table = fixture.latest_default_table().await?;
+    let location = FuseTable::try_from_table(table.as_ref())?
+        .snapshot_loc()
+        .await?;
+    assert_eq!(location, current_location);
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_sql::executor::physical_plans::MutationKind;
+use databend_common_storages_fuse::operations::MutationLogEntry;
+use databend_common_storages_fuse::operations::MutationLogs;
+use databend_common_storages_fuse::operations::TransformSerializeBlock;
+use databend_common_storages_fuse::statistics::ClusterStatsGenerator;
+use databend_common_storages_fuse::FuseTable;
+use databend_query::pipelines::processors::connect;
+use databend_query::pipelines::processors::InputPort;
+use databend_query::pipelines::processors::OutputPort;
+use databend_query::pipelines::processors::Processor;
+use databend_query::test_kits::*;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_serialize_block_unexpected_meta() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let input = InputPort::create();
+    let output = OutputPort::create();
+    let mut processor = TransformSerializeBlock::try_create(
+        ctx,
+        input.clone(),
+        output.clone(),
+        fuse_table,
+        ClusterStatsGenerator::default(),
+        MutationKind::Insert,
+    )?;
+
+    let upstream_output = OutputPort::create();
+    let downstream_input = InputPort::create();
+    unsafe {
+        connect(&input, &upstream_output);
+        connect(&downstream_input, &output);
+    }
+
+    // a meta that TransformSerializeBlock does not understand
+    let meta = MutationLogs {
+        entries: vec![MutationLogEntry::DoNothing],
+    };
+    upstream_output.push_data(Ok(DataBlock::empty_with_meta(Box::new(meta))));
+    downstream_input.set_need_data();
+
+    let err = processor.event().unwrap_err();
+    assert!(err.message().contains("MutationLogs"), "{}", err.message());
+
+    // calling process in a wrong state reports the state
+    let err = processor.process().unwrap_err();
+    assert!(err.message().contains("Consume"), "{}", err.message());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_serialize_block_append_part() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    fixture.create_default_database().await?;
+
+    let qry = format!("create table {db}.t(a int not null) row_per_block = 2");
+    fixture.execute_command(&qry).await?;
+    fixture.execute_command("set max_threads = 1").await?;
+    fixture
+        .execute_command("set fuse_append_part_file_size = 1048576")
+        .await?;
+    let qry = format!("insert into {db}.t select number from numbers(10)");
+    fixture.execute_command(&qry).await?;
+
+    // the five blocks share one data file
+    let qry =
+        format!("select count(distinct block_location), count(*) from fuse_block('{db}', 't')");
+    let block = query_block(&fixture, &qry).await?;
+    let value = |idx: usize| block.get_by_offset(idx).value.index(0).unwrap();
+    assert_eq!(value(0), ScalarRef::Number(NumberScalar::UInt64(1)));
+    assert_eq!(value(1), ScalarRef::Number(NumberScalar::UInt64(5)));
+
+    // and each of them is still read back from its own offsets
+    let qry = format!("select count(*), sum(a) from {db}.t");
+    let block = query_block(&fixture, &qry).await?;
+    let value = |idx: usize| block.get_by_offset(idx).value.index(0).unwrap();
+    assert_eq!(value(0), ScalarRef::Number(NumberScalar::UInt64(10)));
+    assert_eq!(value(1), ScalarRef::Number(NumberScalar::Int64(45)));
+
+    Ok(())
+}
+
+async fn query_block(fixture: &TestFixture, qry: &str) -> Result<DataBlock> {
+    let blocks = fixture
+        .execute_query(qry)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    DataBlock::concat(&blocks)
+}
@@ -90,6 +90,40 @@ async fn test_table_modify_column_ndv_statistics() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_after_create_table_as_select() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture
+        .execute_command("set enable_analyze_after_create_table_as_select = 1")
+        .await?;
+    fixture.execute_command("create table t(c int)").await?;
+    let num_inserts = 3;
+    append_rows(ctx.clone(), num_inserts).await?;
+    fixture
+        .execute_command("create table t2 as select * from t")
+        .await?;
+
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(&ctx.get_tenant(), "default", "t2")
+        .await?;
+
+    // the table meta carries the statistics of the inserted data.
+    let stats = &table.get_table_info().meta.statistics;
+    assert_eq!(stats.number_of_rows, num_inserts as u64);
+
+    // and the column statistics are collected without a separate ANALYZE.
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    assert!(snapshot.table_statistics_location.is_some());
+    let expected = HashMap::from([(0, num_inserts as u64)]);
+    check_column_ndv_statistics(ctx.clone(), table.clone(), expected).await?;
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_table_update_analyze_statistics() -> Result<()> {
     let fixture = TestFixture::setup().await?;
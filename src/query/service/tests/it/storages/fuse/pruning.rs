@@ -107,6 +107,8 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        like_source: None,
+        like_including_options: false,
         cluster_key: None,
     };
 
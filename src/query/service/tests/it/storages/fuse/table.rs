@@ -21,7 +21,9 @@ use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use databend_query::storages::fuse::FuseTable;
 use databend_query::stream::ReadDataBlockStream;
 use databend_query::test_kits::*;
+use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use futures::TryStreamExt;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -147,3 +149,20 @@ fn test_parse_storage_prefix() -> Result<()> {
     assert_eq!(format!("{}/{}", db_id, tbl_id), prefix);
     Ok(())
 }
+
+#[test]
+fn test_fuse_table_description_supported_options() -> Result<()> {
+    let description = FuseTable::description();
+    assert_eq!(description.engine_name, "FUSE");
+    assert!(
+        description
+            .supported_options
+            .contains(&OPT_KEY_BLOOM_INDEX_COLUMNS.to_string())
+    );
+    assert!(
+        description
+            .supported_options
+            .contains(&OPT_KEY_TABLE_COMPRESSION.to_string())
+    );
+    Ok(())
+}
@@ -20,6 +20,7 @@ mod settings_global;
 pub use settings::ChangeValue;
 pub use settings::ScopeLevel;
 pub use settings::Settings;
+pub use settings::SettingsItem;
 pub use settings_default::ReplaceIntoShuffleStrategy;
 pub use settings_default::SettingMode;
 pub use settings_default::SettingRange;
@@ -29,7 +29,7 @@ use crate::settings_default::DefaultSettings;
 use crate::settings_default::SettingRange;
 use crate::SettingMode;
 
-#[derive(serde::Serialize, serde::Deserialize, Clone)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
 pub enum ScopeLevel {
     Default,
     Local,
@@ -94,6 +94,15 @@ impl Settings {
         Ok(val)
     }
 
+    /// Returns the range of values the setting accepts, enforced whenever it is set.
+    pub fn get_setting_range(&self, key: &str) -> Result<Option<SettingRange>> {
+        let range = DefaultSettings::instance()?
+            .settings
+            .get(key)
+            .and_then(|v| v.range.clone());
+        Ok(range)
+    }
+
     pub fn unset_setting(&self, k: &str) {
         self.changes.remove(k);
     }
@@ -116,6 +125,27 @@ impl Settings {
         &self.changes
     }
 
+    /// Returns `(name, current value, default value)` of the settings whose current value
+    /// differs from the default, sorted by name.
+    pub fn changed_settings(&self) -> Vec<(String, String, String)> {
+        self.into_iter()
+            .filter(|item| item.user_value != item.default_value)
+            .map(|item| {
+                (
+                    item.name,
+                    item.user_value.as_string(),
+                    item.default_value.as_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns all the visible settings sorted by name, together with the scope
+    /// level their current value comes from.
+    pub fn list_all(&self) -> Vec<SettingsItem> {
+        self.into_iter().collect()
+    }
+
     /// # Safety
     ///
     /// We will not validate the setting value type
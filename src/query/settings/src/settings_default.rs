@@ -49,6 +49,8 @@ pub enum SettingMode {
 pub enum SettingRange {
     Numeric(RangeInclusive<u64>),
     String(Vec<String>),
+    /// A non-empty path relative to the storage root, which does not leave it.
+    RelativePath,
 }
 
 impl Display for SettingRange {
@@ -56,6 +58,7 @@ impl Display for SettingRange {
         match self {
             SettingRange::Numeric(range) => write!(f, "[{}, {}]", range.start(), range.end()),
             SettingRange::String(values) => write!(f, "{:?}", values),
+            SettingRange::RelativePath => write!(f, "relative path"),
         }
     }
 }
@@ -95,6 +98,28 @@ impl SettingRange {
             _ => Err(ErrorCode::BadArguments("Expected string range".to_string())),
         }
     }
+
+    /// Checks if a string value is a relative path inside the storage root.
+    pub fn is_relative_path(&self, value: &str) -> Result<()> {
+        match self {
+            SettingRange::RelativePath => {
+                let invalid = value.is_empty()
+                    || value.starts_with('/')
+                    || value.split('/').any(|segment| segment == "..");
+                if invalid {
+                    Err(ErrorCode::WrongValueForVariable(format!(
+                        "Value {:?} is not a {} inside the storage root",
+                        value, self
+                    )))
+                } else {
+                    Ok(())
+                }
+            }
+            _ => Err(ErrorCode::BadArguments(
+                "Expected relative path range".to_string(),
+            )),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -254,6 +279,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::String(vec!["PostgreSQL".into(), "MySQL".into(), "Experimental".into(), "Hive".into(), "Prql".into()])),
                 }),
+                ("enable_prql", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables the \"Prql\" sql_dialect, statements are rejected in the Prql dialect unless it is enabled.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_statement_hints", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables the hints in the leading comment of a statement, e.g. /*+ max_execution_time(5000) */.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("default_catalog", DefaultSettingValue {
+                    value: UserSettingValue::String("default".to_owned()),
+                    desc: "Sets the catalog that table references without an explicit catalog resolve against.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("enable_dphyp", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables dphyp join order algorithm.",
@@ -326,6 +369,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("application_name", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Sets the application name reported in query logs and metrics, overriding the one passed by the client at connect. Clients that can't pass it at connect, e.g. MySQL clients, can set it with this setting.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("prefer_broadcast_join", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables broadcast join.",
@@ -540,6 +589,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_analyze_after_create_table_as_select", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables analyze table after CREATE TABLE AS SELECT, so that column statistics are available to the first query.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("fuse_append_part_file_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Packs the small blocks written by INSERT into one data file until it reaches this size in bytes, 0 writes every block to its own file.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("auto_compaction_imperfect_blocks_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(25),
                     desc: "Threshold for triggering auto compaction. This occurs when the number of imperfect blocks in a snapshot exceeds this value after write operations.",
@@ -660,6 +721,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("ownership_cache_ttl_in_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets how long the ownership listing of system tables may be served from cache, changes on other nodes may be unseen for this long (0 disables the cache).",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=3600)),
+                }),
                 ("create_query_flight_client_with_current_rt", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Turns on (1) or off (0) the use of the current runtime for query operations.",
@@ -721,6 +788,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_describe_view_lineage", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables showing the source table column of each view column in DESCRIBE VIEW.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("idle_transaction_timeout_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(4 * 60 * 60),
                     desc: "Set the timeout in seconds for active session without any query",
@@ -751,6 +824,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("spill_path", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_string()),
+                    desc: "Sets the path relative to the storage root where the spilled data of queries are written, the default is '_query_spill'.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::RelativePath),
+                }),
                 ("max_vacuum_temp_files_after_query", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "The maximum temp files will be removed after query. please enable vacuum feature. The default value is 0(all temp files)",
@@ -874,6 +953,12 @@ impl DefaultSettings {
 
                         Ok((k, UserSettingValue::String(value)))
                     }
+                    // Relative path.
+                    SettingRange::RelativePath => {
+                        range.is_relative_path(&v)?;
+
+                        Ok((k, UserSettingValue::String(v)))
+                    }
                 }
             }
         }
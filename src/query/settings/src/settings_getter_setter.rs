@@ -261,6 +261,10 @@ impl Settings {
         self.try_get_u64("max_result_rows")
     }
 
+    pub fn get_application_name(&self) -> Result<String> {
+        self.try_get_string("application_name")
+    }
+
     pub fn get_enable_dphyp(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_dphyp")? != 0)
     }
@@ -306,6 +310,14 @@ impl Settings {
         Ok(self.try_get_u64("disable_merge_into_join_reorder")? != 0)
     }
 
+    pub fn get_default_catalog(&self) -> Result<String> {
+        self.try_get_string("default_catalog")
+    }
+
+    pub fn set_default_catalog(&self, val: String) -> Result<()> {
+        self.set_setting("default_catalog".to_string(), val)
+    }
+
     pub fn get_sql_dialect(&self) -> Result<Dialect> {
         match self.try_get_string("sql_dialect")?.to_lowercase().as_str() {
             "hive" => Ok(Dialect::Hive),
@@ -316,6 +328,14 @@ impl Settings {
         }
     }
 
+    pub fn get_enable_prql(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_prql")? != 0)
+    }
+
+    pub fn get_enable_statement_hints(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_statement_hints")? != 0)
+    }
+
     pub fn get_collation(&self) -> Result<&str> {
         match self.try_get_string("collation")?.to_lowercase().as_str() {
             "utf8" => Ok("utf8"),
@@ -415,6 +435,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_experimental_rbac_check")? != 0)
     }
 
+    pub fn get_ownership_cache_ttl_in_secs(&self) -> Result<u64> {
+        self.try_get_u64("ownership_cache_ttl_in_secs")
+    }
+
     pub fn get_table_lock_expire_secs(&self) -> Result<u64> {
         self.try_get_u64("table_lock_expire_secs")
     }
@@ -476,6 +500,25 @@ impl Settings {
         Ok(self.try_get_u64("enable_compact_after_write")? != 0)
     }
 
+    pub fn get_enable_analyze_after_create_table_as_select(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_analyze_after_create_table_as_select")? != 0)
+    }
+
+    pub fn set_enable_analyze_after_create_table_as_select(&self, val: bool) -> Result<()> {
+        self.try_set_u64(
+            "enable_analyze_after_create_table_as_select",
+            u64::from(val),
+        )
+    }
+
+    pub fn get_fuse_append_part_file_size(&self) -> Result<u64> {
+        self.try_get_u64("fuse_append_part_file_size")
+    }
+
+    pub fn set_fuse_append_part_file_size(&self, val: u64) -> Result<()> {
+        self.try_set_u64("fuse_append_part_file_size", val)
+    }
+
     pub fn get_auto_compaction_imperfect_blocks_threshold(&self) -> Result<u64> {
         self.try_get_u64("auto_compaction_imperfect_blocks_threshold")
     }
@@ -629,6 +672,14 @@ impl Settings {
         self.try_set_u64("enable_geo_create_table", u64::from(val))
     }
 
+    pub fn get_enable_describe_view_lineage(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_describe_view_lineage")? != 0)
+    }
+
+    pub fn set_enable_describe_view_lineage(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enable_describe_view_lineage", u64::from(val))
+    }
+
     pub fn get_idle_transaction_timeout_secs(&self) -> Result<u64> {
         self.try_get_u64("idle_transaction_timeout_secs")
     }
@@ -653,4 +704,8 @@ impl Settings {
     pub fn get_max_vacuum_temp_files_after_query(&self) -> Result<u64> {
         self.try_get_u64("max_vacuum_temp_files_after_query")
     }
+
+    pub fn get_spill_path(&self) -> Result<String> {
+        self.try_get_string("spill_path")
+    }
 }
@@ -198,3 +198,86 @@ async fn test_set_data_retention_time_in_days_from_config() {
         assert_eq!(expect, format!("{}", result.unwrap_err()));
     }
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_changed_settings() {
+    let settings = Settings::create(Tenant::new_literal("test"));
+    assert!(settings.changed_settings().is_empty());
+
+    settings
+        .set_setting("sql_dialect".to_string(), "MySQL".to_string())
+        .unwrap();
+    settings
+        .set_setting("enable_dphyp".to_string(), "0".to_string())
+        .unwrap();
+    // Set to the default value, not a change.
+    settings
+        .set_setting("timezone".to_string(), "UTC".to_string())
+        .unwrap();
+
+    let expect = vec![
+        ("enable_dphyp".to_string(), "0".to_string(), "1".to_string()),
+        (
+            "sql_dialect".to_string(),
+            "MySQL".to_string(),
+            "PostgreSQL".to_string(),
+        ),
+    ];
+    assert_eq!(expect, settings.changed_settings());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_numeric_range() {
+    let settings = Settings::create(Tenant::new_literal("test"));
+
+    let range = settings.get_setting_range("max_threads").unwrap().unwrap();
+    assert_eq!("[1, 1024]", range.to_string());
+
+    // Below min.
+    {
+        let result = settings.set_setting("max_threads".to_string(), "0".to_string());
+        let expect =
+            "WrongValueForVariable. Code: 2803, Text = Value 0 is not within the range [1, 1024].";
+        assert_eq!(expect, format!("{}", result.unwrap_err()));
+    }
+
+    // Above max.
+    {
+        let result = settings.set_setting("max_threads".to_string(), "1025".to_string());
+        let expect = "WrongValueForVariable. Code: 2803, Text = Value 1025 is not within the range [1, 1024].";
+        assert_eq!(expect, format!("{}", result.unwrap_err()));
+    }
+
+    // In range.
+    {
+        settings
+            .set_setting("max_threads".to_string(), "8".to_string())
+            .unwrap();
+        assert_eq!(8, settings.get_max_threads().unwrap());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_spill_path() {
+    let settings = Settings::create(Tenant::new_literal("test"));
+
+    let range = settings.get_setting_range("spill_path").unwrap().unwrap();
+    assert_eq!("relative path", range.to_string());
+
+    // Ok
+    settings
+        .set_setting("spill_path".to_string(), "spill/q1".to_string())
+        .unwrap();
+    assert_eq!("spill/q1", settings.get_spill_path().unwrap());
+
+    // Error
+    for value in ["", "/spill", "../spill", "spill/../../other", ".."] {
+        let result = settings.set_setting("spill_path".to_string(), value.to_string());
+        let expect = format!(
+            "WrongValueForVariable. Code: 2803, Text = Value {:?} is not a relative path inside the storage root.",
+            value
+        );
+        assert_eq!(expect, format!("{}", result.unwrap_err()));
+    }
+    assert_eq!("spill/q1", settings.get_spill_path().unwrap());
+}
@@ -21,6 +21,7 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::UDFOnError;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::PhysicalPlan;
@@ -65,6 +66,7 @@ pub struct UdfFunctionDesc {
     pub data_type: Box<DataType>,
 
     pub udf_type: UDFType,
+    pub on_error: UDFOnError,
 }
 
 impl PhysicalPlanBuilder {
@@ -146,6 +148,7 @@ impl PhysicalPlanBuilder {
                         arg_exprs,
                         data_type: func.return_type.clone(),
                         udf_type: func.udf_type.clone(),
+                        on_error: func.on_error,
                     };
                     Ok(udf_func)
                 } else {
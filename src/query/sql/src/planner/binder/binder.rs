@@ -263,7 +263,7 @@ impl<'a> Binder {
             Statement::ShowMetrics { show_options } => self.bind_show_metrics(bind_context, show_options).await?,
             Statement::ShowProcessList { show_options } => self.bind_show_process_list(bind_context, show_options).await?,
             Statement::ShowEngines { show_options } => self.bind_show_engines(bind_context, show_options).await?,
-            Statement::ShowSettings { show_options } => self.bind_show_settings(bind_context, show_options).await?,
+            Statement::ShowSettings { scope, show_options } => self.bind_show_settings(bind_context, scope, show_options).await?,
             Statement::ShowIndexes { show_options } => self.bind_show_indexes(bind_context, show_options).await?,
             Statement::ShowLocks(stmt) => self.bind_show_locks(bind_context, stmt).await?,
             // Catalogs
@@ -304,6 +304,7 @@ impl<'a> Binder {
             Statement::AlterTable(stmt) => self.bind_alter_table(bind_context, stmt).await?,
             Statement::RenameTable(stmt) => self.bind_rename_table(stmt).await?,
             Statement::TruncateTable(stmt) => self.bind_truncate_table(stmt).await?,
+            Statement::TruncateTables(stmt) => self.bind_truncate_tables(stmt).await?,
             Statement::OptimizeTable(stmt) => self.bind_optimize_table(bind_context, stmt).await?,
             Statement::VacuumTable(stmt) => self.bind_vacuum_table(bind_context, stmt).await?,
             Statement::VacuumDropTable(stmt) => self.bind_vacuum_drop_table(bind_context, stmt).await?,
@@ -616,7 +617,7 @@ impl<'a> Binder {
             Statement::CreateStream(stmt) => self.bind_create_stream(bind_context, stmt).await?,
             Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
             Statement::ShowStreams(stmt) => self.bind_show_streams(bind_context, stmt).await?,
-            Statement::DescribeStream(stmt) => self.bind_describe_stream(bind_context, stmt).await?,
+            Statement::DescribeStream(stmt) => self.bind_describe_stream(stmt).await?,
 
             // Dynamic Table
             Statement::CreateDynamicTable(stmt) => self.bind_create_dynamic_table(stmt).await?,
@@ -894,7 +895,7 @@ impl<'a> Binder {
                     .map(|c| normalize_identifier(c, &self.name_resolution_ctx).name)
                     .collect(),
                 query: *cte.query.clone(),
-                materialized: cte.materialized,
+                materialized: cte.materialized.unwrap_or(false),
                 cte_idx: idx,
                 used_count: 0,
                 columns: vec![],
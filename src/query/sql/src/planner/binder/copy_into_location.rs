@@ -21,7 +21,6 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::StageInfo;
 
-use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::Binder;
 use crate::plans::CopyIntoLocationPlan;
 use crate::plans::Plan;
@@ -76,7 +75,9 @@ impl<'a> Binder {
             }
         }?;
 
-        let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), &stmt.dst).await?;
+        let (mut stage_info, path) = self
+            .resolve_file_location_and_format(&stmt.dst, &stmt.file_format.to_meta_ast())
+            .await?;
         self.apply_copy_into_location_options(stmt, &mut stage_info)
             .await?;
 
@@ -93,10 +94,6 @@ impl<'a> Binder {
         stmt: &CopyIntoLocationStmt,
         stage: &mut StageInfo,
     ) -> Result<()> {
-        if !stmt.file_format.is_empty() {
-            stage.file_format_params = self.try_resolve_file_format(&stmt.file_format).await?;
-        }
-
         // Copy options.
         {
             // max_file_size.
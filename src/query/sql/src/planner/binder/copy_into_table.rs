@@ -135,9 +135,10 @@ impl<'a> Binder {
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
 
-        let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
-        self.apply_copy_into_table_options(stmt, &mut stage_info)
+        let (mut stage_info, path) = self
+            .resolve_file_location_and_format(location, &stmt.file_format.to_meta_ast())
             .await?;
+        stmt.apply_to_copy_option(&mut stage_info.copy_options)?;
         let files_info = StageFilesInfo {
             path,
             files: stmt.files.clone(),
@@ -190,6 +191,11 @@ impl<'a> Binder {
         bind_ctx: &BindContext,
         plan: CopyIntoTablePlan,
     ) -> Result<Plan> {
+        plan.stage_table_info
+            .stage_info
+            .file_format_params
+            .validate_for_schema(&plan.stage_table_info.schema)?;
+
         if let FileFormatParams::Parquet(fmt) = &plan.stage_table_info.stage_info.file_format_params
             && fmt.missing_field_as == NullAs::Error
         {
@@ -450,16 +456,35 @@ impl<'a> Binder {
         Ok(Plan::CopyIntoTable(Box::new(plan)))
     }
 
+    /// Resolve the location with the file format given by `format_ref`, which falls back to
+    /// the file format of the stage when empty.
     #[async_backtrace::framed]
-    pub async fn apply_copy_into_table_options(
-        &mut self,
-        stmt: &CopyIntoTableStmt,
-        stage: &mut StageInfo,
-    ) -> Result<()> {
-        if !stmt.file_format.is_empty() {
-            stage.file_format_params = self.try_resolve_file_format(&stmt.file_format).await?;
+    pub(crate) async fn resolve_file_location_and_format(
+        &self,
+        location: &FileLocation,
+        format_ref: &FileFormatOptionsAst,
+    ) -> Result<(StageInfo, String)> {
+        match location {
+            FileLocation::Stage(location) => {
+                let (mut stage_info, params) = self
+                    .ctx
+                    .resolve_stage_and_format(location, format_ref)
+                    .await?;
+                stage_info.file_format_params = params;
+                Ok((stage_info, stage_location_path(location)))
+            }
+            FileLocation::Uri(_) => {
+                let (mut stage_info, path) =
+                    resolve_file_location(self.ctx.as_ref(), location).await?;
+                if !format_ref.options.is_empty() {
+                    stage_info.file_format_params = match format_ref.options.get("format_name") {
+                        Some(name) => self.ctx.get_file_format(name).await?,
+                        None => FileFormatParams::try_from(format_ref.clone())?,
+                    };
+                }
+                Ok((stage_info, path))
+            }
         }
-        stmt.apply_to_copy_option(&mut stage.copy_options)
     }
 
     #[async_backtrace::framed]
@@ -616,11 +641,18 @@ pub async fn resolve_stage_location(
             .await?
     };
 
-    let path = names.get(1).unwrap_or(&"").trim_start_matches('/');
-    let path = if path.is_empty() { "/" } else { path };
+    let path = stage_location_path(location);
 
     debug!("parsed stage: {stage:?}, path: {path}");
-    Ok((stage, path.to_string()))
+    Ok((stage, path))
+}
+
+/// The path of a stage location relative to the stage's root, see [`resolve_stage_location`].
+pub fn stage_location_path(location: &str) -> String {
+    let names: Vec<&str> = location.splitn(2, '/').filter(|v| !v.is_empty()).collect();
+    let path = names.get(1).unwrap_or(&"").trim_start_matches('/');
+    let path = if path.is_empty() { "/" } else { path };
+    path.to_string()
 }
 
 #[async_backtrace::framed]
@@ -36,6 +36,8 @@ use log::debug;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::AlterDatabaseAction as AlterDatabaseActionInPlan;
+use crate::plans::AlterDatabasePlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::Plan;
@@ -73,6 +75,7 @@ impl Binder {
         if *full {
             select_builder.with_column("catalog AS Catalog");
             select_builder.with_column("owner");
+            select_builder.with_column("created_by");
         }
         select_builder.with_column(format!("name AS `databases_in_{ctl}`"));
         select_builder.with_order_by("catalog");
@@ -153,6 +156,24 @@ impl Binder {
                     entities: vec![entry],
                 })))
             }
+            AlterDatabaseAction::SetOptions { set_options } => {
+                Ok(Plan::AlterDatabase(Box::new(AlterDatabasePlan {
+                    if_exists: *if_exists,
+                    tenant,
+                    catalog,
+                    database,
+                    action: AlterDatabaseActionInPlan::SetOptions(set_options.clone()),
+                })))
+            }
+            AlterDatabaseAction::UnsetOptions { unset_options } => {
+                Ok(Plan::AlterDatabase(Box::new(AlterDatabasePlan {
+                    if_exists: *if_exists,
+                    tenant,
+                    catalog,
+                    database,
+                    action: AlterDatabaseActionInPlan::UnsetOptions(unset_options.clone()),
+                })))
+            }
         }
     }
 
@@ -262,6 +283,15 @@ impl Binder {
             engine_options,
             options,
             from_share: from_share.as_ref().map(ShareNameIdentRaw::from),
+            created_on: self.ctx.get_statement_timestamp(),
+            updated_on: self.ctx.get_statement_timestamp(),
+            created_by: Some(
+                self.ctx
+                    .get_current_user()?
+                    .identity()
+                    .display()
+                    .to_string(),
+            ),
             ..Default::default()
         })
     }
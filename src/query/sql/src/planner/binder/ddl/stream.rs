@@ -25,6 +25,7 @@ use log::debug;
 use crate::binder::Binder;
 use crate::normalize_identifier;
 use crate::plans::CreateStreamPlan;
+use crate::plans::DescribeStreamPlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::Plan;
 use crate::plans::RewriteKind;
@@ -122,6 +123,8 @@ impl Binder {
         let ShowStreamsStmt {
             catalog,
             database,
+            table_database,
+            table,
             full,
             limit,
         } = stmt;
@@ -150,6 +153,14 @@ impl Binder {
             .with_order_by("name");
 
         select_builder.with_filter(format!("database = '{database}'"));
+        if let Some(table) = table {
+            let table_database = table_database
+                .as_ref()
+                .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                .unwrap_or_else(|| self.ctx.get_current_database());
+            let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+            select_builder.with_filter(format!("table_name = '{table_database}.{table}'"));
+        }
         if let Some(catalog) = catalog {
             let catalog = normalize_identifier(catalog, &self.name_resolution_ctx).name;
             select_builder.with_filter(format!("catalog = '{catalog}'"));
@@ -179,7 +190,6 @@ impl Binder {
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_describe_stream(
         &mut self,
-        bind_context: &mut BindContext,
         stmt: &DescribeStreamStmt,
     ) -> Result<Plan> {
         let license_manager = get_license_manager();
@@ -193,29 +203,14 @@ impl Binder {
             stream,
         } = stmt;
 
-        let (catalog, database, stream) =
+        let (catalog, database, stream_name) =
             self.normalize_object_identifier_triple(catalog, database, stream);
 
-        let mut select_builder = SelectBuilder::from("system.streams");
-        select_builder
-            .with_column("created_on")
-            .with_column("name")
-            .with_column("database")
-            .with_column("catalog")
-            .with_column("table_name As table_on")
-            .with_column("owner")
-            .with_column("comment")
-            .with_column("mode")
-            .with_column("invalid_reason");
-        select_builder.with_filter(format!("catalog = '{catalog}'"));
-        select_builder.with_filter(format!("database = '{database}'"));
-        select_builder.with_filter(format!("name = '{stream}'"));
-        let query = select_builder.build();
-        self.bind_rewrite_to_query(
-            bind_context,
-            query.as_str(),
-            RewriteKind::ShowStreams(database),
-        )
-        .await
+        let plan = DescribeStreamPlan {
+            catalog,
+            database,
+            stream_name,
+        };
+        Ok(Plan::DescribeStream(plan.into()))
     }
 }
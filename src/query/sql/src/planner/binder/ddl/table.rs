@@ -44,6 +44,7 @@ use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TruncateTableStmt;
+use databend_common_ast::ast::TruncateTablesStmt;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UndropTableStmt;
 use databend_common_ast::ast::UriLocation;
@@ -120,6 +121,7 @@ use crate::plans::RewriteKind;
 use crate::plans::SetOptionsPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
+use crate::plans::TruncateTablesPlan;
 use crate::plans::UndropTablePlan;
 use crate::plans::VacuumDropTableOption;
 use crate::plans::VacuumDropTablePlan;
@@ -486,6 +488,39 @@ impl Binder {
             }
         }
 
+        // `CREATE TABLE ... LIKE` a normal table also inherits the cluster key, and with
+        // `INCLUDING OPTIONS` the table options of the source table.
+        let mut like_source = None;
+        let mut like_cluster_key = None;
+        let mut like_including_options = false;
+        if let (
+            Some(CreateTableSource::Like {
+                catalog,
+                database,
+                table,
+                including_options,
+            }),
+            None,
+        ) = (&source, &as_query)
+        {
+            let (catalog, database, table) =
+                self.normalize_object_identifier_triple(catalog, database, table);
+            let source_table = self.ctx.get_table(&catalog, &database, &table).await?;
+            if source_table.engine() != VIEW_ENGINE {
+                like_cluster_key = source_table
+                    .get_table_info()
+                    .meta
+                    .default_cluster_key
+                    .clone();
+                like_source = Some((catalog, database, table));
+                like_including_options = *including_options;
+            } else if *including_options {
+                return Err(ErrorCode::BadArguments(
+                    "Incorrect CREATE query: INCLUDING OPTIONS is not supported for LIKE a view",
+                ));
+            }
+        }
+
         // Build table schema
         let (schema, field_comments) = match (&source, &as_query) {
             (Some(source), None) => {
@@ -587,8 +622,9 @@ impl Binder {
                 StorageParams::Fs(_)
             );
 
-            // we should persist the storage format and compression type instead of using the default value in fuse table
-            if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
+            // we should persist the storage format and compression type instead of using the default value in fuse table,
+            // unless they are inherited from the source table of `LIKE ... INCLUDING OPTIONS`.
+            if !options.contains_key(OPT_KEY_STORAGE_FORMAT) && !like_including_options {
                 let default_storage_format = match config.query.default_storage_format.as_str() {
                     "" | "auto" => {
                         if is_blocking_fs {
@@ -605,7 +641,7 @@ impl Binder {
                 );
             }
 
-            if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
+            if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) && !like_including_options {
                 let default_compression = match config.query.default_compression.as_str() {
                     "" | "auto" => {
                         if is_blocking_fs {
@@ -627,10 +663,12 @@ impl Binder {
             let keys = self
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
-            if keys.is_empty() {
-                None
-            } else {
+            if !keys.is_empty() {
                 Some(format!("({})", keys.join(", ")))
+            } else if engine == Engine::Fuse {
+                like_cluster_key
+            } else {
+                None
             }
         };
 
@@ -660,6 +698,8 @@ impl Binder {
             } else {
                 None
             },
+            like_source,
+            like_including_options,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -732,6 +772,8 @@ impl Binder {
             field_comments: vec![],
             cluster_key: None,
             as_select: None,
+            like_source: None,
+            like_including_options: false,
         })))
     }
 
@@ -989,7 +1031,7 @@ impl Binder {
                     limit: limit.map(|v| v as usize),
                 })))
             }
-            AlterTableAction::FlashbackTo { point } => {
+            AlterTableAction::FlashbackTo { point, dry_run } => {
                 let point = self.resolve_data_travel_point(bind_context, point).await?;
                 Ok(Plan::RevertTable(Box::new(RevertTablePlan {
                     tenant,
@@ -997,6 +1039,7 @@ impl Binder {
                     database,
                     table,
                     point,
+                    dry_run: *dry_run,
                 })))
             }
             AlterTableAction::SetOptions { set_options } => {
@@ -1071,6 +1114,35 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_truncate_tables(
+        &mut self,
+        stmt: &TruncateTablesStmt,
+    ) -> Result<Plan> {
+        let TruncateTablesStmt { if_exists, tables } = stmt;
+
+        let tables = tables
+            .iter()
+            .map(|stmt| {
+                let (catalog, database, table) = self.normalize_object_identifier_triple(
+                    &stmt.catalog,
+                    &stmt.database,
+                    &stmt.table,
+                );
+                TruncateTablePlan {
+                    catalog,
+                    database,
+                    table,
+                }
+            })
+            .collect();
+
+        Ok(Plan::TruncateTables(Box::new(TruncateTablesPlan {
+            if_exists: *if_exists,
+            tables,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_optimize_table(
         &mut self,
@@ -1399,6 +1471,7 @@ impl Binder {
                 catalog,
                 database,
                 table,
+                ..
             } => {
                 let (catalog, database, table) =
                     self.normalize_object_identifier_triple(catalog, database, table);
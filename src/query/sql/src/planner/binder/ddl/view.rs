@@ -50,6 +50,7 @@ impl Binder {
             view,
             columns,
             query,
+            no_schema_binding,
         } = stmt;
         let mut query = *query.clone();
         let tenant = self.ctx.get_tenant();
@@ -73,6 +74,7 @@ impl Binder {
             view_name,
             column_names,
             subquery,
+            no_schema_binding: *no_schema_binding,
         };
         Ok(Plan::CreateView(plan.into()))
     }
@@ -229,18 +231,24 @@ impl Binder {
 
         let (catalog, database, view_name) =
             self.normalize_object_identifier_triple(catalog, database, view);
-        let schema = DataSchemaRefExt::create(vec![
+        let lineage = self.ctx.get_settings().get_enable_describe_view_lineage()?;
+        let mut fields = vec![
             DataField::new("Field", DataType::String),
             DataField::new("Type", DataType::String),
             DataField::new("Null", DataType::String),
             DataField::new("Default", DataType::String),
             DataField::new("Extra", DataType::String),
-        ]);
+        ];
+        if lineage {
+            fields.push(DataField::new("Source", DataType::String));
+        }
+        let schema = DataSchemaRefExt::create(fields);
 
         Ok(Plan::DescribeView(Box::new(DescribeViewPlan {
             catalog,
             database,
             view_name,
+            lineage,
             schema,
         })))
     }
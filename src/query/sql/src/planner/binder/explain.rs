@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::ExplainOption;
 use databend_common_ast::ast::Statement;
@@ -22,16 +24,45 @@ use crate::plans::Plan;
 use crate::BindContext;
 use crate::Binder;
 
+/// How much detail the EXPLAIN output carries, set with `EXPLAIN(VERBOSITY = '<level>')`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExplainVerbosity {
+    /// Only the operator tree, without stats and other decorations.
+    Terse,
+    #[default]
+    Normal,
+    /// Also show the logical plan the physical plan is built from, with the column ids
+    /// and statistics.
+    Verbose,
+}
+
+impl FromStr for ExplainVerbosity {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "terse" => Ok(ExplainVerbosity::Terse),
+            "normal" => Ok(ExplainVerbosity::Normal),
+            "verbose" => Ok(ExplainVerbosity::Verbose),
+            _ => Err(ErrorCode::SyntaxException(format!(
+                "Unknown VERBOSITY '{s}', expect 'terse', 'normal' or 'verbose'"
+            ))),
+        }
+    }
+}
+
 /// Configuration for the EXPLAIN statement.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct ExplainConfig {
     pub verbose: bool,
+    pub verbosity: ExplainVerbosity,
     pub logical: bool,
     pub optimized: bool,
 }
 
 struct ExplainConfigBuilder {
     verbose: bool,
+    verbosity: ExplainVerbosity,
     logical: bool,
     optimized: bool,
 }
@@ -40,12 +71,13 @@ impl ExplainConfigBuilder {
     pub fn new() -> Self {
         ExplainConfigBuilder {
             verbose: false,
+            verbosity: ExplainVerbosity::Normal,
             logical: false,
             optimized: false,
         }
     }
 
-    pub fn add_option(mut self, option: &ExplainOption) -> Self {
+    pub fn add_option(mut self, option: &ExplainOption) -> Result<Self> {
         match option {
             ExplainOption::Verbose => self.verbose = true,
             ExplainOption::Logical => self.logical = true,
@@ -53,14 +85,16 @@ impl ExplainConfigBuilder {
                 self.logical = true;
                 self.optimized = true;
             }
+            ExplainOption::Verbosity(level) => self.verbosity = level.parse()?,
         }
 
-        self
+        Ok(self)
     }
 
     pub fn build(self) -> ExplainConfig {
         ExplainConfig {
             verbose: self.verbose,
+            verbosity: self.verbosity,
             logical: self.logical,
             optimized: self.optimized,
         }
@@ -79,17 +113,17 @@ impl Binder {
 
         // Rewrite `EXPLAIN RAW` to `EXPLAIN(LOGICAL)`
         if matches!(kind, ExplainKind::Raw) {
-            builder = builder.add_option(&ExplainOption::Logical);
+            builder = builder.add_option(&ExplainOption::Logical)?;
         }
 
         // Rewrite `EXPLAIN OPTIMIZED` to `EXPLAIN(LOGICAL, OPTIMIZED)`
         if matches!(kind, ExplainKind::Optimized) {
-            builder = builder.add_option(&ExplainOption::Logical);
-            builder = builder.add_option(&ExplainOption::Optimized);
+            builder = builder.add_option(&ExplainOption::Logical)?;
+            builder = builder.add_option(&ExplainOption::Optimized)?;
         }
 
         for option in options {
-            builder = builder.add_option(option);
+            builder = builder.add_option(option)?;
         }
 
         let config = builder.build();
@@ -146,5 +180,15 @@ fn validate_explain_config(kind: &ExplainKind, config: &ExplainConfig) -> Result
         ));
     }
 
+    if !matches!(
+        kind,
+        ExplainKind::Plan | ExplainKind::Raw | ExplainKind::Optimized
+    ) && config.verbosity != ExplainVerbosity::Normal
+    {
+        return Err(ErrorCode::SyntaxException(
+            "VERBOSITY option is only supported for EXPLAIN SELECT statement".to_string(),
+        ));
+    }
+
     Ok(())
 }
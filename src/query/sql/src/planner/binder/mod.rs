@@ -65,6 +65,7 @@ pub use column_binding::ColumnBindingBuilder;
 pub use copy_into_table::resolve_file_location;
 pub use copy_into_table::resolve_stage_location;
 pub use explain::ExplainConfig;
+pub use explain::ExplainVerbosity;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::get_storage_params_from_options;
 pub use location::parse_storage_params_from_uri;
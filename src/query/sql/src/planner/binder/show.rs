@@ -15,11 +15,15 @@
 use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowLocksStmt;
 use databend_common_ast::ast::ShowOptions;
+use databend_common_ast::ast::ShowSettingsScope;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_settings::ScopeLevel;
 use log::debug;
 
 use crate::plans::Plan;
 use crate::plans::RewriteKind;
+use crate::plans::ShowSettingsPlan;
 use crate::BindContext;
 use crate::Binder;
 use crate::SelectBuilder;
@@ -77,8 +81,34 @@ impl Binder {
     pub(in crate::planner::binder) async fn bind_show_settings(
         &mut self,
         bind_context: &mut BindContext,
+        scope: &Option<ShowSettingsScope>,
         show_options: &Option<ShowOptions>,
     ) -> Result<Plan> {
+        if let Some(scope) = scope {
+            let level = match scope {
+                ShowSettingsScope::Session => ScopeLevel::Session,
+                ShowSettingsScope::Global => ScopeLevel::Global,
+                ShowSettingsScope::Default => ScopeLevel::Default,
+            };
+            let (like, limit) = match show_options {
+                None => (None, None),
+                Some(ShowOptions { show_limit, limit }) => match show_limit {
+                    None => (None, *limit),
+                    Some(ShowLimit::Like { pattern }) => (Some(pattern.clone()), *limit),
+                    Some(ShowLimit::Where { .. }) => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "SHOW {scope} SETTINGS only supports LIKE filter"
+                        )));
+                    }
+                },
+            };
+            return Ok(Plan::ShowSettings(Box::new(ShowSettingsPlan {
+                level,
+                like,
+                limit,
+            })));
+        }
+
         let (show_limit, limit_str) = get_show_options(show_options, None);
         let query = format!(
             "SELECT name, value, default, `range`, level, description, type FROM system.settings {} ORDER BY name {}",
@@ -131,7 +161,7 @@ impl Binder {
         let (show_limit, limit_str) =
             get_show_options(show_options, Some("\"Engine\"".to_string()));
         let query = format!(
-            "SELECT \"Engine\", \"Comment\" FROM system.engines {} ORDER BY \"Engine\" ASC {}",
+            "SELECT \"Engine\", \"Comment\", \"Supported_options\" FROM system.engines {} ORDER BY \"Engine\" ASC {}",
             show_limit, limit_str,
         );
 
@@ -399,6 +399,7 @@ impl Binder {
                         arg_types: udf.arg_types.clone(),
                         return_type: udf.return_type.clone(),
                         arguments: new_args,
+                        on_error: udf.on_error,
                     }
                     .into())
                 }
@@ -67,6 +67,7 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::FileFormatOptionsAst;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageInfo;
@@ -89,7 +90,6 @@ use databend_storages_common_table_meta::table::ChangeType;
 use log::info;
 use parking_lot::RwLock;
 
-use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::table_args::bind_table_args;
 use crate::binder::Binder;
@@ -800,13 +800,16 @@ impl Binder {
             }),
             _ => location.clone(),
         };
-        let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), &location).await?;
+        let mut format_ref = BTreeMap::new();
         if let Some(f) = &options.file_format {
-            stage_info.file_format_params = match StageFileFormatType::from_str(f) {
-                Ok(t) => FileFormatParams::default_by_type(t)?,
-                _ => self.ctx.get_file_format(f).await?,
-            }
+            match StageFileFormatType::from_str(f) {
+                Ok(_) => format_ref.insert("type".to_string(), f.clone()),
+                _ => format_ref.insert("format_name".to_string(), f.clone()),
+            };
         }
+        let (stage_info, path) = self
+            .resolve_file_location_and_format(&location, &FileFormatOptionsAst::new(format_ref))
+            .await?;
         let files_info = StageFilesInfo {
             path,
             pattern: options.pattern.clone(),
@@ -26,6 +26,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::udf_client::UDFFlightClient;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition as PlanUDFDefinition;
+use databend_common_meta_app::principal::UDFOnError;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
 use databend_common_meta_app::principal::UserDefinedFunction;
@@ -139,12 +140,13 @@ impl Binder {
                 handler,
                 language,
                 runtime_version,
+                on_error,
             } => {
                 let mut arg_datatypes = Vec::with_capacity(arg_types.len());
                 for arg_type in arg_types {
                     arg_datatypes.push(DataType::from(&resolve_type_name(arg_type, true)?));
                 }
-                let return_type = DataType::from(&resolve_type_name(return_type, true)?);
+                let mut return_type = DataType::from(&resolve_type_name(return_type, true)?);
 
                 if !Self::is_allowed_language(language) {
                     return Err(ErrorCode::InvalidArgument(format!(
@@ -152,6 +154,15 @@ impl Binder {
                     )));
                 }
 
+                let on_error = match on_error {
+                    Some(on_error) => on_error.parse::<UDFOnError>()?,
+                    None => UDFOnError::Abort,
+                };
+                // Failed rows are returned as NULL, so the result must be nullable.
+                if on_error == UDFOnError::Null {
+                    return_type = return_type.wrap_nullable();
+                }
+
                 let mut runtime_version = runtime_version.to_string();
                 if runtime_version.is_empty() && language.to_lowercase() == "python" {
                     runtime_version = "3.12.2".to_string();
@@ -167,6 +178,7 @@ impl Binder {
                         handler: handler.clone(),
                         language: language.clone(),
                         runtime_version,
+                        on_error,
                     }),
                     created_on: Utc::now(),
                 })
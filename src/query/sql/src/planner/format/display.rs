@@ -25,6 +25,7 @@ use crate::plans::RelOperator;
 use crate::ColumnEntry;
 use crate::IndexType;
 use crate::Metadata;
+use crate::DUMMY_COLUMN_INDEX;
 
 /// A trait for humanizing IDs.
 pub trait IdHumanizer {
@@ -179,9 +180,11 @@ impl<'a, I: IdHumanizer<ColumnId = IndexType>, O: OperatorHumanizer<I, Output =
     }
 
     fn humanize_property(&self, prop: &RelationalProperty) -> Vec<FormatTreeNode> {
+        // The dummy column of `DummyTableScan` has no entry in the metadata.
         let output_columns = prop
             .output_columns
             .iter()
+            .filter(|idx| **idx != DUMMY_COLUMN_INDEX)
             .map(|idx| self.id_humanizer.humanize_column_id(*idx))
             .sorted()
             .collect::<Vec<_>>();
@@ -70,6 +70,7 @@ impl Plan {
             Plan::DropDatabase(_) => Ok("DropDatabase".to_string()),
             Plan::UndropDatabase(_) => Ok("UndropDatabase".to_string()),
             Plan::RenameDatabase(_) => Ok("RenameDatabase".to_string()),
+            Plan::AlterDatabase(_) => Ok("AlterDatabase".to_string()),
 
             // Tables
             Plan::CreateTable(create_table) => format_create_table(create_table),
@@ -88,6 +89,7 @@ impl Plan {
             Plan::DropTableClusterKey(_) => Ok("DropTableClusterKey".to_string()),
             Plan::ReclusterTable(_) => Ok("ReclusterTable".to_string()),
             Plan::TruncateTable(_) => Ok("TruncateTable".to_string()),
+            Plan::TruncateTables(_) => Ok("TruncateTables".to_string()),
             Plan::OptimizeTable(_) => Ok("OptimizeTable".to_string()),
             Plan::VacuumTable(_) => Ok("VacuumTable".to_string()),
             Plan::VacuumDropTable(_) => Ok("VacuumDropTable".to_string()),
@@ -104,6 +106,7 @@ impl Plan {
             // Streams
             Plan::CreateStream(_) => Ok("CreateStream".to_string()),
             Plan::DropStream(_) => Ok("DropStream".to_string()),
+            Plan::DescribeStream(_) => Ok("DescribeStream".to_string()),
 
             // Dynamic Tables
             Plan::CreateDynamicTable(_) => Ok("CreateDynamicTable".to_string()),
@@ -158,6 +161,7 @@ impl Plan {
 
             Plan::SetVariable(_) => Ok("SetVariable".to_string()),
             Plan::UnSetVariable(_) => Ok("UnSetVariable".to_string()),
+            Plan::ShowSettings(_) => Ok("ShowSettings".to_string()),
             Plan::SetRole(_) => Ok("SetRole".to_string()),
             Plan::SetSecondaryRoles(_) => Ok("SetSecondaryRoles".to_string()),
             Plan::UseDatabase(_) => Ok("UseDatabase".to_string()),
@@ -380,6 +380,7 @@ impl SubqueryRewriter {
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments: args,
+                    on_error: udf.on_error,
                 }
                 .into();
 
@@ -1141,6 +1141,7 @@ fn rewrite_query_item(
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments: new_args,
+                    on_error: udf.on_error,
                 }
                 .into(),
             )
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -30,6 +31,7 @@ use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_settings::Settings;
 use derive_visitor::DriveMut;
 use log::info;
 use log::warn;
@@ -37,6 +39,7 @@ use parking_lot::RwLock;
 
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
+use super::semantic::InListRewriter;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::plans::Insert;
@@ -59,6 +62,9 @@ pub struct PlanExtras {
     pub metadata: MetadataRef,
     pub format: Option<String>,
     pub statement: Statement,
+    /// The `(catalog, database, table)` of every table the statement reads,
+    /// including the ones referenced in subqueries and CTEs.
+    pub referenced_tables: Vec<(String, String, String)>,
 }
 
 impl Planner {
@@ -71,10 +77,12 @@ impl Planner {
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
         let start = Instant::now();
         let settings = self.ctx.get_settings();
+        apply_statement_hints(sql, &settings)?;
         let sql_dialect = settings.get_sql_dialect()?;
+        let enable_prql = settings.get_enable_prql()?;
         // compile prql to sql for prql dialect
         let mut prql_converted = false;
-        let final_sql: String = match sql_dialect == Dialect::PRQL {
+        let final_sql: String = match sql_dialect == Dialect::PRQL && enable_prql {
             true => {
                 let options = prqlc::Options::default();
                 match prqlc::compile(sql, &options) {
@@ -133,13 +141,23 @@ impl Planner {
         loop {
             let res = async {
                 // Step 2: Parse the SQL.
-                let (mut stmt, format) = if is_insert_stmt {
-                    (parse_raw_insert_stmt(&tokens, sql_dialect)?, None)
+                let parsed = if is_insert_stmt {
+                    parse_raw_insert_stmt(&tokens, sql_dialect).map(|stmt| (stmt, None))
                 } else if is_replace_stmt {
-                    (parse_raw_replace_stmt(&tokens, sql_dialect)?, None)
+                    parse_raw_replace_stmt(&tokens, sql_dialect).map(|stmt| (stmt, None))
                 } else {
-                    parse_sql(&tokens, sql_dialect)?
+                    parse_sql(&tokens, sql_dialect)
                 };
+                // `SET` is always allowed, so that the dialect can be switched back.
+                if sql_dialect == Dialect::PRQL
+                    && !enable_prql
+                    && !matches!(parsed, Ok((Statement::SetVariable { .. }, _)))
+                {
+                    return Err(ErrorCode::BadArguments(
+                        "PRQL dialect is disabled, set enable_prql = 1 to use it or set sql_dialect to another dialect",
+                    ));
+                }
+                let (mut stmt, format) = parsed?;
                 if !matches!(stmt, Statement::SetVariable { .. })
                     && sql_dialect == Dialect::PRQL
                     && !prql_converted
@@ -147,7 +165,7 @@ impl Planner {
                     return Err(ErrorCode::SyntaxException("convert prql to sql failed."));
                 }
 
-                self.replace_stmt(&mut stmt);
+                self.replace_stmt(&mut stmt)?;
 
                 // Step 3: Bind AST with catalog, and generate a pure logical SExpr
                 let metadata = Arc::new(RwLock::new(Metadata::default()));
@@ -167,6 +185,18 @@ impl Planner {
                 self.ctx
                     .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
 
+                // `EXPLAIN AST` and `EXPLAIN SYNTAX` only need the parsed statement,
+                // there is nothing to optimize.
+                if matches!(plan, Plan::ExplainAst { .. } | Plan::ExplainSyntax { .. }) {
+                    let referenced_tables = referenced_tables(&metadata);
+                    return Ok((plan, PlanExtras {
+                        metadata,
+                        format,
+                        statement: stmt,
+                        referenced_tables,
+                    }));
+                }
+
                 // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
                 let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
                     .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
@@ -177,10 +207,12 @@ impl Planner {
                     );
 
                 let optimized_plan = optimize(opt_ctx, plan).await?;
+                let referenced_tables = referenced_tables(&metadata);
                 Ok((optimized_plan, PlanExtras {
                     metadata,
                     format,
                     statement: stmt,
+                    referenced_tables,
                 }))
             }
             .await;
@@ -242,12 +274,100 @@ impl Planner {
         }
     }
 
-    fn replace_stmt(&self, stmt: &mut Statement) {
+    fn replace_stmt(&self, stmt: &mut Statement) -> Result<()> {
+        let inlist_threshold = self.ctx.get_settings().get_inlist_to_join_threshold()?;
+        stmt.drive_mut(&mut InListRewriter::new(inlist_threshold));
         stmt.drive_mut(&mut DistinctToGroupBy::default());
         stmt.drive_mut(&mut AggregateRewriter);
 
         self.add_max_rows_limit(stmt);
+        Ok(())
+    }
+}
+
+/// Apply the hints in the leading comment of the statement to the settings of this query,
+/// e.g. `/*+ max_execution_time(5000) */ SELECT ...` limits the execution time to 5 seconds.
+///
+/// The hints are only applied if `enable_statement_hints` is set. They are mapped to the
+/// settings they stand for and applied like the `SET_VAR` hints, so a `SET_VAR` hint of the
+/// statement takes precedence. Unknown or malformed hints are ignored.
+fn apply_statement_hints(sql: &str, settings: &Settings) -> Result<()> {
+    if !settings.get_enable_statement_hints()? {
+        return Ok(());
+    }
+
+    let Some(hints) = sql
+        .trim_start()
+        .strip_prefix("/*+")
+        .and_then(|s| s.split_once("*/"))
+        .map(|(hints, _)| hints)
+    else {
+        return Ok(());
+    };
+
+    let tokens = match Tokenizer::new(hints)
+        .filter(|token| !matches!(token, Ok(token) if token.kind == TokenKind::EOI))
+        .collect::<Result<Vec<_>>>()
+    {
+        Ok(tokens) => tokens,
+        Err(_) => {
+            warn!("ignore malformed hints {}", hints.trim());
+            return Ok(());
+        }
+    };
+
+    let mut hint_settings = HashMap::new();
+    let mut tokens = tokens.as_slice();
+    while let Some((token, rest)) = tokens.split_first() {
+        match tokens {
+            [name, lparen, value, rparen, rest @ ..]
+                if name.kind == TokenKind::Ident
+                    && lparen.kind == TokenKind::LParen
+                    && rparen.kind == TokenKind::RParen =>
+            {
+                match name.text().to_lowercase().as_str() {
+                    "max_execution_time" => match value.text().parse::<u64>() {
+                        // The hint is in milliseconds, round it up to the seconds of the setting.
+                        Ok(millis) => {
+                            hint_settings.insert(
+                                "max_execute_time_in_seconds".to_string(),
+                                millis.div_ceil(1000).to_string(),
+                            );
+                        }
+                        Err(_) => warn!("ignore invalid hint max_execution_time({})", value.text()),
+                    },
+                    name => info!("ignore unknown hint {}", name),
+                }
+                tokens = rest;
+            }
+            _ => {
+                info!("ignore malformed hint at {}", token.text());
+                tokens = rest;
+            }
+        }
+    }
+
+    settings.set_batch_settings(&hint_settings)
+}
+
+/// Collect the tables bound into the metadata, skipping the stage tables
+/// which are not stored in any catalog.
+fn referenced_tables(metadata: &MetadataRef) -> Vec<(String, String, String)> {
+    let mut tables = Vec::new();
+    for table in metadata.read().tables() {
+        if table.table().is_stage_table() {
+            continue;
+        }
+        let table = (
+            table.catalog().to_string(),
+            table.database().to_string(),
+            table.name().to_string(),
+        );
+        if !tables.contains(&table) {
+            tables.push(table);
+        }
     }
+    tables
 }
 
 pub fn get_query_kind(stmt: &Statement) -> QueryKind {
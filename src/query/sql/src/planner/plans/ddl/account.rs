@@ -117,6 +117,7 @@ impl ShowRolesPlan {
             DataField::new("inherited_roles", DataType::Number(NumberDataType::UInt64)),
             DataField::new("is_current", DataType::Boolean),
             DataField::new("is_default", DataType::Boolean),
+            DataField::new("owned_objects", DataType::Number(NumberDataType::UInt64)),
         ])
     }
 }
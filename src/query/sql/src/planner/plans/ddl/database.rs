@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use databend_common_expression::DataSchemaRef;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::CreateDatabaseReq;
@@ -93,6 +95,25 @@ pub struct RenameDatabaseEntity {
     pub new_database: String,
 }
 
+// AlterDatabaseAction after name resolved, used in AlterDatabasePlan
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlterDatabaseAction {
+    // option name -> option value
+    SetOptions(BTreeMap<String, String>),
+    // option names
+    UnsetOptions(Vec<String>),
+}
+
+/// Alter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterDatabasePlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub action: AlterDatabaseAction,
+}
+
 /// Undrop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UndropDatabasePlan {
@@ -28,6 +28,15 @@ pub struct CreateFileFormatPlan {
     pub file_format_params: FileFormatParams,
 }
 
+impl CreateFileFormatPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("status", DataType::String),
+            DataField::new("changes", DataType::String),
+        ])
+    }
+}
+
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropFileFormatPlan {
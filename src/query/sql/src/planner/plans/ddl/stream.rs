@@ -12,7 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_catalog::table::NavigationPoint;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::tenant::Tenant;
 
@@ -38,3 +45,28 @@ pub struct DropStreamPlan {
     pub database: String,
     pub stream_name: String,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescribeStreamPlan {
+    pub catalog: String,
+    pub database: String,
+    pub stream_name: String,
+}
+
+impl DescribeStreamPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::new(vec![
+            DataField::new("created_on", DataType::Timestamp),
+            DataField::new("name", DataType::String),
+            DataField::new("database", DataType::String),
+            DataField::new("catalog", DataType::String),
+            DataField::new("table_on", DataType::String),
+            DataField::new("owner", DataType::String.wrap_nullable()),
+            DataField::new("comment", DataType::String),
+            DataField::new("mode", DataType::String),
+            DataField::new("invalid_reason", DataType::String),
+            DataField::new("offset", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("snapshot_location", DataType::String.wrap_nullable()),
+        ]))
+    }
+}
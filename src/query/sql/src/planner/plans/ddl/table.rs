@@ -55,6 +55,11 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    /// The (catalog, database, table) of `CREATE TABLE ... LIKE`, the schema,
+    /// field comments and cluster key are already resolved from it by the binder.
+    pub like_source: Option<(String, String, String)>,
+    /// Also take the table options of `like_source`, `LIKE ... INCLUDING OPTIONS`.
+    pub like_including_options: bool,
 }
 
 impl CreateTablePlan {
@@ -402,6 +407,24 @@ impl TruncateTablePlan {
     }
 }
 
+/// Truncate multiple tables, one by one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TruncateTablesPlan {
+    /// If true, keep truncating the remaining tables when one of them fails,
+    /// otherwise stop at the first failure.
+    pub if_exists: bool,
+    pub tables: Vec<TruncateTablePlan>,
+}
+
+impl TruncateTablesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("table", DataType::String),
+            DataField::new("error", DataType::String.wrap_nullable()),
+        ])
+    }
+}
+
 /// Undrop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UndropTablePlan {
@@ -25,6 +25,7 @@ pub struct CreateViewPlan {
     pub view_name: String,
     pub column_names: Vec<String>,
     pub subquery: String,
+    pub no_schema_binding: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -51,6 +52,8 @@ pub struct DescribeViewPlan {
     pub catalog: String,
     pub database: String,
     pub view_name: String,
+    /// Show the source column of each view column.
+    pub lineage: bool,
     pub schema: DataSchemaRef,
 }
 
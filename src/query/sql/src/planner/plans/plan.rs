@@ -28,6 +28,7 @@ use crate::binder::ExplainConfig;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AlterDatabasePlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
@@ -71,6 +72,7 @@ use crate::plans::DescNetworkPolicyPlan;
 use crate::plans::DescNotificationPlan;
 use crate::plans::DescPasswordPolicyPlan;
 use crate::plans::DescSharePlan;
+use crate::plans::DescribeStreamPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
 use crate::plans::DescribeViewPlan;
@@ -139,10 +141,12 @@ use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowNetworkPoliciesPlan;
 use crate::plans::ShowObjectGrantPrivilegesPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowSettingsPlan;
 use crate::plans::ShowShareEndpointPlan;
 use crate::plans::ShowSharesPlan;
 use crate::plans::ShowTasksPlan;
 use crate::plans::TruncateTablePlan;
+use crate::plans::TruncateTablesPlan;
 use crate::plans::UnSettingPlan;
 use crate::plans::UndropDatabasePlan;
 use crate::plans::UndropTablePlan;
@@ -196,6 +200,7 @@ pub enum Plan {
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
+    AlterDatabase(Box<AlterDatabasePlan>),
     UseDatabase(Box<UseDatabasePlan>),
 
     // Tables
@@ -215,6 +220,7 @@ pub enum Plan {
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
+    TruncateTables(Box<TruncateTablesPlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
     VacuumTable(Box<VacuumTablePlan>),
     VacuumDropTable(Box<VacuumDropTablePlan>),
@@ -243,6 +249,7 @@ pub enum Plan {
     // Streams
     CreateStream(Box<CreateStreamPlan>),
     DropStream(Box<DropStreamPlan>),
+    DescribeStream(Box<DescribeStreamPlan>),
 
     // Indexes
     CreateIndex(Box<CreateIndexPlan>),
@@ -302,6 +309,7 @@ pub enum Plan {
     // Set
     SetVariable(Box<SettingPlan>),
     UnSetVariable(Box<UnSettingPlan>),
+    ShowSettings(Box<ShowSettingsPlan>),
     Kill(Box<KillPlan>),
 
     // Share
@@ -419,6 +427,39 @@ impl Plan {
             _ => QueryKind::Other,
         }
     }
+
+    /// Returns true if the plan doesn't modify anything, so that it can be served
+    /// by a read-only replica. Most `SHOW` statements are planned as queries.
+    pub fn is_read_only(&self) -> bool {
+        self.kind().is_read_only()
+            || matches!(
+                self,
+                Plan::ShowCreateCatalog(_)
+                    | Plan::ShowCreateDatabase(_)
+                    | Plan::ShowCreateTable(_)
+                    | Plan::DescribeTable(_)
+                    | Plan::DescribeView(_)
+                    | Plan::DescribeStream(_)
+                    | Plan::ShowRoles(_)
+                    | Plan::ShowGrants(_)
+                    | Plan::ShowFileFormats(_)
+                    | Plan::DescConnection(_)
+                    | Plan::ShowConnections(_)
+                    | Plan::ShowSettings(_)
+                    | Plan::ShowShareEndpoint(_)
+                    | Plan::DescShare(_)
+                    | Plan::ShowShares(_)
+                    | Plan::ShowObjectGrantPrivileges(_)
+                    | Plan::ShowGrantTenantsOfShare(_)
+                    | Plan::DescDatamaskPolicy(_)
+                    | Plan::DescNetworkPolicy(_)
+                    | Plan::ShowNetworkPolicies(_)
+                    | Plan::DescPasswordPolicy(_)
+                    | Plan::DescribeTask(_)
+                    | Plan::ShowTasks(_)
+                    | Plan::DescNotification(_)
+            )
+    }
 }
 
 impl Display for Plan {
@@ -451,8 +492,10 @@ impl Plan {
             Plan::VacuumTemporaryFiles(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
             Plan::DescribeView(plan) => plan.schema(),
+            Plan::DescribeStream(plan) => plan.schema(),
             Plan::ShowRoles(plan) => plan.schema(),
             Plan::ShowGrants(plan) => plan.schema(),
+            Plan::CreateFileFormat(plan) => plan.schema(),
             Plan::ShowFileFormats(plan) => plan.schema(),
             Plan::Replace(plan) => plan.schema(),
             Plan::Presign(plan) => plan.schema(),
@@ -478,6 +521,9 @@ impl Plan {
             Plan::ShowConnections(plan) => plan.schema(),
             Plan::ExecuteImmediate(plan) => plan.schema(),
             Plan::InsertMultiTable(plan) => plan.schema(),
+            Plan::RevertTable(plan) => plan.schema(),
+            Plan::TruncateTables(plan) => plan.schema(),
+            Plan::ShowSettings(plan) => plan.schema(),
 
             _ => Arc::new(DataSchema::empty()),
         }
@@ -12,7 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_catalog::table::NavigationPoint;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
 use databend_common_meta_app::tenant::Tenant;
 
 #[derive(Clone, Debug)]
@@ -22,4 +29,26 @@ pub struct RevertTablePlan {
     pub database: String,
     pub table: String,
     pub point: NavigationPoint,
+    pub dry_run: bool,
+}
+
+impl RevertTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        if self.dry_run {
+            Arc::new(DataSchema::new(vec![
+                DataField::new(
+                    "current_snapshot_id",
+                    DataType::Nullable(Box::new(DataType::String)),
+                ),
+                DataField::new(
+                    "target_snapshot_id",
+                    DataType::Nullable(Box::new(DataType::String)),
+                ),
+                DataField::new("rows_added", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("rows_removed", DataType::Number(NumberDataType::UInt64)),
+            ]))
+        } else {
+            Arc::new(DataSchema::empty())
+        }
+    }
 }
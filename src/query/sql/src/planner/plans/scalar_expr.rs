@@ -24,6 +24,7 @@ use databend_common_exception::Span;
 use databend_common_expression::types::DataType;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
+use databend_common_meta_app::principal::UDFOnError;
 use educe::Educe;
 use enum_as_inner::EnumAsInner;
 use itertools::Itertools;
@@ -643,8 +644,12 @@ pub struct UDFCall {
     pub return_type: Box<DataType>,
     pub arguments: Vec<ScalarExpr>,
     pub udf_type: UDFType,
+    pub on_error: UDFOnError,
 }
 
+/// Prefix of a WASM module embedded in the function body as base64, instead of a location.
+pub const WASM_INLINE_BASE64_PREFIX: &str = "wasm://base64,";
+
 #[derive(Clone, Debug, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize, EnumAsInner)]
 pub enum UDFType {
     Server(String),                        // server_addr
@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_settings::ScopeLevel;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VarValue {
     pub is_global: bool,
@@ -28,3 +34,26 @@ pub struct SettingPlan {
 pub struct UnSettingPlan {
     pub vars: Vec<String>,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowSettingsPlan {
+    /// Only the settings whose current value comes from this level are listed.
+    pub level: ScopeLevel,
+    /// `LIKE` pattern on the setting name.
+    pub like: Option<String>,
+    pub limit: Option<u64>,
+}
+
+impl ShowSettingsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("value", DataType::String),
+            DataField::new("default", DataType::String),
+            DataField::new("range", DataType::String),
+            DataField::new("level", DataType::String),
+            DataField::new("description", DataType::String),
+            DataField::new("type", DataType::String),
+        ])
+    }
+}
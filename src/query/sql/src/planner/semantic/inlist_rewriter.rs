@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SetExpr;
+use derive_visitor::VisitorMut;
+
+/// Rewrite `<expr> [NOT] IN (<literal>, ...)` with at least `threshold` items
+/// into `<expr> [NOT] IN (VALUES (<literal>), ...)` before binding.
+///
+/// The subquery is bound as a constant table and joined, instead of checking
+/// the items one by one. `NOT IN` is resolved as `NOT (<expr> = ANY (...))`,
+/// which keeps the NULL semantics of the original list.
+#[derive(Debug, Clone, VisitorMut)]
+#[visitor(Expr(exit))]
+pub struct InListRewriter {
+    threshold: usize,
+}
+
+impl InListRewriter {
+    pub fn new(threshold: usize) -> Self {
+        Self { threshold }
+    }
+
+    fn exit_expr(&mut self, expr: &mut Expr) {
+        let Expr::InList {
+            span,
+            expr: left,
+            list,
+            not,
+        } = expr
+        else {
+            return;
+        };
+
+        if list.len() < self.threshold || !list.iter().all(|e| matches!(e, Expr::Literal { .. })) {
+            return;
+        }
+
+        let values = std::mem::take(list).into_iter().map(|e| vec![e]).collect();
+        let subquery = Query {
+            span: *span,
+            with: None,
+            body: SetExpr::Values {
+                span: *span,
+                values,
+            },
+            order_by: vec![],
+            limit: vec![],
+            offset: None,
+            ignore_result: false,
+        };
+        *expr = Expr::InSubquery {
+            span: *span,
+            expr: left.clone(),
+            subquery: Box::new(subquery),
+            not: *not,
+        };
+    }
+}
@@ -17,6 +17,7 @@ mod aggregating_index_visitor;
 mod async_function_rewriter;
 mod distinct_to_groupby;
 mod grouping_check;
+mod inlist_rewriter;
 mod lowering;
 mod name_resolution;
 mod type_check;
@@ -32,6 +33,7 @@ pub use aggregating_index_visitor::RefreshAggregatingIndexRewriter;
 pub(crate) use async_function_rewriter::AsyncFunctionRewriter;
 pub use distinct_to_groupby::DistinctToGroupBy;
 pub use grouping_check::GroupingChecker;
+pub use inlist_rewriter::InListRewriter;
 pub use lowering::*;
 pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
@@ -92,6 +92,7 @@ use databend_common_functions::GENERAL_SEARCH_FUNCTIONS;
 use databend_common_functions::GENERAL_WINDOW_FUNCTIONS;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UDFOnError;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
 use databend_common_storages_stage::StageTable;
@@ -144,6 +145,7 @@ use crate::plans::WindowFuncFrameBound;
 use crate::plans::WindowFuncFrameUnits;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
+use crate::plans::WASM_INLINE_BASE64_PREFIX;
 use crate::BaseTableColumn;
 use crate::BindContext;
 use crate::ColumnBinding;
@@ -3513,6 +3515,7 @@ impl<'a> TypeChecker<'a> {
                 arg_types: udf_definition.arg_types,
                 return_type: Box::new(udf_definition.return_type.clone()),
                 arguments: args,
+                on_error: UDFOnError::Abort,
             }
             .into(),
             udf_definition.return_type.clone(),
@@ -3520,6 +3523,15 @@ impl<'a> TypeChecker<'a> {
     }
 
     async fn resolve_wasm_file_location(&mut self, udf_definition: &UDFScript) -> Result<UDFType> {
+        // The inline module is decoded when the runtime is created, no storage access is needed.
+        if udf_definition.code.starts_with(WASM_INLINE_BASE64_PREFIX) {
+            return Ok(UDFType::WasmScript((
+                udf_definition.language.clone(),
+                udf_definition.runtime_version.clone(),
+                udf_definition.code.clone().into_bytes(),
+            )));
+        }
+
         let file_location = match udf_definition.code.strip_prefix('@') {
             Some(location) => FileLocation::Stage(location.to_string()),
             None => {
@@ -3622,6 +3634,7 @@ impl<'a> TypeChecker<'a> {
             }
         }
 
+        let on_error = udf_definition.on_error;
         let const_udf_type = if udf_definition.language.to_lowercase().as_str() == "wasm" {
             self.resolve_wasm_file_location(&udf_definition).await?
         } else {
@@ -3646,6 +3659,7 @@ impl<'a> TypeChecker<'a> {
                 return_type: Box::new(udf_definition.return_type.clone()),
                 udf_type: const_udf_type,
                 arguments: args,
+                on_error,
             }
             .into(),
             udf_definition.return_type.clone(),
@@ -107,6 +107,7 @@ impl DeltaTable {
             engine_name: DELTA_ENGINE.to_string(),
             comment: "DELTA Storage Engine".to_string(),
             support_cluster_key: false,
+            ..Default::default()
         }
     }
 
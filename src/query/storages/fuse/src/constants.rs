@@ -12,11 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+
 pub const FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD: &str = "block_size_threshold";
 pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+pub const FUSE_OPT_KEY_BLOOM_INDEX_MIN_BLOCK_ROWS: &str = "bloom_index_min_block_rows";
+/// Also accepted as a database option, as the default of the tables in the database.
+pub const FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS: &str = "data_retention_time_in_days";
+
+/// Table option keys the FUSE engine accepts in 'create table statement'.
+pub const FUSE_TABLE_OPTIONS: &[&str] = &[
+    FUSE_OPT_KEY_ROW_PER_PAGE,
+    FUSE_OPT_KEY_BLOCK_PER_SEGMENT,
+    FUSE_OPT_KEY_ROW_PER_BLOCK,
+    FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD,
+    FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
+    FUSE_OPT_KEY_BLOOM_INDEX_MIN_BLOCK_ROWS,
+    FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS,
+    OPT_KEY_BLOOM_INDEX_COLUMNS,
+    OPT_KEY_TABLE_COMPRESSION,
+    OPT_KEY_STORAGE_FORMAT,
+    OPT_KEY_COMMENT,
+    OPT_KEY_CHANGE_TRACKING,
+];
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
@@ -33,5 +58,7 @@ pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_PAGE: usize = 131072;
 pub const DEFAULT_ROW_PER_PAGE_FOR_BLOCKING: usize = 2048;
 pub const DEFAULT_ROW_PER_INDEX: usize = 100000;
+// blocks with fewer rows than this do not get a bloom index.
+pub const DEFAULT_BLOOM_INDEX_MIN_BLOCK_ROWS: usize = 1000;
 
 pub const DEFAULT_AVG_DEPTH_THRESHOLD: f64 = 0.001;
@@ -18,6 +18,7 @@ use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use chrono::Duration;
 use databend_common_catalog::catalog::StorageDescription;
 use databend_common_catalog::lock::Lock;
 use databend_common_catalog::plan::DataSourcePlan;
@@ -95,12 +96,16 @@ use crate::NavigationPoint;
 use crate::Table;
 use crate::TableStatistics;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
+use crate::DEFAULT_BLOOM_INDEX_MIN_BLOCK_ROWS;
 use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_BLOOM_INDEX_MIN_BLOCK_ROWS;
+use crate::FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
+use crate::FUSE_TABLE_OPTIONS;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 
 #[derive(Clone)]
@@ -242,6 +247,7 @@ impl FuseTable {
             engine_name: "FUSE".to_string(),
             comment: "FUSE Storage Engine".to_string(),
             support_cluster_key: true,
+            supported_options: FUSE_TABLE_OPTIONS.iter().map(|s| s.to_string()).collect(),
         }
     }
 
@@ -262,12 +268,17 @@ impl FuseTable {
         let max_page_size = self.get_option(FUSE_OPT_KEY_ROW_PER_PAGE, default_rows_per_page);
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        let bloom_index_min_block_rows = self.get_option(
+            FUSE_OPT_KEY_BLOOM_INDEX_MIN_BLOCK_ROWS,
+            DEFAULT_BLOOM_INDEX_MIN_BLOCK_ROWS,
+        );
 
         WriteSettings {
             storage_format: self.storage_format,
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            bloom_index_min_block_rows,
         }
     }
 
@@ -301,6 +312,43 @@ impl FuseTable {
         Ok(table_storage_prefix(db_id, table_id))
     }
 
+    /// Get the data retention of this table.
+    ///
+    /// The `data_retention_time_in_days` option of the table takes precedence over the
+    /// option of the same name of its database, which takes precedence over the
+    /// `data_retention_time_in_days` setting.
+    #[async_backtrace::framed]
+    pub async fn effective_retention(&self, ctx: &dyn TableContext) -> Result<Duration> {
+        let table_option = self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS);
+        if let Some(value) = table_option {
+            return parse_data_retention(value);
+        }
+
+        let database_options = self.database_options(ctx).await?;
+        if let Some(value) = database_options.get(FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS) {
+            return parse_data_retention(value);
+        }
+
+        let days = ctx.get_settings().get_data_retention_time_in_days()?;
+        Ok(Duration::days(days as i64))
+    }
+
+    #[async_backtrace::framed]
+    async fn database_options(&self, ctx: &dyn TableContext) -> Result<BTreeMap<String, String>> {
+        // Attached tables do not record the database they belong to.
+        let Some(db_id) = self.table_info.options().get(OPT_KEY_DATABASE_ID) else {
+            return Ok(BTreeMap::new());
+        };
+        let db_id = db_id.parse::<u64>()?;
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let db_name = catalog.get_db_name_by_id(db_id).await?;
+        let db = catalog.get_database(&ctx.get_tenant(), &db_name).await?;
+        Ok(db.options().clone())
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn read_table_snapshot_statistics(
@@ -914,3 +962,13 @@ impl Table for FuseTable {
         self.table_type.is_readonly()
     }
 }
+
+fn parse_data_retention(value: &str) -> Result<Duration> {
+    let days = value.parse::<u64>().map_err(|_| {
+        ErrorCode::TableOptionInvalid(format!(
+            "invalid {} option: {}",
+            FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS, value
+        ))
+    })?;
+    Ok(Duration::days(days as i64))
+}
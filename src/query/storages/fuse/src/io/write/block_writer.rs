@@ -32,6 +32,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_metrics::storage::metrics_inc_block_index_write_skipped_nums;
 use databend_common_metrics::storage::metrics_inc_block_inverted_index_generate_milliseconds;
 use databend_storages_common_blocks::blocks_to_parquet;
 use databend_storages_common_index::BloomIndex;
@@ -247,6 +248,8 @@ pub struct BlockSerialization {
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
     pub inverted_index_states: Vec<InvertedIndexState>,
+    // serialized size of each column in the block file
+    pub column_sizes: HashMap<ColumnId, u64>,
 }
 
 #[derive(Clone)]
@@ -267,13 +270,24 @@ impl BlockBuilder {
         let (cluster_stats, data_block) = f(data_block, &self.cluster_stats_gen)?;
         let (block_location, block_id) = self.meta_locations.gen_block_location();
 
-        let bloom_index_location = self.meta_locations.block_bloom_index_location(&block_id);
-        let bloom_index_state = BloomIndexState::try_create(
-            self.ctx.clone(),
-            &data_block,
-            bloom_index_location,
-            self.bloom_columns_map.clone(),
-        )?;
+        // the bloom index of a tiny block costs more than it saves, skip it.
+        // readers already tolerate blocks without bloom index location.
+        let bloom_index_state = if data_block.num_rows()
+            < self.write_settings.bloom_index_min_block_rows
+        {
+            if !self.bloom_columns_map.is_empty() {
+                metrics_inc_block_index_write_skipped_nums(1);
+            }
+            None
+        } else {
+            let bloom_index_location = self.meta_locations.block_bloom_index_location(&block_id);
+            BloomIndexState::try_create(
+                self.ctx.clone(),
+                &data_block,
+                bloom_index_location,
+                self.bloom_columns_map.clone(),
+            )?
+        };
         let column_distinct_count = bloom_index_state
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
@@ -302,6 +316,10 @@ impl BlockBuilder {
             &mut buffer,
         )?;
         let file_size = buffer.len() as u64;
+        let column_sizes = col_metas
+            .iter()
+            .map(|(column_id, col_meta)| (*column_id, col_meta.offset_length().1))
+            .collect();
         let block_meta = BlockMeta {
             row_count,
             block_size,
@@ -325,6 +343,7 @@ impl BlockBuilder {
             block_meta,
             bloom_index_state,
             inverted_index_states,
+            column_sizes,
         };
         Ok(serialized)
     }
@@ -16,6 +16,7 @@ use databend_storages_common_table_meta::table::TableCompression;
 
 use crate::FuseStorageFormat;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
+use crate::DEFAULT_BLOOM_INDEX_MIN_BLOCK_ROWS;
 use crate::DEFAULT_ROW_PER_PAGE;
 
 #[derive(Clone, Debug)]
@@ -26,6 +27,9 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+
+    // skip generating bloom index for blocks with fewer rows than this
+    pub bloom_index_min_block_rows: usize,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +39,7 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            bloom_index_min_block_rows: DEFAULT_BLOOM_INDEX_MIN_BLOCK_ROWS,
         }
     }
 }
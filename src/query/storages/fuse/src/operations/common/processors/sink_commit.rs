@@ -389,12 +389,16 @@ where F: SnapshotGenerator + Send + 'static
                 {
                     Ok(_) => {
                         if self.do_truncate() {
-                            catalog
+                            let reply = catalog
                                 .truncate_table(&table_info, TruncateTableReq {
                                     table_id: table_info.ident.table_id,
                                     batch_size: None,
                                 })
                                 .await?;
+                            info!(
+                                "truncated table {}, removed {} bytes in {} blocks",
+                                table_info.desc, reply.removed_bytes, reply.removed_blocks
+                            );
                         }
 
                         if self.purge {
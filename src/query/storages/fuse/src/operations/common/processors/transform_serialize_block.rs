@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -25,6 +26,8 @@ use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableSchema;
+use databend_common_meta_app::schema::ListIndexesByIdReq;
+use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_metrics::storage::*;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
@@ -34,12 +37,16 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::ColumnMeta;
+use databend_storages_common_table_meta::meta::Location;
 use opendal::Operator;
 
 use crate::io::create_inverted_index_builders;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
+use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
@@ -60,17 +67,92 @@ enum State {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
     },
+    SealPart,
+}
+
+impl State {
+    fn name(&self) -> &'static str {
+        match self {
+            State::Consume => "Consume",
+            State::NeedSerialize { .. } => "NeedSerialize",
+            State::Serialized { .. } => "Serialized",
+            State::SealPart => "SealPart",
+        }
+    }
+}
+
+/// A data file that small appended blocks are packed into, see `fuse_append_part_file_size`.
+///
+/// Blocks are read by the column offsets in their meta, so every block in the part is
+/// still read back on its own. The metas are held until the part is written.
+struct AppendPart {
+    threshold: usize,
+    location: Location,
+    data: Vec<u8>,
+    block_metas: Vec<BlockMeta>,
+    catalog: String,
+    table_id: u64,
+    // Whether the table is checked for aggregating indexes and virtual columns.
+    checked: bool,
+}
+
+impl AppendPart {
+    fn new(threshold: usize, table: &FuseTable) -> Self {
+        AppendPart {
+            threshold,
+            location: table.meta_location_generator().gen_block_location().0,
+            data: vec![],
+            block_metas: vec![],
+            catalog: table.get_table_info().catalog().to_string(),
+            table_id: table.get_id(),
+            checked: false,
+        }
+    }
+
+    fn append(&mut self, mut block_meta: BlockMeta, block_raw_data: Vec<u8>) {
+        let base = self.data.len() as u64;
+        for column_meta in block_meta.col_metas.values_mut() {
+            if let ColumnMeta::Parquet(column_meta) = column_meta {
+                column_meta.offset += base;
+            }
+        }
+        block_meta.location = self.location.clone();
+        self.data.extend_from_slice(&block_raw_data);
+        self.block_metas.push(block_meta);
+    }
+
+    fn is_full(&self) -> bool {
+        self.data.len() >= self.threshold
+    }
+
+    fn is_empty(&self) -> bool {
+        self.block_metas.is_empty()
+    }
+
+    /// Take the data and the metas of the part, and move on to a new location.
+    fn seal(
+        &mut self,
+        meta_locations: &TableMetaLocationGenerator,
+    ) -> (Location, Vec<u8>, Vec<BlockMeta>) {
+        let location = std::mem::replace(&mut self.location, meta_locations.gen_block_location().0);
+        let data = std::mem::take(&mut self.data);
+        let block_metas = std::mem::take(&mut self.block_metas);
+        (location, data, block_metas)
+    }
 }
 
 pub struct TransformSerializeBlock {
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
-    output_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
 
     block_builder: BlockBuilder,
     dal: Operator,
     table_id: Option<u64>, // Only used in mutli table insert
+    // Table id the per-column write metrics are labelled with.
+    metrics_table_id: u64,
+    append_part: Option<AppendPart>,
 }
 
 impl TransformSerializeBlock {
@@ -130,6 +212,17 @@ impl TransformSerializeBlock {
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
 
+        // Packing blocks is only done for plain inserts of parquet blocks. Inverted indexes
+        // and change tracking identify a block by the location of its data file, so do
+        // aggregating indexes and virtual columns, which are checked before the first block.
+        let append_part_size = ctx.get_settings().get_fuse_append_part_file_size()? as usize;
+        let append_part = (append_part_size > 0
+            && matches!(kind, MutationKind::Insert)
+            && !table.is_native()
+            && inverted_index_builders.is_empty()
+            && !table.change_tracking_enabled())
+        .then(|| AppendPart::new(append_part_size, table));
+
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -143,10 +236,12 @@ impl TransformSerializeBlock {
             state: State::Consume,
             input,
             output,
-            output_data: None,
+            output_data: VecDeque::new(),
             block_builder,
             dal: table.get_operator(),
             table_id: if with_tid { Some(table.get_id()) } else { None },
+            metrics_table_id: table.get_id(),
+            append_part,
         })
     }
 
@@ -165,6 +260,79 @@ impl TransformSerializeBlock {
         self.block_builder.clone()
     }
 
+    fn append_block_output(&self, block_meta: BlockMeta) -> DataBlock {
+        let progress_values = ProgressValues {
+            rows: block_meta.row_count as usize,
+            bytes: block_meta.block_size as usize,
+        };
+        self.block_builder
+            .ctx
+            .get_write_progress()
+            .incr(&progress_values);
+
+        if let Some(tid) = self.table_id {
+            self.block_builder
+                .ctx
+                .update_multi_table_insert_status(tid, block_meta.row_count);
+        }
+
+        DataBlock::empty_with_meta(Box::new(block_meta))
+    }
+
+    /// Aggregating index and virtual column files are named after the location of the block,
+    /// blocks packed into one part would overwrite each other's files. Packing is disabled
+    /// if the table has any of them.
+    #[async_backtrace::framed]
+    async fn check_append_part(&mut self) -> Result<()> {
+        let Some(part) = self.append_part.as_mut() else {
+            return Ok(());
+        };
+        if part.checked {
+            return Ok(());
+        }
+        part.checked = true;
+        let table_id = part.table_id;
+        let catalog_name = part.catalog.clone();
+
+        let ctx = &self.block_builder.ctx;
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(&catalog_name).await?;
+        let index_ids = catalog
+            .list_index_ids_by_table_id(ListIndexesByIdReq::new(&tenant, table_id))
+            .await?;
+        let virtual_columns = catalog
+            .list_virtual_columns(ListVirtualColumnsReq::new(&tenant, Some(table_id)))
+            .await?;
+        if !index_ids.is_empty() || !virtual_columns.is_empty() {
+            self.append_part = None;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn seal_part(&mut self) -> Result<()> {
+        let Some(part) = self.append_part.as_mut() else {
+            return Ok(());
+        };
+        let (location, data, block_metas) = part.seal(&self.block_builder.meta_locations);
+
+        let start = Instant::now();
+        let data_size = data.len();
+        write_data(data, &self.dal, &location.0).await?;
+        // Perf.
+        {
+            metrics_inc_block_write_nums(1);
+            metrics_inc_block_write_bytes(data_size as u64);
+            metrics_inc_block_write_milliseconds(start.elapsed().as_millis() as u64);
+        }
+
+        for block_meta in block_metas {
+            let data_block = self.append_block_output(block_meta);
+            self.output_data.push_back(data_block);
+        }
+        Ok(())
+    }
+
     fn mutation_logs(entry: MutationLogEntry) -> DataBlock {
         let meta = MutationLogs {
             entries: vec![entry],
@@ -188,7 +356,7 @@ impl Processor for TransformSerializeBlock {
             return Ok(Event::Sync);
         }
 
-        if matches!(self.state, State::Serialized { .. }) {
+        if matches!(self.state, State::Serialized { .. } | State::SealPart) {
             return Ok(Event::Async);
         }
 
@@ -200,12 +368,20 @@ impl Processor for TransformSerializeBlock {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some(data_block) = self.output_data.take() {
+        if let Some(data_block) = self.output_data.pop_front() {
             self.output.push_data(Ok(data_block));
             return Ok(Event::NeedConsume);
         }
 
         if self.input.is_finished() {
+            if self
+                .append_part
+                .as_ref()
+                .is_some_and(|part| !part.is_empty())
+            {
+                self.state = State::SealPart;
+                return Ok(Event::Async);
+            }
             self.output.finish();
             return Ok(Event::Finished);
         }
@@ -218,8 +394,13 @@ impl Processor for TransformSerializeBlock {
         let mut input_data = self.input.pull_data().unwrap()?;
         let meta = input_data.take_meta();
         if let Some(meta) = meta {
-            let meta = SerializeDataMeta::downcast_from(meta)
-                .ok_or_else(|| ErrorCode::Internal("It's a bug"))?;
+            if SerializeDataMeta::downcast_ref_from(&meta).is_none() {
+                return Err(ErrorCode::Internal(format!(
+                    "It's a bug, TransformSerializeBlock expects SerializeDataMeta, but got {:?}",
+                    meta
+                )));
+            }
+            let meta = SerializeDataMeta::downcast_from(meta).unwrap();
             match meta {
                 SerializeDataMeta::DeletedSegment(deleted_segment) => {
                     // delete a whole segment, segment level
@@ -294,7 +475,12 @@ impl Processor for TransformSerializeBlock {
 
                 self.state = State::Serialized { serialized, index };
             }
-            _ => return Err(ErrorCode::Internal("It's a bug.")),
+            state => {
+                return Err(ErrorCode::Internal(format!(
+                    "It's a bug, expect state NeedSerialize in process, but got {}",
+                    state.name()
+                )));
+            }
         }
         Ok(())
     }
@@ -303,18 +489,42 @@ impl Processor for TransformSerializeBlock {
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
             State::Serialized { serialized, index } => {
-                let start = Instant::now();
-                // write block data.
-                let raw_block_data = serialized.block_raw_data;
-                let data_size = raw_block_data.len();
-                let path = serialized.block_meta.location.0.as_str();
-                write_data(raw_block_data, &self.dal, path).await?;
-
-                // Perf.
-                {
-                    metrics_inc_block_write_nums(1);
-                    metrics_inc_block_write_bytes(data_size as u64);
-                    metrics_inc_block_write_milliseconds(start.elapsed().as_millis() as u64);
+                if index.is_none() {
+                    self.check_append_part().await?;
+                }
+
+                // Appended blocks are packed into the part, which is written once it is full.
+                let mut part_data = None;
+                if index.is_none() && self.append_part.is_some() {
+                    for (column_id, column_size) in &serialized.column_sizes {
+                        metrics_inc_block_write_bytes_per_column(
+                            self.metrics_table_id,
+                            *column_id,
+                            *column_size,
+                        );
+                    }
+                    part_data = Some(serialized.block_raw_data);
+                } else {
+                    let start = Instant::now();
+                    // write block data.
+                    let raw_block_data = serialized.block_raw_data;
+                    let data_size = raw_block_data.len();
+                    let path = serialized.block_meta.location.0.as_str();
+                    write_data(raw_block_data, &self.dal, path).await?;
+
+                    // Perf.
+                    {
+                        metrics_inc_block_write_nums(1);
+                        metrics_inc_block_write_bytes(data_size as u64);
+                        for (column_id, column_size) in &serialized.column_sizes {
+                            metrics_inc_block_write_bytes_per_column(
+                                self.metrics_table_id,
+                                *column_id,
+                                *column_size,
+                            );
+                        }
+                        metrics_inc_block_write_milliseconds(start.elapsed().as_millis() as u64);
+                    }
                 }
 
                 // write index data.
@@ -358,32 +568,31 @@ impl Processor for TransformSerializeBlock {
                     }
                 }
 
-                let data_block = if let Some(index) = index {
-                    Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                if let Some(index) = index {
+                    let data_block = Self::mutation_logs(MutationLogEntry::ReplacedBlock {
                         index,
                         block_meta: Arc::new(serialized.block_meta),
-                    })
-                } else {
-                    let progress_values = ProgressValues {
-                        rows: serialized.block_meta.row_count as usize,
-                        bytes: serialized.block_meta.block_size as usize,
-                    };
-                    self.block_builder
-                        .ctx
-                        .get_write_progress()
-                        .incr(&progress_values);
-
-                    if let Some(tid) = self.table_id {
-                        self.block_builder
-                            .ctx
-                            .update_multi_table_insert_status(tid, serialized.block_meta.row_count);
+                    });
+                    self.output_data.push_back(data_block);
+                } else if let (Some(part), Some(block_raw_data)) =
+                    (self.append_part.as_mut(), part_data)
+                {
+                    part.append(serialized.block_meta, block_raw_data);
+                    if part.is_full() {
+                        self.seal_part().await?;
                     }
-
-                    DataBlock::empty_with_meta(Box::new(serialized.block_meta))
-                };
-                self.output_data = Some(data_block);
+                } else {
+                    let data_block = self.append_block_output(serialized.block_meta);
+                    self.output_data.push_back(data_block);
+                }
+            }
+            State::SealPart => self.seal_part().await?,
+            state => {
+                return Err(ErrorCode::Internal(format!(
+                    "It's a bug, expect state Serialized in async_process, but got {}",
+                    state.name()
+                )));
             }
-            _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
     }
@@ -47,6 +47,7 @@ pub use mutation::*;
 pub use read::build_row_fetcher_pipeline;
 pub use read::need_reserve_block_info;
 pub use replace_into::*;
+pub use revert::RevertPreview;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
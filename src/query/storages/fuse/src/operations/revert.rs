@@ -12,16 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+use std::sync::Arc;
+
 use databend_common_catalog::table::NavigationDescriptor;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::TableSnapshot;
 
+use crate::io::SegmentsIO;
 use crate::FuseTable;
 
+/// What reverting a table to a given point would change, relative to the current snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RevertPreview {
+    pub current_snapshot_id: Option<String>,
+    pub target_snapshot_id: Option<String>,
+    /// Rows that only exist in the snapshot being reverted to.
+    pub rows_added: u64,
+    /// Rows that only exist in the current snapshot.
+    pub rows_removed: u64,
+}
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_revert_to(
@@ -73,4 +92,63 @@ impl FuseTable {
 
         reply.map(|_| ())
     }
+
+    /// Compute the changes `do_revert_to` would make, without committing anything.
+    ///
+    /// Segments are immutable and shared between snapshots, so the difference is
+    /// taken over the segment locations of the two snapshots.
+    #[async_backtrace::framed]
+    pub async fn do_revert_preview(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        point: &NavigationPoint,
+    ) -> Result<RevertPreview> {
+        let table_reverting_to = self.navigate_to_point(point).await?;
+
+        let current = self.read_table_snapshot().await?;
+        let target = table_reverting_to.read_table_snapshot().await?;
+
+        let current_segments = segment_locations(&current);
+        let target_segments = segment_locations(&target);
+
+        let added = target_segments
+            .difference(&current_segments)
+            .cloned()
+            .collect::<Vec<_>>();
+        let removed = current_segments
+            .difference(&target_segments)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        Ok(RevertPreview {
+            current_snapshot_id: current.map(|s| s.snapshot_id.simple().to_string()),
+            target_snapshot_id: target.map(|s| s.snapshot_id.simple().to_string()),
+            rows_added: self.count_segment_rows(ctx.clone(), &added).await?,
+            rows_removed: self.count_segment_rows(ctx, &removed).await?,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn count_segment_rows(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        locations: &[Location],
+    ) -> Result<u64> {
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        let segments = segments_io
+            .read_segments::<SegmentInfo>(locations, false)
+            .await?;
+        let mut rows = 0;
+        for segment in segments {
+            rows += segment?.summary.row_count;
+        }
+        Ok(rows)
+    }
+}
+
+fn segment_locations(snapshot: &Option<Arc<TableSnapshot>>) -> HashSet<Location> {
+    snapshot
+        .as_ref()
+        .map(|s| s.segments.iter().cloned().collect())
+        .unwrap_or_default()
 }
@@ -81,6 +81,7 @@ impl IcebergTable {
             engine_name: ICEBERG_ENGINE.to_string(),
             comment: "ICEBERG Storage Engine".to_string(),
             support_cluster_key: false,
+            ..Default::default()
         }
     }
 
@@ -20,3 +20,4 @@ pub use share::get_share_spec_location;
 pub use share::save_share_spec;
 pub use share::save_share_table_info;
 pub use share::share_table_info_location;
+pub use share::update_share_comment;
@@ -16,11 +16,13 @@ use std::collections::BTreeMap;
 
 use chrono::DateTime;
 use chrono::Utc;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::share::ShareDatabaseSpec;
 use databend_common_meta_app::share::ShareSpec;
 use databend_common_meta_app::share::ShareTableInfoMap;
 use databend_common_meta_app::share::ShareTableSpec;
+use databend_common_meta_app::share::ShareViewSpec;
 use opendal::Operator;
 
 const SHARE_CONFIG_PREFIX: &str = "_share_config";
@@ -95,6 +97,34 @@ pub async fn save_share_spec(
     Ok(())
 }
 
+/// Update the comment of a share in the spec file, other fields of the share are kept as they are.
+#[async_backtrace::framed]
+pub async fn update_share_comment(
+    tenant: &str,
+    operator: Operator,
+    share_name: &str,
+    comment: Option<String>,
+) -> Result<()> {
+    let location = get_share_spec_location(tenant);
+    let data = operator.read(&location).await?;
+    let mut share_spec_vec: ShareSpecVec = serde_json::from_slice(&data)?;
+
+    match share_spec_vec.share_specs.get_mut(share_name) {
+        Some(share_spec_ext) => share_spec_ext.set_comment(comment),
+        None => {
+            return Err(ErrorCode::UnknownShare(format!(
+                "UnknownShare {}",
+                share_name
+            )));
+        }
+    }
+
+    operator
+        .write(&location, serde_json::to_vec(&share_spec_vec)?)
+        .await?;
+    Ok(())
+}
+
 mod ext {
     use databend_common_meta_app::share::ShareGrantObjectPrivilege;
     use databend_storages_common_table_meta::table::database_storage_prefix;
@@ -110,8 +140,8 @@ mod ext {
         t: T,
     }
 
-    /// An extended form of [ShareSpec], which decorates [ShareDatabaseSpec] and [ShareTableSpec]
-    /// with location
+    /// An extended form of [ShareSpec], which decorates [ShareDatabaseSpec], [ShareTableSpec]
+    /// and [ShareViewSpec] with location
     #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
     pub(super) struct ShareSpecExt {
         name: String,
@@ -119,6 +149,8 @@ mod ext {
         version: u64,
         database: Option<WithLocation<ShareDatabaseSpec>>,
         tables: Vec<WithLocation<ShareTableSpec>>,
+        #[serde(default)]
+        views: Vec<WithLocation<ShareViewSpec>>,
         tenants: Vec<String>,
         db_privileges: Option<BitFlags<ShareGrantObjectPrivilege>>,
         comment: Option<String>,
@@ -147,12 +179,28 @@ mod ext {
                         t: tbl_spec,
                     })
                     .collect(),
+                views: spec
+                    .views
+                    .into_iter()
+                    .map(|view_spec| WithLocation {
+                        location: shared_table_prefix(
+                            operator,
+                            view_spec.database_id,
+                            view_spec.view_id,
+                        ),
+                        t: view_spec,
+                    })
+                    .collect(),
                 tenants: spec.tenants,
                 db_privileges: spec.db_privileges,
                 comment: spec.comment.clone(),
                 share_on: spec.share_on,
             }
         }
+
+        pub fn set_comment(&mut self, comment: Option<String>) {
+            self.comment = comment;
+        }
     }
 
     /// Returns prefix path which covers all the data of give table.
@@ -179,4 +227,152 @@ mod ext {
         // storage_prefix has suffix character '/'
         format!("{}{}/", storage_prefix, database_storage_prefix)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use databend_common_meta_app::share::ShareGrantObjectPrivilege;
+        use opendal::services::Fs;
+        use serde_json::json;
+
+        use super::*;
+
+        fn test_operator(root: &str) -> Result<Operator> {
+            let mut builder = Fs::default();
+            builder.root(root);
+            Ok(Operator::new(builder)?.finish())
+        }
+
+        #[test]
+        fn test_serialize_share_spec_ext() -> Result<()> {
+            let share_spec = ShareSpec {
+                name: "test_share_name".to_string(),
+                version: 1,
+                share_id: 1,
+                database: Some(ShareDatabaseSpec {
+                    name: "share_database".to_string(),
+                    id: 1,
+                }),
+                tables: vec![ShareTableSpec {
+                    name: "share_table".to_string(),
+                    database_id: 1,
+                    table_id: 1,
+                    presigned_url_timeout: "100s".to_string(),
+                }],
+                views: vec![ShareViewSpec::new(
+                    "share_view",
+                    1,
+                    2,
+                    "SELECT * FROM share_table",
+                )],
+                tenants: vec!["test_tenant".to_owned()],
+                comment: None,
+                share_on: None,
+                db_privileges: Some(ShareGrantObjectPrivilege::Usage.into()),
+            };
+            let tmp_dir = tempfile::tempdir()?;
+            let test_root = tmp_dir.path().join("test_cluster_id/test_tenant_id");
+            let test_root_str = test_root.to_str().unwrap();
+            let operator = test_operator(test_root_str)?;
+
+            let share_spec_ext = ShareSpecExt::from_share_spec(share_spec, &operator);
+            let spec_json_value = serde_json::to_value(&share_spec_ext)?;
+
+            let expected = json!({
+              "name": "test_share_name",
+              "share_id": 1,
+              "version": 1,
+              "database": {
+                "location": format!("{}/1/", test_root_str),
+                "name": "share_database",
+                "id": 1
+              },
+              "tables": [
+                {
+                  "location": format!("{}/1/1/", test_root_str),
+                  "name": "share_table",
+                  "database_id": 1,
+                  "table_id": 1,
+                  "presigned_url_timeout": "100s"
+                }
+              ],
+              "views": [
+                {
+                  "location": format!("{}/1/2/", test_root_str),
+                  "name": "share_view",
+                  "database_id": 1,
+                  "view_id": 2,
+                  "view_query": "SELECT * FROM share_table"
+                }
+              ],
+              "tenants": [
+                "test_tenant"
+              ],
+              "db_privileges": 1,
+              "comment": null,
+              "share_on": null
+            });
+
+            assert_eq!(expected, spec_json_value);
+
+            // Specs written before views were supported have no `views` field.
+            let mut legacy = spec_json_value;
+            legacy.as_object_mut().unwrap().remove("views");
+            let legacy: ShareSpecExt = serde_json::from_value(legacy)?;
+            assert!(legacy.views.is_empty());
+            assert_eq!(legacy.tables, share_spec_ext.tables);
+
+            Ok(())
+        }
+
+        async fn read_share_spec_vec(operator: &Operator, tenant: &str) -> Result<ShareSpecVec> {
+            let data = operator.read(&get_share_spec_location(tenant)).await?;
+            Ok(serde_json::from_slice(&data)?)
+        }
+
+        #[tokio::test]
+        async fn test_update_share_comment() -> Result<()> {
+            let tmp_dir = tempfile::tempdir()?;
+            let operator = test_operator(tmp_dir.path().to_str().unwrap())?;
+            let tenant = "test_tenant";
+
+            let share_spec = ShareSpec {
+                name: "share1".to_string(),
+                share_id: 1,
+                version: 1,
+                database: Some(ShareDatabaseSpec {
+                    name: "share_database".to_string(),
+                    id: 1,
+                }),
+                tables: vec![ShareTableSpec::new("share_table", 1, 2)],
+                tenants: vec!["consumer".to_string()],
+                comment: Some("old comment".to_string()),
+                ..Default::default()
+            };
+            save_share_spec(tenant, operator.clone(), Some(vec![share_spec]), None).await?;
+
+            let before = read_share_spec_vec(&operator, tenant).await?;
+
+            update_share_comment(
+                tenant,
+                operator.clone(),
+                "share1",
+                Some("new comment".to_string()),
+            )
+            .await?;
+
+            let after = read_share_spec_vec(&operator, tenant).await?;
+            let mut expected = before.share_specs["share1"].clone();
+            expected.comment = Some("new comment".to_string());
+            assert_eq!(expected, after.share_specs["share1"]);
+            assert_eq!(after.share_specs["share1"].tables.len(), 1);
+            assert_eq!(after.share_specs["share1"].tenants, vec!["consumer"]);
+
+            let err = update_share_comment(tenant, operator.clone(), "share2", None)
+                .await
+                .unwrap_err();
+            assert_eq!(ErrorCode::UNKNOWN_SHARE, err.code());
+
+            Ok(())
+        }
+    }
 }
@@ -52,6 +52,8 @@ impl LoadContext {
         let mut file_format_options_ext =
             FileFormatOptionsExt::create_from_settings(&settings, is_select)?;
         file_format_options_ext.disable_variant_check = copy_options.disable_variant_check;
+        file_format_options_ext
+            .apply_file_format_time_zone(&stage_table_info.stage_info.file_format_params)?;
         let on_error_mode = copy_options.on_error.clone();
         let fields = stage_table_info
             .schema
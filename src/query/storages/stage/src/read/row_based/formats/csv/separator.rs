@@ -40,8 +40,12 @@ pub struct CsvReader {
     // select $1, $2, $3 ..  from csv
     projection: Option<Vec<usize>>,
     error_on_column_count_mismatch: bool,
+    skip_blank_lines: bool,
     num_fields: usize,
 
+    builder: csv_core::ReaderBuilder,
+    // record_delimiter is AUTO and no data is read yet
+    sniff_record_delimiter: bool,
     reader: csv_core::Reader,
     // remain from last read batch
     last_partial_row: Vec<u8>,
@@ -51,6 +55,8 @@ pub struct CsvReader {
     // flush to RowBatch when a complete row is read
     field_ends: Vec<usize>,
     n_end: usize,
+    // whether the output of the current (maybe partial) row is whitespace only so far
+    blank_record: bool,
 
     pos: Position,
     rows_to_skip: usize,
@@ -62,6 +68,18 @@ impl SeparatorState for CsvReader {
     }
 }
 
+fn build_reader(
+    builder: &mut csv_core::ReaderBuilder,
+    record_delimiter: &RecordDelimiter,
+) -> csv_core::Reader {
+    builder
+        .terminator(match record_delimiter {
+            RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
+            RecordDelimiter::Any(v) => csv_core::Terminator::Any(*v),
+        })
+        .build()
+}
+
 enum ReadRecordOutput {
     Record { num_fields: usize, bytes: usize },
     RecordSkipped,
@@ -79,15 +97,19 @@ impl CsvReader {
         } else {
             Some(format.params.escape.as_bytes()[0])
         };
-        let reader = csv_core::ReaderBuilder::new()
+        let mut builder = csv_core::ReaderBuilder::new();
+        builder
             .delimiter(format.params.field_delimiter.as_bytes()[0])
             .quote(format.params.quote.as_bytes()[0])
-            .escape(escape)
-            .terminator(match format.params.record_delimiter.as_str().try_into()? {
-                RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
-                RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
-            })
-            .build();
+            .escape(escape);
+        // with AUTO, the reader is rebuilt once the first batch of the file is seen
+        let sniff_record_delimiter = format.params.is_auto_record_delimiter();
+        let record_delimiter = if sniff_record_delimiter {
+            RecordDelimiter::default()
+        } else {
+            format.params.record_delimiter.as_str().try_into()?
+        };
+        let reader = build_reader(&mut builder, &record_delimiter);
         let projection = load_ctx.pos_projection.clone();
         let max_fields = match &projection {
             Some(p) => p.iter().copied().max().unwrap_or(1),
@@ -99,13 +121,17 @@ impl CsvReader {
             load_ctx,
             projection,
             error_on_column_count_mismatch: format.params.error_on_column_count_mismatch,
+            skip_blank_lines: format.params.skip_blank_lines,
             num_fields,
+            builder,
+            sniff_record_delimiter,
             reader,
             pos: Position::new(path.to_string()),
             rows_to_skip: format.params.headers as usize,
             field_ends: vec![0; max_fields],
             last_partial_row: vec![],
             n_end: 0,
+            blank_record: true,
         })
     }
 
@@ -121,6 +147,7 @@ impl CsvReader {
         self.n_end += n_end;
         // shadow the n_end return from reader to avoid misuse
         let n_end = self.n_end;
+        self.blank_record &= output[..n_out].iter().all(u8::is_ascii_whitespace);
 
         match result {
             ReadRecordResult::InputEmpty => {
@@ -134,8 +161,11 @@ impl CsvReader {
             ReadRecordResult::OutputFull => Err(self.error_output_full()),
             ReadRecordResult::OutputEndsFull => Err(self.error_output_ends_full()),
             ReadRecordResult::Record => {
+                let is_blank = mem::replace(&mut self.blank_record, true) && n_end <= 1;
                 let output = {
-                    if self.projection.is_some() {
+                    if self.skip_blank_lines && is_blank {
+                        ReadRecordOutput::RecordSkipped
+                    } else if self.projection.is_some() {
                         // select $1, $2, $3 ..  from csv, not check num of fields here
                         ReadRecordOutput::Record {
                             num_fields: n_end,
@@ -198,6 +228,16 @@ impl CsvReader {
     fn separate(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
         // prepare for reading header and data
 
+        if self.sniff_record_delimiter && !batch.data.is_empty() {
+            let record_delimiter = RecordDelimiter::sniff(&batch.data);
+            debug!(
+                "csv aligner: record delimiter of {} sniffed as {:?}",
+                self.pos.path, record_delimiter
+            );
+            self.reader = build_reader(&mut self.builder, &record_delimiter);
+            self.sniff_record_delimiter = false;
+        }
+
         let need_flush = batch.is_eof;
         let mut buf_in = &batch.data[..];
         let size_in = buf_in.len();
@@ -223,7 +263,7 @@ impl CsvReader {
 
         let mut buf_out_pos = 0usize;
         let mut buf_out_row_end: usize = 0;
-        let last_batch_remain_len = self.last_partial_row.len();
+        let mut last_batch_remain_len = self.last_partial_row.len();
         let pos = Position::from_bytes_batch(&batch, self.pos.rows);
         let mut row_batch = CSVRowBatch::default();
 
@@ -251,7 +291,14 @@ impl CsvReader {
                 ReadRecordOutput::PartialRecord { bytes } => {
                     buf_out_pos += bytes;
                 }
-                _ => {}
+                ReadRecordOutput::RecordSkipped => {
+                    // drop what is decoded for the skipped row
+                    buf_out_pos = buf_out_row_end;
+                    if row_batch.row_ends.is_empty() {
+                        self.last_partial_row.clear();
+                        last_batch_remain_len = 0;
+                    }
+                }
             }
             if buf_in.is_empty() {
                 // state already flushed
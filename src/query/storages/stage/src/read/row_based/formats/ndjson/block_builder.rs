@@ -29,6 +29,8 @@ use crate::read::row_based::format::RowDecoder;
 use crate::read::row_based::formats::ndjson::format::NdJsonInputFormat;
 use crate::read::row_based::processors::BlockBuilderState;
 
+const NULL_NOT_ALLOWED: &str = "null value is not allowed for non-nullable field, when NULL_FIELD_AS=NULL (use NULL_FIELD_AS=FIELD_DEFAULT to load the field default instead)";
+
 pub struct NdJsonDecoder {
     pub load_context: Arc<LoadContext>,
     pub fmt: NdJsonInputFormat,
@@ -44,6 +46,20 @@ impl NdJsonDecoder {
             field_decoder,
         }
     }
+
+    /// Fill a null or missing field for `FIELD_DEFAULT`, falling back in order to:
+    /// 1. the default declared for the column in the table schema;
+    /// 2. the default value of the column type, which is NULL for nullable columns.
+    ///
+    /// When loading into a table, `default_values` is evaluated from both tiers at bind time,
+    /// otherwise there is no declared default and the type default is used.
+    fn push_field_default(&self, column: &mut ColumnBuilder, column_index: usize) {
+        match &self.load_context.default_values {
+            Some(values) => column.push(values[column_index].as_ref()),
+            None => column.push_default(),
+        }
+    }
+
     fn read_row(
         &self,
         buf: &[u8],
@@ -104,13 +120,7 @@ impl NdJsonDecoder {
                                 });
                             }
                         }
-                        NullAs::FieldDefault => {
-                            if let Some(values) = &self.load_context.default_values {
-                                column.push(values[column_index].as_ref());
-                            } else {
-                                column.push_default();
-                            }
-                        }
+                        NullAs::FieldDefault => self.push_field_default(column, column_index),
                     },
                     Some(serde_json::Value::Null) => match self.fmt.params.null_field_as {
                         NullAs::Error => unreachable!("null_field_as should be error"),
@@ -122,18 +132,12 @@ impl NdJsonDecoder {
                                     column_index,
                                     column_name: field.name().to_owned(),
                                     column_type: field.data_type.to_string(),
-                                    decode_error: "null value is not allowed for non-nullable field, when NULL_FIELDS_AS=NULL".to_owned(),
+                                    decode_error: NULL_NOT_ALLOWED.to_owned(),
                                     column_data: "null".to_owned(),
                                 });
                             }
                         }
-                        NullAs::FieldDefault => {
-                            if let Some(values) = &self.load_context.default_values {
-                                column.push(values[column_index].as_ref());
-                            } else {
-                                column.push_default();
-                            }
-                        }
+                        NullAs::FieldDefault => self.push_field_default(column, column_index),
                     },
                     Some(value) => {
                         if !null_if.is_empty()
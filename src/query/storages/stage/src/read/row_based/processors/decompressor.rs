@@ -32,6 +32,9 @@ pub struct Decompressor {
     algo: Option<CompressAlgorithm>,
     decompressor: Option<(DecompressDecoder, usize)>,
     path: Option<String>,
+    /// The head of a file whose compression is to be detected by its magic bytes, held
+    /// until it is long enough or the file ends.
+    head: Option<BytesBatch>,
 }
 
 impl Decompressor {
@@ -41,16 +44,12 @@ impl Decompressor {
             algo,
             path: None,
             decompressor: None,
+            head: None,
         })
     }
 
-    fn new_file(&mut self, path: String) {
+    fn new_file(&mut self, path: String, algo: Option<CompressAlgorithm>) {
         assert!(self.decompressor.is_none());
-        let algo = if let Some(algo) = &self.algo {
-            Some(algo.to_owned())
-        } else {
-            CompressAlgorithm::from_path(&path)
-        };
         self.path = Some(path);
 
         if let Some(algo) = algo {
@@ -66,17 +65,33 @@ impl AccumulatingTransform for Decompressor {
     const NAME: &'static str = "Decompressor";
 
     fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
-        let batch = data
+        let mut batch = data
             .get_owned_meta()
             .and_then(BytesBatch::downcast_from)
             .unwrap();
-        match &self.path {
-            None => self.new_file(batch.path.clone()),
-            Some(path) => {
-                if path != &batch.path {
-                    self.new_file(batch.path.clone())
+
+        if let Some(mut head) = self.head.take() {
+            head.data.extend_from_slice(&batch.data);
+            head.is_eof = batch.is_eof;
+            batch = head;
+        }
+        if self.path.as_ref() != Some(&batch.path) {
+            // With `COMPRESSION = AUTO`, `algo` is None and the compression of each file is
+            // detected from its extension first, and its magic bytes second.
+            let algo = match self
+                .algo
+                .or_else(|| CompressAlgorithm::from_path(&batch.path))
+            {
+                Some(algo) => Some(algo),
+                None if batch.data.len() < CompressAlgorithm::MAX_MAGIC_BYTES_LEN
+                    && !batch.is_eof =>
+                {
+                    self.head = Some(batch);
+                    return Ok(vec![]);
                 }
-            }
+                None => CompressAlgorithm::from_magic_bytes(&batch.data),
+            };
+            self.new_file(batch.path.clone(), algo);
         }
 
         if let Some((de, offset)) = &mut self.decompressor {
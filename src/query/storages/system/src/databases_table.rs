@@ -70,6 +70,7 @@ impl AsyncSystemTable for DatabasesTable {
         let mut db_names = vec![];
         let mut db_id = vec![];
         let mut owners: Vec<Option<String>> = vec![];
+        let mut created_bys: Vec<Option<String>> = vec![];
 
         let visibility_checker = ctx.get_visibility_checker().await?;
 
@@ -102,6 +103,7 @@ impl AsyncSystemTable for DatabasesTable {
                         .ok()
                         .and_then(|ownership| ownership.map(|o| o.role.clone())),
                 );
+                created_bys.push(db.get_db_info().meta.created_by.clone());
             }
         }
 
@@ -110,6 +112,7 @@ impl AsyncSystemTable for DatabasesTable {
             StringType::from_data(db_names),
             UInt64Type::from_data(db_id),
             StringType::from_opt_data(owners),
+            StringType::from_opt_data(created_bys),
         ]))
     }
 }
@@ -124,6 +127,10 @@ impl DatabasesTable {
                 "owner",
                 TableDataType::Nullable(Box::from(TableDataType::String)),
             ),
+            TableField::new(
+                "created_by",
+                TableDataType::Nullable(Box::from(TableDataType::String)),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -54,14 +54,17 @@ impl AsyncSystemTable for EnginesTable {
         let table_engine_descriptors = ctx.get_catalog(CATALOG_DEFAULT).await?.get_table_engines();
         let mut engine_name = Vec::with_capacity(table_engine_descriptors.len());
         let mut engine_comment = Vec::with_capacity(table_engine_descriptors.len());
+        let mut engine_supported_options = Vec::with_capacity(table_engine_descriptors.len());
         for descriptor in &table_engine_descriptors {
             engine_name.push(descriptor.engine_name.clone());
             engine_comment.push(descriptor.comment.clone());
+            engine_supported_options.push(descriptor.supported_options.join(", "));
         }
 
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(engine_name),
             StringType::from_data(engine_comment),
+            StringType::from_data(engine_supported_options),
         ]))
     }
 }
@@ -71,6 +74,7 @@ impl EnginesTable {
         let schema = TableSchemaRefExt::create(vec![
             TableField::new("Engine", TableDataType::String),
             TableField::new("Comment", TableDataType::String),
+            TableField::new("Supported_options", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -150,6 +150,7 @@ pub struct QueryLogElement {
     pub client_info: String,
     pub client_address: String,
     pub user_agent: String,
+    pub application_name: String,
 
     // Exception.
     pub exception_code: i32,
@@ -295,6 +296,7 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("client_info", TableDataType::String),
             TableField::new("client_address", TableDataType::String),
             TableField::new("user_agent", TableDataType::String),
+            TableField::new("application_name", TableDataType::String),
             // Exception.
             TableField::new(
                 "exception_code",
@@ -515,6 +517,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.user_agent.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.application_name.clone()).as_ref());
         // Exception.
         columns
             .next()
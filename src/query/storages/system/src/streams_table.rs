@@ -93,6 +93,25 @@ impl AsyncSystemTable for StreamsTable {
 
         let visibility_checker = ctx.get_visibility_checker().await?;
 
+        // Source tables(`<database>.<table>`) pushed down by `table_name = '...'`.
+        let mut source_tables: Vec<String> = Vec::new();
+        if let Some(filter) = push_downs
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| &f.filter)
+        {
+            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if col_name == "table_name" {
+                    if let Scalar::String(table) = scalar {
+                        if !source_tables.contains(table) {
+                            source_tables.push(table.clone());
+                        }
+                    }
+                }
+            });
+        }
+
         for (ctl_name, ctl) in ctls.into_iter() {
             let mut dbs = Vec::new();
             if let Some(push_downs) = &push_downs {
@@ -162,6 +181,16 @@ impl AsyncSystemTable for StreamsTable {
                         t_id,
                     ) && table.engine() == "STREAM"
                     {
+                        let stream_table = StreamTable::try_from_table(table.as_ref())?;
+                        let source_table = format!(
+                            "{}.{}",
+                            stream_table.source_table_database(),
+                            stream_table.source_table_name()
+                        );
+                        if !source_tables.is_empty() && !source_tables.contains(&source_table) {
+                            continue;
+                        }
+
                         catalogs.push(ctl_name);
                         databases.push(name);
 
@@ -183,12 +212,7 @@ impl AsyncSystemTable for StreamsTable {
                         );
                         comment.push(stream_info.meta.comment.clone());
 
-                        let stream_table = StreamTable::try_from_table(table.as_ref())?;
-                        table_name.push(format!(
-                            "{}.{}",
-                            stream_table.source_table_database(),
-                            stream_table.source_table_name()
-                        ));
+                        table_name.push(source_table);
                         mode.push(stream_table.mode().to_string());
                         table_version.push(stream_table.offset());
                         table_id.push(stream_table.source_table_id());
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
@@ -231,6 +232,11 @@ where TablesTable<T, U>: HistoryAware
         let mut database_tables = vec![];
         let mut owner: Vec<Option<String>> = Vec::new();
         let user_api = UserApiProvider::instance();
+        let ownership_cache_ttl = Duration::from_secs(
+            ctx.get_settings()
+                .get_ownership_cache_ttl_in_secs()
+                .unwrap_or_default(),
+        );
 
         for (ctl_name, ctl) in ctls.into_iter() {
             let mut dbs = Vec::new();
@@ -286,7 +292,10 @@ where TablesTable<T, U>: HistoryAware
                 })
                 .collect::<Vec<_>>();
 
-            let ownership = user_api.get_ownerships(&tenant).await.unwrap_or_default();
+            let ownership = user_api
+                .get_ownerships_cached(&tenant, ownership_cache_ttl)
+                .await
+                .unwrap_or_default();
             for db in final_dbs {
                 let name = db.name().to_string().into_boxed_str();
                 let db_id = db.get_db_info().ident.db_id;
@@ -34,7 +34,7 @@ use databend_common_expression::Value;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
-use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_core::query_spill_prefix_with_path;
 use databend_common_storage::DataOperator;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -68,7 +68,11 @@ impl AsyncSystemTable for TempFilesTable {
         let mut temp_files_content_length = vec![];
         let mut temp_files_last_modified = vec![];
 
-        let location_prefix = format!("{}/", query_spill_prefix(tenant.tenant_name(), ""));
+        let spill_path = ctx.get_settings().get_spill_path()?;
+        let location_prefix = format!(
+            "{}/",
+            query_spill_prefix_with_path(&spill_path, tenant.tenant_name(), "")
+        );
         if let Ok(lister) = operator
             .lister_with(&location_prefix)
             .recursive(true)
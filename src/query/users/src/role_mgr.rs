@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -65,6 +66,39 @@ impl UserApiProvider {
         Ok(roles)
     }
 
+    // Get a page of the tenant roles list, the roles after `start_after` in the order of name.
+    // An empty page means there are no more roles.
+    #[async_backtrace::framed]
+    pub async fn get_roles_page(
+        &self,
+        tenant: &Tenant,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<RoleInfo>> {
+        let builtin_roles = self.builtin_roles();
+        let seq_roles = self
+            .role_api(tenant)
+            .get_roles_page(start_after, limit)
+            .await
+            .map_err(|e| e.add_message_back("(while get roles page)."))?;
+        // The builtin roles are in the page which would hold them if they were stored.
+        let page_end = match seq_roles.len() < limit {
+            true => None,
+            false => seq_roles.last().map(|r| r.data.name.clone()),
+        };
+        let mut roles = seq_roles
+            .into_iter()
+            .map(|r| r.data)
+            .filter(|r| !builtin_roles.contains_key(&r.name))
+            .collect::<Vec<_>>();
+        roles.extend(builtin_roles.into_values().filter(|r| {
+            start_after.map_or(true, |s| r.name.as_str() > s)
+                && page_end.as_ref().map_or(true, |e| r.name <= *e)
+        }));
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(roles)
+    }
+
     // Currently we have to built account_admin role in query:
     // 1. ACCOUNT_ADMIN, which has the equivalent privileges of `GRANT ALL ON *.* TO ROLE account_admin`,
     //    it also contains all roles. ACCOUNT_ADMIN can access the data objects which owned by any role.
@@ -101,6 +135,41 @@ impl UserApiProvider {
         Ok(roles)
     }
 
+    /// Same as `get_ownerships`, but may return a listing cached up to `ttl` ago.
+    ///
+    /// A zero `ttl` always reads from meta-service.
+    #[async_backtrace::framed]
+    pub async fn get_ownerships_cached(
+        &self,
+        tenant: &Tenant,
+        ttl: Duration,
+    ) -> Result<HashMap<OwnershipObject, String>> {
+        let seq_owns = self
+            .role_api_with_ownership_cache(tenant, ttl)
+            .get_ownerships_cached()
+            .await
+            .map_err(|e| e.add_message_back("(while get ownerships)."))?;
+
+        let roles: HashMap<OwnershipObject, String> = seq_owns
+            .into_iter()
+            .map(|r| (r.data.object, r.data.role))
+            .collect();
+        Ok(roles)
+    }
+
+    /// Objects owned by each role, keyed by the role name.
+    #[async_backtrace::framed]
+    pub async fn get_ownerships_by_role(
+        &self,
+        tenant: &Tenant,
+    ) -> Result<HashMap<String, Vec<OwnershipObject>>> {
+        let mut ownerships: HashMap<String, Vec<OwnershipObject>> = HashMap::new();
+        for (object, role) in self.get_ownerships(tenant).await? {
+            ownerships.entry(role).or_default().push(object);
+        }
+        Ok(ownerships)
+    }
+
     #[async_backtrace::framed]
     pub async fn exists_role(&self, tenant: &Tenant, role: String) -> Result<bool> {
         match self.get_role(tenant, role).await {
@@ -180,6 +249,7 @@ impl UserApiProvider {
                 Ok(Some(OwnershipInfo {
                     role: BUILTIN_ROLE_ACCOUNT_ADMIN.to_string(),
                     object: object.clone(),
+                    updated_on: owner.updated_on,
                 }))
             } else {
                 Ok(Some(owner))
@@ -287,6 +357,21 @@ impl UserApiProvider {
         }
     }
 
+    // Drop a role by name, and revoke it from all the roles and users which are granted it.
+    // Dropping a role which does not exist only cleans up the remaining grants, so it is
+    // safe to retry.
+    #[async_backtrace::framed]
+    pub async fn drop_role_cascade(&self, tenant: &Tenant, role: String) -> Result<()> {
+        let client = self.role_api(tenant);
+        // If the dropped role owns objects, transfer objects owner to account_admin role.
+        client.transfer_ownership_to_admin(&role).await?;
+
+        client
+            .drop_role_cascade(&role)
+            .await
+            .map_err(|e| e.add_message_back("(while drop role cascade)"))
+    }
+
     // Find all related roles by role names. Every role have a PUBLIC role, and ACCOUNT_ADMIN
     // default contains every role.
     #[async_backtrace::framed]
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_base::base::GlobalInstance;
 use databend_common_exception::Result;
@@ -22,6 +23,7 @@ use databend_common_management::udf::UdfMgr;
 use databend_common_management::ConnectionMgr;
 use databend_common_management::FileFormatMgr;
 use databend_common_management::NetworkPolicyMgr;
+use databend_common_management::OwnershipCache;
 use databend_common_management::PasswordPolicyMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
@@ -31,6 +33,7 @@ use databend_common_management::SettingApi;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TableLocationMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
 use databend_common_meta_app::principal::AuthInfo;
@@ -50,6 +53,7 @@ pub struct UserApiProvider {
     meta: MetaStore,
     client: Arc<dyn kvapi::KVApi<Error = MetaError> + Send + Sync>,
     idm_config: IDMConfig,
+    ownership_cache: Arc<OwnershipCache>,
 }
 
 impl UserApiProvider {
@@ -81,6 +85,7 @@ impl UserApiProvider {
             meta: client.clone(),
             client: client.arc(),
             idm_config,
+            ownership_cache: Arc::new(OwnershipCache::new()),
         };
 
         // init built-in role
@@ -123,7 +128,20 @@ impl UserApiProvider {
     }
 
     pub fn role_api(&self, tenant: &Tenant) -> Arc<impl RoleApi> {
-        let role_mgr = RoleMgr::create(self.client.clone(), tenant);
+        self.role_api_with_ownership_cache(tenant, Duration::ZERO)
+    }
+
+    /// Same as `role_api`, but `get_ownerships_cached` serves listings younger than `ttl`.
+    ///
+    /// The ownership cache is shared by every `RoleMgr` of this node, so that ownership
+    /// changes made through any of them invalidate it.
+    pub fn role_api_with_ownership_cache(
+        &self,
+        tenant: &Tenant,
+        ttl: Duration,
+    ) -> Arc<impl RoleApi> {
+        let role_mgr = RoleMgr::create(self.client.clone(), tenant)
+            .with_ownership_cache(self.ownership_cache.clone(), ttl);
         Arc::new(role_mgr)
     }
 
@@ -139,6 +157,10 @@ impl UserApiProvider {
         ConnectionMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn table_location_api(&self, tenant: &Tenant) -> Result<TableLocationMgr> {
+        TableLocationMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn tenant_quota_api(&self, tenant: &Tenant) -> Arc<dyn QuotaApi> {
         Arc::new(QuotaMgr::create(self.client.clone(), tenant))
     }
@@ -133,6 +133,7 @@ impl GrantObjectVisibilityChecker {
                     GrantObject::UDF(udf) => {
                         granted_udfs.insert(udf.to_string());
                     }
+                    GrantObject::Sequence(_) => {}
                     GrantObject::Stage(stage) => {
                         if ent
                             .privileges()
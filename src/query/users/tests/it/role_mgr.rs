@@ -18,8 +18,10 @@ use databend_common_exception::Result;
 use databend_common_grpc::RpcClientConf;
 use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_users::UserApiProvider;
 use pretty_assertions::assert_eq;
@@ -105,3 +107,50 @@ async fn test_role_manager() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_role_cascade() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let tenant = Tenant::new_literal("tenant1");
+
+    let user_mgr = UserApiProvider::try_create_simple(conf, &tenant).await?;
+
+    let parent_role = "parent-role".to_string();
+    let dropped_role = "dropped-role".to_string();
+    user_mgr
+        .add_role(&tenant, RoleInfo::new(&parent_role), false)
+        .await?;
+    user_mgr
+        .add_role(&tenant, RoleInfo::new(&dropped_role), false)
+        .await?;
+    user_mgr
+        .grant_role_to_role(&tenant, &parent_role, dropped_role.clone())
+        .await?;
+
+    let user_info = UserInfo::new_no_auth("test-user", "%");
+    let user = user_info.identity();
+    user_mgr
+        .add_user(&tenant, user_info, &CreateOption::Create)
+        .await?;
+    user_mgr
+        .grant_role_to_user(tenant.clone(), user.clone(), dropped_role.clone())
+        .await?;
+
+    user_mgr
+        .drop_role_cascade(&tenant, dropped_role.clone())
+        .await?;
+
+    let res = user_mgr.get_role(&tenant, dropped_role.clone()).await;
+    assert_eq!(res.err().unwrap().code(), ErrorCode::UNKNOWN_ROLE);
+
+    let user_info = user_mgr.get_user(&tenant, user.clone()).await?;
+    assert!(!user_info.grants.roles().contains(&dropped_role));
+
+    let parent = user_mgr.get_role(&tenant, parent_role.clone()).await?;
+    assert!(!parent.grants.roles().contains(&dropped_role));
+
+    // Dropping again is a no-op.
+    user_mgr.drop_role_cascade(&tenant, dropped_role).await?;
+
+    Ok(())
+}
@@ -169,7 +169,11 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         let (table, alias) = self.gen_subquery_table(schema);
         self.cte_tables.push(table);
 
-        let materialized = self.rng.gen_bool(0.5);
+        let materialized = if self.rng.gen_bool(0.5) {
+            Some(self.rng.gen_bool(0.5))
+        } else {
+            None
+        };
 
         CTE {
             span: None,
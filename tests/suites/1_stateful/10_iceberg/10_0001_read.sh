@@ -24,3 +24,5 @@ echo "SELECT * FROM iceberg_ctl.iceberg_db.iceberg_tbl WHERE id = 5;" | $BENDSQL
 echo "SELECT data FROM iceberg_ctl.iceberg_db.iceberg_tbl WHERE id > 3 ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
 
 echo "SELECT data FROM iceberg_ctl.iceberg_db.iceberg_tbl WHERE id > 3 ORDER BY data;" | $BENDSQL_CLIENT_CONNECT
+
+echo "EXISTS TABLE iceberg_ctl.iceberg_db.iceberg_tbl;" | $BENDSQL_CLIENT_CONNECT
@@ -19,7 +19,7 @@ echo "select a, change\$action, change\$is_update, change\$row_id='$BASE_ROW_ID'
 echo "create stream test_s1 on table db_stream.t at(stream => default.test_s) append_only=false comment = 'standard'" | $BENDSQL_CLIENT_CONNECT
 echo "show streams like 'test_s%'" | $BENDSQL_CLIENT_CONNECT | awk '{print $(NF-3), $(NF-2), $(NF-1), $NF}'
 echo "show full streams like 'test_s%'" | $BENDSQL_CLIENT_CONNECT | awk '{print $(NF-6), $(NF-5), $(NF-4), $(NF-3), $(NF-2), $(NF-1), $NF}'
-echo "desc stream default.test_s" | $BENDSQL_CLIENT_CONNECT | awk '{print $(NF-6), $(NF-5), $(NF-4), $(NF-3), $(NF-2), $(NF-1), $NF}'
+echo "select name, database, catalog, table_name, owner, comment, mode from system.streams where database = 'default' and name = 'test_s'" | $BENDSQL_CLIENT_CONNECT
 
 echo "drop stream if exists default.test_s" | $BENDSQL_CLIENT_CONNECT
 echo "drop stream if exists default.test_s1" | $BENDSQL_CLIENT_CONNECT